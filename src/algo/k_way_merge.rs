@@ -1,13 +1,20 @@
+use alloc::vec;
+use alloc::vec::Vec;
+
 use super::super::sorts::utils::copy_nonoverlapping;
+use super::super::{RadixKey, Radixable};
 
-fn forward_merge2<T: Copy + PartialOrd>(
+fn forward_merge2<T: Radixable<K>, K: RadixKey>(
     arr: &mut [T],
     copy: &mut [T],
     start: usize,
     middle: usize,
     end: usize,
 ) {
-    if start < middle && middle < end && arr[middle - 1] <= arr[middle] {
+    if start < middle
+        && middle < end
+        && arr[middle - 1].into_key_type() <= arr[middle].into_key_type()
+    {
         return;
     }
 
@@ -29,7 +36,7 @@ fn forward_merge2<T: Copy + PartialOrd>(
             return;
         }
 
-        if copy[i] <= arr[j] {
+        if copy[i].into_key_type() <= arr[j].into_key_type() {
             arr[position] = copy[i];
             i += 1;
         } else {
@@ -40,14 +47,17 @@ fn forward_merge2<T: Copy + PartialOrd>(
     }
 }
 
-fn backward_merge2<T: Copy + PartialOrd>(
+fn backward_merge2<T: Radixable<K>, K: RadixKey>(
     arr: &mut [T],
     copy: &mut [T],
     start: usize,
     middle: usize,
     end: usize,
 ) {
-    if start < middle && middle < end && arr[middle - 1] <= arr[middle] {
+    if start < middle
+        && middle < end
+        && arr[middle - 1].into_key_type() <= arr[middle].into_key_type()
+    {
         return;
     }
 
@@ -68,7 +78,8 @@ fn backward_merge2<T: Copy + PartialOrd>(
             return;
         }
 
-        if copy[i as usize] >= arr[j as usize] {
+        let (a, b) = (&copy[i as usize], &arr[j as usize]);
+        if a.into_key_type() >= b.into_key_type() {
             arr[position] = copy[i as usize];
             i -= 1;
         } else {
@@ -79,7 +90,7 @@ fn backward_merge2<T: Copy + PartialOrd>(
     }
 }
 
-pub fn merge2<T: Copy + PartialOrd>(
+pub fn merge2<T: Radixable<K>, K: RadixKey>(
     arr: &mut [T],
     copy: &mut [T],
     start: usize,
@@ -93,7 +104,43 @@ pub fn merge2<T: Copy + PartialOrd>(
     }
 }
 
-pub fn k_way_merge<T: Copy + PartialOrd>(
+// One round of the parallel k-way merges: the runs are merged by pairs, each
+// pair with its own part of `arr` and of `buffer`, so the pairs can be merged
+// at the same time. `merge` gets the parts of a pair, with the middle and the
+// end of the pair in its part; the merged pairs are then removed from
+// `separators`.
+#[cfg(feature = "voracious_multithread_std")]
+pub fn merge_round<'s, T, F>(
+    arr: &'s mut [T],
+    buffer: &'s mut [T],
+    separators: &mut Vec<usize>,
+    mut merge: F,
+) where
+    F: FnMut(&'s mut [T], &'s mut [T], usize, usize),
+{
+    let half = (separators.len() - 1) / 2;
+    let mut offset = 0;
+    let mut rest = arr;
+    let mut rest_buffer = buffer;
+    for i in 0..half {
+        let i2 = i * 2;
+        let sep1 = separators[i2];
+        let sep2 = separators[i2 + 1];
+        let sep3 = separators[i2 + 2];
+        let (part, snd) = rest.split_at_mut(sep3 - offset);
+        rest = snd;
+        let (buffer_part, snd) = rest_buffer.split_at_mut(sep3 - offset);
+        rest_buffer = snd;
+        offset = sep3;
+
+        merge(part, buffer_part, sep2 - sep1, sep3 - sep1);
+    }
+    for i in 0..half {
+        separators.remove(i + 1);
+    }
+}
+
+pub fn k_way_merge<T: Radixable<K>, K: RadixKey>(
     arr: &mut [T],
     separators: &mut Vec<usize>,
 ) {
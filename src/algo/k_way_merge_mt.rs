@@ -1,14 +1,13 @@
-use rayon::{ThreadPoolBuilder, ThreadPool};
-
+use super::super::sorts::utils_mt::run_in_pool;
+use super::super::{RadixKey, Radixable};
 use super::k_way_merge::merge2;
 
-fn kway_merge_mt_helper<T: Copy + PartialOrd + Send>(
-    pool: &ThreadPool,
+fn kway_merge_mt_helper<T: Radixable<K>, K: RadixKey>(
     arr: &mut [T],
     buffer: &mut [T],
     separators: &mut Vec<usize>,
 ) {
-    pool.scope(|s| {
+    rayon::scope(|s| {
         let half = (separators.len() - 1) / 2;
         let mut offset = 0;
         let mut rest = arr;
@@ -44,7 +43,7 @@ fn kway_merge_mt_helper<T: Copy + PartialOrd + Send>(
     });
 }
 
-pub fn k_way_merge_mt<T: Copy + PartialOrd + Send>(
+pub fn k_way_merge_mt<T: Radixable<K>, K: RadixKey>(
     arr: &mut [T],
     buffer: &mut [T],
     separators: &mut Vec<usize>,
@@ -72,17 +71,14 @@ pub fn k_way_merge_mt<T: Copy + PartialOrd + Send>(
         return;
     }
 
-    let pool = ThreadPoolBuilder::new()
-        .num_threads(thread_n)
-        .build()
-        .unwrap();
-
-    while separators.len() > 2 {
-        kway_merge_mt_helper(&pool, arr, buffer, separators);
-    }
+    run_in_pool(thread_n, || {
+        while separators.len() > 2 {
+            kway_merge_mt_helper(arr, buffer, separators);
+        }
+    });
 }
 
-pub fn k_way_merge_mt_with_buffer<T: Copy + PartialOrd + Send>(
+pub fn k_way_merge_mt_with_buffer<T: Radixable<K>, K: RadixKey>(
     arr: &mut [T],
     separators: &mut Vec<usize>,
     thread_n: usize,
@@ -109,14 +105,11 @@ pub fn k_way_merge_mt_with_buffer<T: Copy + PartialOrd + Send>(
         return;
     }
 
-    let pool = ThreadPoolBuilder::new()
-        .num_threads(thread_n)
-        .build()
-        .unwrap();
-
     let mut buffer: Vec<T> = arr.to_vec();
     let buffer = buffer.as_mut_slice();
-    while separators.len() > 2 {
-        kway_merge_mt_helper(&pool, arr, buffer, separators);
-    }
+    run_in_pool(thread_n, || {
+        while separators.len() > 2 {
+            kway_merge_mt_helper(arr, buffer, separators);
+        }
+    });
 }
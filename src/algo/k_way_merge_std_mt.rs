@@ -0,0 +1,41 @@
+use super::super::sorts::work_queue::{part_mut, Job, WorkQueue};
+use super::super::{RadixKey, Radixable};
+use super::k_way_merge::{k_way_merge, merge2, merge_round};
+
+// Same as `k_way_merge`, but at each round, the pairs of runs are merged in
+// parallel on the threads of the std work queue `queue`.
+pub fn k_way_merge_std_mt<'a, T: Radixable<K> + 'a, K: RadixKey>(
+    queue: &WorkQueue<'a>,
+    arr: &mut [T],
+    separators: &mut Vec<usize>,
+) {
+    if separators.len() <= 3 {
+        k_way_merge(arr, separators);
+        return;
+    }
+
+    let mut buffer: Vec<T> = arr.to_vec();
+
+    while separators.len() > 2 {
+        let mut jobs: Vec<Job<'a>> = Vec::new();
+        merge_round(
+            arr,
+            &mut buffer,
+            separators,
+            |part, buffer_part, mid, end| {
+                let (ptr, len) = (part.as_mut_ptr() as usize, part.len());
+                let buffer_ptr = buffer_part.as_mut_ptr() as usize;
+                let buffer_len = buffer_part.len();
+
+                jobs.push(Box::new(move |_| {
+                    let part = unsafe { part_mut::<T>(ptr, len) };
+                    let buffer_part =
+                        unsafe { part_mut::<T>(buffer_ptr, buffer_len) };
+                    merge2(part, buffer_part, 0, mid, end);
+                }));
+            },
+        );
+
+        queue.join_all(jobs);
+    }
+}
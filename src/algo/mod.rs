@@ -1,4 +1,9 @@
 pub mod k_way_merge;
+#[cfg(feature = "voracious_multithread_std")] pub mod k_way_merge_std_mt;
 // pub mod k_way_merge_mt;
-#[cfg(feature = "voracious_multithread")] pub mod regions_graph;
+#[cfg(any(
+    feature = "voracious_multithread",
+    feature = "voracious_multithread_std"
+))]
+pub mod regions_graph;
 pub mod verge_sort_heuristic;
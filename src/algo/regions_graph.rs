@@ -1,4 +1,4 @@
-use super::super::sorts::utils_mt::aggregate_histograms;
+use super::super::sorts::utils::aggregate_histograms;
 
 pub type CountryId = usize;
 pub type RegionSize = usize;
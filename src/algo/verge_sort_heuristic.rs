@@ -1,3 +1,7 @@
+use alloc::vec;
+use alloc::vec::Vec;
+
+use super::super::sorts::utils::log2;
 use super::super::{RadixKey, Radixable};
 
 #[derive(PartialEq, Debug)]
@@ -42,14 +46,15 @@ pub enum ForwardGrowth {
 
 #[inline]
 pub fn compute_big_enough_run(size: usize) -> usize {
-    let div = (size as f64).log2();
+    let div = log2(size as f64);
     ((size as f64) / div) as usize
 }
 
 #[inline]
-pub fn explore_forward_asc<T>(arr: &mut [T], start: usize) -> usize
+pub fn explore_forward_asc<T, K>(arr: &[T], start: usize) -> usize
 where
-    T: PartialOrd,
+    T: Radixable<K>,
+    K: RadixKey,
 {
     if start == arr.len() - 1 {
         return arr.len();
@@ -64,11 +69,11 @@ where
         for q in 0..(quotient - 1) {
             let j = start + q * 4;
             unsafe {
-                let a0 = arr.get_unchecked(j);
-                let a1 = arr.get_unchecked(j + 1);
-                let a2 = arr.get_unchecked(j + 2);
-                let a3 = arr.get_unchecked(j + 3);
-                let a4 = arr.get_unchecked(j + 4);
+                let a0 = arr.get_unchecked(j).into_key_type();
+                let a1 = arr.get_unchecked(j + 1).into_key_type();
+                let a2 = arr.get_unchecked(j + 2).into_key_type();
+                let a3 = arr.get_unchecked(j + 3).into_key_type();
+                let a4 = arr.get_unchecked(j + 4).into_key_type();
 
                 // using a function as a parameter for the compare
                 // drastically impact performance
@@ -95,7 +100,7 @@ where
     i = if quotient > 10 { start + ((quotient - 1) * 4) - 1 } else { i };
 
     while i < arr.len() - 1 {
-        if arr[i] <= arr[i + 1] {
+        if arr[i].into_key_type() <= arr[i + 1].into_key_type() {
             i += 1;
         } else {
             return i + 1;
@@ -106,9 +111,10 @@ where
 }
 
 #[inline]
-pub fn explore_forward_desc<T>(arr: &mut [T], start: usize) -> usize
+pub fn explore_forward_desc<T, K>(arr: &[T], start: usize) -> usize
 where
-    T: PartialOrd,
+    T: Radixable<K>,
+    K: RadixKey,
 {
     if start == arr.len() - 1 {
         return arr.len();
@@ -123,11 +129,11 @@ where
         for q in 0..(quotient - 1) {
             let j = start + q * 4;
             unsafe {
-                let a0 = arr.get_unchecked(j);
-                let a1 = arr.get_unchecked(j + 1);
-                let a2 = arr.get_unchecked(j + 2);
-                let a3 = arr.get_unchecked(j + 3);
-                let a4 = arr.get_unchecked(j + 4);
+                let a0 = arr.get_unchecked(j).into_key_type();
+                let a1 = arr.get_unchecked(j + 1).into_key_type();
+                let a2 = arr.get_unchecked(j + 2).into_key_type();
+                let a3 = arr.get_unchecked(j + 3).into_key_type();
+                let a4 = arr.get_unchecked(j + 4).into_key_type();
 
                 let b0 = a0 >= a1;
                 let b1 = a1 >= a2 && b0;
@@ -152,7 +158,7 @@ where
     i = if quotient > 10 { start + ((quotient - 1) * 4) - 1 } else { i };
 
     while i < arr.len() - 1 {
-        if arr[i] >= arr[i + 1] {
+        if arr[i].into_key_type() >= arr[i + 1].into_key_type() {
             i += 1;
         } else {
             return i + 1;
@@ -163,8 +169,8 @@ where
 }
 
 #[inline]
-pub fn explore_backward_asc<T: PartialOrd>(
-    arr: &mut [T],
+pub fn explore_backward_asc<T: Radixable<K>, K: RadixKey>(
+    arr: &[T],
     start: usize,
     min_boundary: usize,
 ) -> usize {
@@ -177,11 +183,11 @@ pub fn explore_backward_asc<T: PartialOrd>(
         for q in 0..(quotient - 1) {
             let j = start - q * 4;
             unsafe {
-                let a0 = arr.get_unchecked(j);
-                let a1 = arr.get_unchecked(j - 1);
-                let a2 = arr.get_unchecked(j - 2);
-                let a3 = arr.get_unchecked(j - 3);
-                let a4 = arr.get_unchecked(j - 4);
+                let a0 = arr.get_unchecked(j).into_key_type();
+                let a1 = arr.get_unchecked(j - 1).into_key_type();
+                let a2 = arr.get_unchecked(j - 2).into_key_type();
+                let a3 = arr.get_unchecked(j - 3).into_key_type();
+                let a4 = arr.get_unchecked(j - 4).into_key_type();
 
                 let b0 = a1 <= a0;
                 let b1 = a2 <= a1 && b0;
@@ -206,7 +212,7 @@ pub fn explore_backward_asc<T: PartialOrd>(
     i = if quotient > 10 { start - ((quotient - 1) * 4) + 1 } else { i };
 
     while i > min_boundary {
-        if arr[i - 1] <= arr[i] {
+        if arr[i - 1].into_key_type() <= arr[i].into_key_type() {
             i -= 1;
         } else {
             break;
@@ -217,8 +223,8 @@ pub fn explore_backward_asc<T: PartialOrd>(
 }
 
 #[inline]
-pub fn explore_backward_desc<T: PartialOrd>(
-    arr: &mut [T],
+pub fn explore_backward_desc<T: Radixable<K>, K: RadixKey>(
+    arr: &[T],
     start: usize,
     min_boundary: usize,
 ) -> usize {
@@ -231,11 +237,11 @@ pub fn explore_backward_desc<T: PartialOrd>(
         for q in 0..(quotient - 1) {
             let j = start - q * 4;
             unsafe {
-                let a0 = arr.get_unchecked(j);
-                let a1 = arr.get_unchecked(j - 1);
-                let a2 = arr.get_unchecked(j - 2);
-                let a3 = arr.get_unchecked(j - 3);
-                let a4 = arr.get_unchecked(j - 4);
+                let a0 = arr.get_unchecked(j).into_key_type();
+                let a1 = arr.get_unchecked(j - 1).into_key_type();
+                let a2 = arr.get_unchecked(j - 2).into_key_type();
+                let a3 = arr.get_unchecked(j - 3).into_key_type();
+                let a4 = arr.get_unchecked(j - 4).into_key_type();
 
                 let b0 = a1 >= a0;
                 let b1 = a2 >= a1 && b0;
@@ -260,7 +266,7 @@ pub fn explore_backward_desc<T: PartialOrd>(
     i = if quotient > 10 { start - ((quotient - 1) * 4) + 1 } else { i };
 
     while i > min_boundary {
-        if arr[i - 1] >= arr[i] {
+        if arr[i - 1].into_key_type() >= arr[i].into_key_type() {
             i -= 1;
         } else {
             break;
@@ -271,8 +277,8 @@ pub fn explore_backward_desc<T: PartialOrd>(
 }
 
 #[inline]
-pub fn explore_backward_plateau<T: PartialOrd>(
-    arr: &mut [T],
+pub fn explore_backward_plateau<T: Radixable<K>, K: RadixKey>(
+    arr: &[T],
     start: usize,
     min_boundary: usize,
 ) -> usize {
@@ -285,11 +291,11 @@ pub fn explore_backward_plateau<T: PartialOrd>(
         for q in 0..(quotient - 1) {
             let j = start - q * 4;
             unsafe {
-                let a0 = arr.get_unchecked(j);
-                let a1 = arr.get_unchecked(j - 1);
-                let a2 = arr.get_unchecked(j - 2);
-                let a3 = arr.get_unchecked(j - 3);
-                let a4 = arr.get_unchecked(j - 4);
+                let a0 = arr.get_unchecked(j).into_key_type();
+                let a1 = arr.get_unchecked(j - 1).into_key_type();
+                let a2 = arr.get_unchecked(j - 2).into_key_type();
+                let a3 = arr.get_unchecked(j - 3).into_key_type();
+                let a4 = arr.get_unchecked(j - 4).into_key_type();
 
                 let b0 = a0 == a1;
                 let b1 = a1 == a2 && b0;
@@ -314,7 +320,7 @@ pub fn explore_backward_plateau<T: PartialOrd>(
     i = if quotient > 10 { start - ((quotient - 1) * 4) + 1 } else { i };
 
     while i > min_boundary {
-        if arr[i - 1] == arr[i] {
+        if arr[i - 1].into_key_type() == arr[i].into_key_type() {
             i -= 1;
         } else {
             break;
@@ -325,8 +331,8 @@ pub fn explore_backward_plateau<T: PartialOrd>(
 }
 
 #[inline]
-pub fn explore_forward_plateau<T: PartialOrd>(
-    arr: &mut [T],
+pub fn explore_forward_plateau<T: Radixable<K>, K: RadixKey>(
+    arr: &[T],
     start: usize,
 ) -> usize {
     if start == arr.len() - 1 {
@@ -342,11 +348,11 @@ pub fn explore_forward_plateau<T: PartialOrd>(
         for q in 0..(quotient - 1) {
             let j = start + q * 4;
             unsafe {
-                let a0 = arr.get_unchecked(j);
-                let a1 = arr.get_unchecked(j + 1);
-                let a2 = arr.get_unchecked(j + 2);
-                let a3 = arr.get_unchecked(j + 3);
-                let a4 = arr.get_unchecked(j + 4);
+                let a0 = arr.get_unchecked(j).into_key_type();
+                let a1 = arr.get_unchecked(j + 1).into_key_type();
+                let a2 = arr.get_unchecked(j + 2).into_key_type();
+                let a3 = arr.get_unchecked(j + 3).into_key_type();
+                let a4 = arr.get_unchecked(j + 4).into_key_type();
 
                 let b0 = a0 == a1;
                 let b1 = a1 == a2 && b0;
@@ -371,7 +377,7 @@ pub fn explore_forward_plateau<T: PartialOrd>(
     i = if quotient > 10 { start + ((quotient - 1) * 4) - 1 } else { i };
 
     while i < arr.len() - 1 {
-        if arr[i] == arr[i + 1] {
+        if arr[i].into_key_type() == arr[i + 1].into_key_type() {
             i += 1;
         } else {
             return i + 1;
@@ -393,15 +399,18 @@ pub fn jump(arr_length: usize, position: usize, part_size: usize) -> usize {
 }
 
 #[inline]
-pub fn backward_orientation<T: PartialOrd>(
-    arr: &mut [T],
+pub fn backward_orientation<T: Radixable<K>, K: RadixKey>(
+    arr: &[T],
     position: usize,
 ) -> Orientation {
     if position == 0 {
-        Orientation::IsNone
-    } else if arr[position - 1] < arr[position] {
+        return Orientation::IsNone;
+    }
+    let a = arr[position - 1].into_key_type();
+    let b = arr[position].into_key_type();
+    if a < b {
         Orientation::IsAsc
-    } else if arr[position - 1] > arr[position] {
+    } else if a > b {
         Orientation::IsDesc
     } else {
         Orientation::IsPlateau
@@ -409,15 +418,18 @@ pub fn backward_orientation<T: PartialOrd>(
 }
 
 #[inline]
-pub fn forward_orientation<T: PartialOrd>(
-    arr: &mut [T],
+pub fn forward_orientation<T: Radixable<K>, K: RadixKey>(
+    arr: &[T],
     position: usize,
 ) -> Orientation {
     if position >= arr.len() - 1 {
-        Orientation::IsNone
-    } else if arr[position] < arr[position + 1] {
+        return Orientation::IsNone;
+    }
+    let a = arr[position].into_key_type();
+    let b = arr[position + 1].into_key_type();
+    if a < b {
         Orientation::IsAsc
-    } else if arr[position] > arr[position + 1] {
+    } else if a > b {
         Orientation::IsDesc
     } else {
         Orientation::IsPlateau
@@ -477,7 +489,11 @@ pub fn get_growth_pattern(
 // This function is not for the Verge sort pre processing heuristic, but for
 // an other heuristic in the Voracious Sort
 #[inline]
-pub fn explore_simple_forward<T: PartialOrd>(arr: &mut [T]) -> Orientation {
+pub fn explore_simple_forward<T, K>(arr: &[T]) -> Orientation
+where
+    T: Radixable<K>,
+    K: RadixKey,
+{
     match forward_orientation(arr, 0) {
         Orientation::IsAsc => {
             let p = explore_forward_asc(arr, 0);
@@ -527,8 +543,8 @@ pub fn explore_simple_forward<T: PartialOrd>(arr: &mut [T]) -> Orientation {
 }
 
 #[inline]
-pub fn explore_around<T: PartialOrd>(
-    arr: &mut [T],
+pub fn explore_around<T: Radixable<K>, K: RadixKey>(
+    arr: &[T],
     position: usize,
     min_boundary: usize,
 ) -> ((BackwardGrowth, usize, usize), (ForwardGrowth, usize, usize)) {
@@ -597,132 +613,87 @@ pub fn explore_around<T: PartialOrd>(
     ((b_pattern, bp1, bp2), (f_pattern, fp1, fp2))
 }
 
+// Reverse a descending run. A descending run may hold equal elements: for
+// the stable sorts, they are put back in their initial order.
 #[inline]
-fn handle_asc_then_desc<T, K>(
-    arr: &mut [T],
+fn reverse_run<T: Radixable<K>, K: RadixKey>(arr: &mut [T], stable: bool) {
+    arr.reverse();
+    if !stable {
+        return;
+    }
+
+    let mut start = 0;
+    for i in 1..=arr.len() {
+        if i == arr.len()
+            || arr[i].into_key_type() != arr[start].into_key_type()
+        {
+            if i - start > 1 {
+                arr[start..i].reverse();
+            }
+            start = i;
+        }
+    }
+}
+
+#[inline]
+fn handle_asc_then_desc(
     bp2: usize,
     bp1: usize,
     fp1: usize,
     fp2: usize,
-    last_sorted: usize,
     big_enough: usize,
-    separators: &mut Vec<usize>,
-    radix: usize,
-    fallback_sort: &dyn Fn(&mut [T], usize) -> (),
-) -> (usize, usize)
-where
-    T: Radixable<K> + Copy + PartialOrd,
-    K: RadixKey,
-{
+) -> (usize, Option<Run>) {
     //     bp2 bp1  position  fp1 fp2
     //        /¯¯¯¯¯¯¯¯¯¯¯¯¯¯¯¯¯\
     //       /                   \
     //      /                     \
     if fp1 - bp2 >= big_enough {
-        if bp2 - last_sorted > 0 {
-            fallback_sort(&mut arr[last_sorted..bp2], radix);
-            separators.push(bp2);
-        }
-        separators.push(fp1);
-        (fp2, fp1)
+        (fp2, Some(Run { start: bp2, end: fp1, descending: false }))
     } else if fp2 - bp1 >= big_enough {
-        if bp1 - last_sorted > 0 {
-            fallback_sort(&mut arr[last_sorted..bp1], radix);
-            separators.push(bp1);
-        }
-        separators.push(fp2);
-        arr[bp1..fp2].reverse();
-        (fp2, fp2)
+        (fp2, Some(Run { start: bp1, end: fp2, descending: true }))
     } else {
-        (fp2, last_sorted)
+        (fp2, None)
     }
 }
 
 #[inline]
-fn handle_desc_then_asc<T, K>(
-    arr: &mut [T],
+fn handle_desc_then_asc(
     bp2: usize,
     bp1: usize,
     fp1: usize,
     fp2: usize,
-    last_sorted: usize,
     big_enough: usize,
-    separators: &mut Vec<usize>,
-    radix: usize,
-    fallback_sort: &dyn Fn(&mut [T], usize) -> (),
-) -> (usize, usize)
-where
-    T: Radixable<K> + Copy + PartialOrd,
-    K: RadixKey,
-{
+) -> (usize, Option<Run>) {
     //     bp2 bp1   position   fp1 fp2
     //      \  |        |        |  /
     //       \ |        |        | /
     //        \|________|________|/
     if fp2 - bp1 >= big_enough {
-        if bp1 - last_sorted > 0 {
-            fallback_sort(&mut arr[last_sorted..bp1], radix);
-            separators.push(bp1);
-        }
-        separators.push(fp2);
-        (fp2, fp2)
+        (fp2, Some(Run { start: bp1, end: fp2, descending: false }))
     } else if fp1 - bp2 >= big_enough {
-        if bp2 - last_sorted > 0 {
-            fallback_sort(&mut arr[last_sorted..bp2], radix);
-            separators.push(bp2);
-        }
-        separators.push(fp1);
-        arr[bp2..fp1].reverse();
-        (fp2, fp1)
+        (fp2, Some(Run { start: bp2, end: fp1, descending: true }))
     } else {
-        (fp2, last_sorted)
+        (fp2, None)
     }
 }
 
 #[inline]
-fn handle_part<T, K>(
-    arr: &mut [T],
+fn handle_part(
     b_pattern: BackwardGrowth,
     f_pattern: ForwardGrowth,
     bp2: usize,
     bp1: usize,
     fp1: usize,
     fp2: usize,
-    last_sorted: usize,
     big_enough: usize,
-    separators: &mut Vec<usize>,
-    radix: usize,
-    fallback_sort: &dyn Fn(&mut [T], usize) -> (),
-) -> (usize, usize)
-where
-    T: Radixable<K> + Copy + PartialOrd,
-    K: RadixKey,
-{
+) -> (usize, Option<Run>) {
     match get_growth_pattern(b_pattern, f_pattern) {
-        GrowthPattern::AscThenDesc => handle_asc_then_desc(
-            arr,
-            bp2,
-            bp1,
-            fp1,
-            fp2,
-            last_sorted,
-            big_enough,
-            separators,
-            radix,
-            fallback_sort,
-        ),
-        GrowthPattern::DescThenAsc => handle_desc_then_asc(
-            arr,
-            bp2,
-            bp1,
-            fp1,
-            fp2,
-            last_sorted,
-            big_enough,
-            separators,
-            radix,
-            fallback_sort,
-        ),
+        GrowthPattern::AscThenDesc => {
+            handle_asc_then_desc(bp2, bp1, fp1, fp2, big_enough)
+        },
+        GrowthPattern::DescThenAsc => {
+            handle_desc_then_asc(bp2, bp1, fp1, fp2, big_enough)
+        },
         GrowthPattern::AscOnly | GrowthPattern::PlateauOnly => {
             //     bp2 bp1   position   fp1 fp2
             //       | |        |        | /
@@ -730,14 +701,9 @@ where
             //       | /
             //       |/
             if fp2 - bp2 >= big_enough {
-                if bp2 - last_sorted > 0 {
-                    fallback_sort(&mut arr[last_sorted..bp2], radix);
-                    separators.push(bp2);
-                }
-                separators.push(fp2);
-                (fp2, fp2)
+                (fp2, Some(Run { start: bp2, end: fp2, descending: false }))
             } else {
-                (fp2, last_sorted)
+                (fp2, None)
             }
         },
         GrowthPattern::DescOnly => {
@@ -747,15 +713,9 @@ where
             //                           \ |
             //                            \|
             if fp2 - bp2 >= big_enough {
-                if bp2 - last_sorted > 0 {
-                    fallback_sort(&mut arr[last_sorted..bp2], radix);
-                    separators.push(bp2);
-                }
-                separators.push(fp2);
-                arr[bp2..fp2].reverse();
-                (fp2, fp2)
+                (fp2, Some(Run { start: bp2, end: fp2, descending: true }))
             } else {
-                (fp2, last_sorted)
+                (fp2, None)
             }
         },
         GrowthPattern::Neither => {
@@ -764,44 +724,86 @@ where
     }
 }
 
-pub fn verge_sort_preprocessing<T, K>(
-    arr: &mut [T],
-    radix: usize,
-    fallback_sort: &dyn Fn(&mut [T], usize) -> (),
-) -> Vec<usize>
+/// # Run
+///
+/// A long run of an array: `arr[start..end]` is sorted in ascending order,
+/// or in descending order when `descending` is set.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Run {
+    pub start: usize,
+    pub end: usize,
+    pub descending: bool,
+}
+
+impl Run {
+    pub fn len(&self) -> usize { self.end - self.start }
+    pub fn is_empty(&self) -> bool { self.start == self.end }
+}
+
+// The long runs of the array (at least `n / log2(n)` elements), from left to
+// right. The array is probed every `n / log2(n)` elements, and the runs are
+// explored around these positions.
+pub fn find_long_runs<T, K>(arr: &[T]) -> Vec<Run>
 where
-    T: Radixable<K> + Copy + PartialOrd,
+    T: Radixable<K>,
     K: RadixKey,
 {
     let size = arr.len();
+    let mut runs = Vec::new();
+    if size < 2 {
+        return runs;
+    }
+
     let big_enough = compute_big_enough_run(size);
     let mut last_sorted = 0;
-    let mut separators: Vec<usize> = vec![0];
     let mut position = jump(size, 0, big_enough);
 
     while position < size {
         let ((b_pattern, bp1, bp2), (f_pattern, fp1, fp2)) =
             explore_around(arr, position, last_sorted);
 
-        let (jump_position, new_last_sorted) = handle_part(
-            arr,
-            b_pattern,
-            f_pattern,
-            bp2,
-            bp1,
-            fp1,
-            fp2,
-            last_sorted,
-            big_enough,
-            &mut separators,
-            radix,
-            fallback_sort,
-        );
-
-        last_sorted = new_last_sorted;
+        let (jump_position, run) =
+            handle_part(b_pattern, f_pattern, bp2, bp1, fp1, fp2, big_enough);
+
+        if let Some(run) = run {
+            last_sorted = run.end;
+            runs.push(run);
+        }
         position = jump(size, jump_position, big_enough);
     }
 
+    runs
+}
+
+// The runs are found before the array is touched: the exploration never goes
+// back before the end of the previous run, so the runs do not depend on the
+// parts sorted or reversed before it.
+fn verge_sort_preprocessing_with<T, K>(
+    arr: &mut [T],
+    radix: usize,
+    fallback_sort: &dyn Fn(&mut [T], usize) -> (),
+    stable: bool,
+) -> Vec<usize>
+where
+    T: Radixable<K>,
+    K: RadixKey,
+{
+    let size = arr.len();
+    let mut last_sorted = 0;
+    let mut separators: Vec<usize> = vec![0];
+
+    for run in find_long_runs(arr) {
+        if run.start > last_sorted {
+            fallback_sort(&mut arr[last_sorted..run.start], radix);
+            separators.push(run.start);
+        }
+        if run.descending {
+            reverse_run(&mut arr[run.start..run.end], stable);
+        }
+        separators.push(run.end);
+        last_sorted = run.end;
+    }
+
     if last_sorted < size {
         fallback_sort(&mut arr[last_sorted..size], radix);
         separators.push(size);
@@ -809,3 +811,29 @@ where
 
     separators
 }
+
+pub fn verge_sort_preprocessing<T, K>(
+    arr: &mut [T],
+    radix: usize,
+    fallback_sort: &dyn Fn(&mut [T], usize) -> (),
+) -> Vec<usize>
+where
+    T: Radixable<K>,
+    K: RadixKey,
+{
+    verge_sort_preprocessing_with(arr, radix, fallback_sort, false)
+}
+
+// Same as `verge_sort_preprocessing`, but the equal elements of the reversed
+// runs keep their initial order. `fallback_sort` must be stable too.
+pub fn verge_sort_preprocessing_stable<T, K>(
+    arr: &mut [T],
+    radix: usize,
+    fallback_sort: &dyn Fn(&mut [T], usize) -> (),
+) -> Vec<usize>
+where
+    T: Radixable<K>,
+    K: RadixKey,
+{
+    verge_sort_preprocessing_with(arr, radix, fallback_sort, true)
+}
@@ -1,3 +1,5 @@
+use alloc::vec;
+
 const UNROLL_SIZE: usize = 4;
 
 pub fn cs_u16(arr: &mut [u16]) {
@@ -1,9 +1,17 @@
+use alloc::vec;
+use alloc::vec::Vec;
+
 use super::super::algo::k_way_merge::k_way_merge;
 use super::super::algo::verge_sort_heuristic::verge_sort_preprocessing;
+use super::super::sorts::comparative_sort::key_cmp;
+use super::super::sorts::msd_sort::copy_by_histogram_wc;
 use super::super::sorts::utils::{
     copy_nonoverlapping, get_empty_histograms, only_one_bucket_filled,
     prefix_sums, Params,
 };
+use super::super::sorts::utils_simd::{
+    bits_of_f32, fill_histograms_u32, simd_histograms_available, KeyTransform,
+};
 use super::super::Radixable;
 
 const UNROLL_SIZE: usize = 4;
@@ -52,6 +60,19 @@ fn copy_by_histogram(
 }
 
 fn get_full_histograms(arr: &mut [f32], p: &Params) -> Vec<Vec<usize>> {
+    if simd_histograms_available(arr.len(), p.radix) {
+        let mut histograms =
+            get_empty_histograms(p.max_level, p.radix_range);
+        fill_histograms_u32(
+            bits_of_f32(arr),
+            p.radix,
+            p.max_level,
+            KeyTransform::FlipFloat,
+            &mut histograms,
+        );
+        return histograms;
+    }
+
     let mut histograms = get_empty_histograms(p.max_level, p.radix_range);
 
     let default_mask = 0x0000_00FFu32;
@@ -228,14 +249,25 @@ fn lsd_radixsort_body(arr: &mut [f32], p: Params) {
         let (mask, shift) = dummy.get_mask_and_shift(&p.new_level(level));
         let (_, mut heads, _) = prefix_sums(&histograms[level]);
 
-        copy_by_histogram(
-            source.len(),
-            &mut source,
-            &mut destination,
-            &mut heads,
-            mask,
-            shift,
-        );
+        if p.write_combining {
+            copy_by_histogram_wc(
+                source.len(),
+                &mut source,
+                &mut destination,
+                &mut heads,
+                mask,
+                shift,
+            );
+        } else {
+            copy_by_histogram(
+                source.len(),
+                &mut source,
+                &mut destination,
+                &mut heads,
+                mask,
+                shift,
+            );
+        }
 
         index = 1 - index;
 
@@ -255,7 +287,7 @@ fn lsd_radixsort_body(arr: &mut [f32], p: Params) {
 
 pub fn lsd_f32(arr: &mut [f32]) {
     if arr.len() <= 128 {
-        arr.sort_unstable_by(|a, b| a.partial_cmp(b).unwrap());
+        arr.sort_unstable_by(key_cmp);
         return;
     }
 
@@ -268,7 +300,9 @@ pub fn lsd_f32(arr: &mut [f32]) {
         return;
     }
 
-    let params = Params::new(0, radix, offset, max_level);
+    // Above this size, the scatter steps are bound by the TLB and cache misses.
+    let params = Params::new(0, radix, offset, max_level)
+        .with_write_combining(arr.len() >= 50_000_000);
 
     let mut separators = verge_sort_preprocessing(arr, radix, &|arr, _| {
         lsd_radixsort_body(arr, params);
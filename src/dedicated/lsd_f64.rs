@@ -0,0 +1,41 @@
+use alloc::vec::Vec;
+
+use super::super::sorts::utils::Params;
+use super::super::sorts::utils_simd::{
+    bits_of_f64, fill_histograms_u64, KeyTransform,
+};
+
+// Same key as the `Radixable` implementation: the negative numbers have
+// all their bits flipped, the positive ones only their sign bit.
+#[inline]
+fn key(v: f64) -> u64 {
+    let submask = 0x8000_0000_0000_0000;
+    let casted = v.to_bits();
+
+    if casted & submask == submask {
+        casted ^ 0xFFFF_FFFF_FFFF_FFFF
+    } else {
+        casted ^ submask
+    }
+}
+
+fn simd_histograms(arr: &[f64], p: &Params, histograms: &mut [Vec<usize>]) {
+    fill_histograms_u64(
+        bits_of_f64(arr),
+        p.radix,
+        p.max_level,
+        KeyTransform::FlipFloat,
+        histograms,
+    );
+}
+
+lsd_kernel! {
+    /// # LSD sort for `f64`
+    ///
+    /// A LSD radix sort (radix 8) dedicated to `f64`. The key (the bits of the
+    /// float, ordered as integers) is computed inline, without the `Radixable`
+    /// indirection.
+    ///
+    /// This sort is an unstable radix sort.
+    lsd_f64, f64, key: key, simd: simd_histograms
+}
@@ -0,0 +1,30 @@
+use alloc::vec::Vec;
+
+use super::super::sorts::utils::Params;
+use super::super::sorts::utils_simd::{
+    bits_of_i32, fill_histograms_u32, KeyTransform,
+};
+
+// The sign bit is flipped, so the negative numbers come first.
+#[inline]
+fn key(v: i32) -> u32 { v as u32 ^ 0x8000_0000 }
+
+fn simd_histograms(arr: &[i32], p: &Params, histograms: &mut [Vec<usize>]) {
+    fill_histograms_u32(
+        bits_of_i32(arr),
+        p.radix,
+        p.max_level,
+        KeyTransform::FlipSign,
+        histograms,
+    );
+}
+
+lsd_kernel! {
+    /// # LSD sort for `i32`
+    ///
+    /// A LSD radix sort (radix 8) dedicated to `i32`. The key (the sign bit
+    /// flipped) is computed inline, without the `Radixable` indirection.
+    ///
+    /// This sort is an unstable radix sort.
+    lsd_i32, i32, key: key, simd: simd_histograms
+}
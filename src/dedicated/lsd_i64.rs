@@ -0,0 +1,30 @@
+use alloc::vec::Vec;
+
+use super::super::sorts::utils::Params;
+use super::super::sorts::utils_simd::{
+    bits_of_i64, fill_histograms_u64, KeyTransform,
+};
+
+// The sign bit is flipped, so the negative numbers come first.
+#[inline]
+fn key(v: i64) -> u64 { v as u64 ^ 0x8000_0000_0000_0000 }
+
+fn simd_histograms(arr: &[i64], p: &Params, histograms: &mut [Vec<usize>]) {
+    fill_histograms_u64(
+        bits_of_i64(arr),
+        p.radix,
+        p.max_level,
+        KeyTransform::FlipSign,
+        histograms,
+    );
+}
+
+lsd_kernel! {
+    /// # LSD sort for `i64`
+    ///
+    /// A LSD radix sort (radix 8) dedicated to `i64`. The key (the sign bit
+    /// flipped) is computed inline, without the `Radixable` indirection.
+    ///
+    /// This sort is an unstable radix sort.
+    lsd_i64, i64, key: key, simd: simd_histograms
+}
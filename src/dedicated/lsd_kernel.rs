@@ -0,0 +1,187 @@
+// Body of the dedicated LSD sorts (radix 8).
+//
+// The sorts only differ by the type of their elements, by their key (an
+// unsigned integer with the order of the elements, computed inline instead of
+// through the `Radixable` indirection) and by the SIMD histograms. The
+// optional `simd` function fills the histograms when
+// `simd_histograms_available` allows it; otherwise they are filled from the
+// keys.
+//
+// The `Radixable` impls do not route their `voracious_sort` to these sorts:
+// the speed tests (src/tests/speed_sort_ded.rs) show they are slower than the
+// DLSD, Rollercoaster and heuristic sorts picked there. `lsd_i32` only wins on
+// uniform keys (30k to 1M elements), and loses on a small key range.
+macro_rules! lsd_kernel {
+    (
+        $(#[$doc:meta])*
+        $name:ident, $t:ty, key: $key:ident $(, simd: $simd:ident)?
+    ) => {
+        const UNROLL_SIZE: usize = 4;
+
+        #[inline]
+        fn bucket(v: $t, shift: usize) -> usize {
+            (($key(v) >> shift) & 0xFF) as usize
+        }
+
+        fn copy_by_histogram(
+            source: &[$t],
+            destination: &mut [$t],
+            heads: &mut [usize],
+            shift: usize,
+        ) {
+            let remainder = source.len() % UNROLL_SIZE;
+            let (source_fst, source_snd) =
+                source.split_at(source.len() - remainder);
+
+            source_fst.chunks_exact(UNROLL_SIZE).for_each(|chunk| unsafe {
+                let b0 = bucket(*chunk.get_unchecked(0), shift);
+                let b1 = bucket(*chunk.get_unchecked(1), shift);
+                let b2 = bucket(*chunk.get_unchecked(2), shift);
+                let b3 = bucket(*chunk.get_unchecked(3), shift);
+
+                let d0 = *heads.get_unchecked(b0);
+                heads[b0] += 1;
+                let d1 = *heads.get_unchecked(b1);
+                heads[b1] += 1;
+                let d2 = *heads.get_unchecked(b2);
+                heads[b2] += 1;
+                let d3 = *heads.get_unchecked(b3);
+                heads[b3] += 1;
+
+                destination[d0] = *chunk.get_unchecked(0);
+                destination[d1] = *chunk.get_unchecked(1);
+                destination[d2] = *chunk.get_unchecked(2);
+                destination[d3] = *chunk.get_unchecked(3);
+            });
+
+            for item in source_snd.iter() {
+                let target_bucket = bucket(*item, shift);
+                destination[heads[target_bucket]] = *item;
+                heads[target_bucket] += 1;
+            }
+        }
+
+        // The histograms are ordered from the most significant byte to the
+        // least significant one, so they are filled from the last one.
+        fn get_full_histograms(
+            arr: &[$t],
+            p: &$crate::sorts::utils::Params,
+        ) -> alloc::vec::Vec<alloc::vec::Vec<usize>> {
+            let mut histograms = $crate::sorts::utils::get_empty_histograms(
+                p.max_level,
+                p.radix_range,
+            );
+
+            $(
+                if $crate::sorts::utils_simd::simd_histograms_available(
+                    arr.len(),
+                    p.radix,
+                ) {
+                    $simd(arr, p, &mut histograms);
+                    return histograms;
+                }
+            )?
+
+            let remainder = arr.len() % UNROLL_SIZE;
+            let (arr_fst, arr_snd) = arr.split_at(arr.len() - remainder);
+
+            arr_fst.chunks_exact(UNROLL_SIZE).for_each(|chunk| unsafe {
+                let mut v0 = $key(*chunk.get_unchecked(0));
+                let mut v1 = $key(*chunk.get_unchecked(1));
+                let mut v2 = $key(*chunk.get_unchecked(2));
+                let mut v3 = $key(*chunk.get_unchecked(3));
+                for histogram in histograms.iter_mut().rev() {
+                    histogram[(v0 & 0xFF) as usize] += 1;
+                    histogram[(v1 & 0xFF) as usize] += 1;
+                    histogram[(v2 & 0xFF) as usize] += 1;
+                    histogram[(v3 & 0xFF) as usize] += 1;
+                    v0 >>= 8;
+                    v1 >>= 8;
+                    v2 >>= 8;
+                    v3 >>= 8;
+                }
+            });
+
+            for item in arr_snd.iter() {
+                let mut v = $key(*item);
+                for histogram in histograms.iter_mut().rev() {
+                    histogram[(v & 0xFF) as usize] += 1;
+                    v >>= 8;
+                }
+            }
+
+            histograms
+        }
+
+        fn lsd_radixsort_body(arr: &mut [$t], p: $crate::sorts::utils::Params) {
+            let size = arr.len();
+            let mut buffer: alloc::vec::Vec<$t> = alloc::vec![arr[0]; size];
+            let mut index = 0;
+
+            let histograms = get_full_histograms(arr, &p);
+
+            let mut t1 = arr;
+            let mut t2 = buffer.as_mut_slice();
+
+            for level in (p.level..p.max_level).rev() {
+                if $crate::sorts::utils::only_one_bucket_filled(
+                    &histograms[level],
+                ) {
+                    continue;
+                }
+
+                let (source, destination) =
+                    if index == 0 { (t1, t2) } else { (t2, t1) };
+                let shift = p.radix * (p.max_level - level - 1);
+                let (_, mut heads, _) =
+                    $crate::sorts::utils::prefix_sums(&histograms[level]);
+
+                copy_by_histogram(source, destination, &mut heads, shift);
+
+                index = 1 - index;
+
+                if index == 1 {
+                    t1 = source;
+                    t2 = destination;
+                } else {
+                    t2 = source;
+                    t1 = destination;
+                }
+            }
+
+            if index == 1 {
+                $crate::sorts::utils::copy_nonoverlapping(t2, t1, size);
+            }
+        }
+
+        $(#[$doc])*
+        pub fn $name(arr: &mut [$t]) {
+            if arr.len() <= 128 {
+                arr.sort_unstable_by_key(|item| $key(*item));
+                return;
+            }
+
+            let radix = 8;
+            let dummy = arr[0];
+            let (offset, _) =
+                $crate::Radixable::compute_offset(&dummy, arr, radix);
+            let max_level =
+                $crate::Radixable::compute_max_level(&dummy, offset, radix);
+
+            if max_level == 0 {
+                return;
+            }
+
+            let params =
+                $crate::sorts::utils::Params::new(0, radix, offset, max_level);
+
+            let mut separators =
+                $crate::algo::verge_sort_heuristic::verge_sort_preprocessing(
+                    arr,
+                    radix,
+                    &|arr, _| lsd_radixsort_body(arr, params),
+                );
+            $crate::algo::k_way_merge::k_way_merge(arr, &mut separators);
+        }
+    };
+}
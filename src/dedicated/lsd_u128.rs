@@ -0,0 +1,12 @@
+#[inline]
+fn key(v: u128) -> u128 { v }
+
+lsd_kernel! {
+    /// # LSD sort for `u128`
+    ///
+    /// A LSD radix sort (radix 8) dedicated to `u128`, without the `Radixable`
+    /// indirection in its inner loops.
+    ///
+    /// This sort is an unstable radix sort.
+    lsd_u128, u128, key: key
+}
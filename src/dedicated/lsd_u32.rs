@@ -1,9 +1,16 @@
+use alloc::vec;
+use alloc::vec::Vec;
+
 use super::super::algo::k_way_merge::k_way_merge;
 use super::super::algo::verge_sort_heuristic::verge_sort_preprocessing;
+use super::super::sorts::msd_sort::copy_by_histogram_wc;
 use super::super::sorts::utils::{
     copy_nonoverlapping, get_empty_histograms, only_one_bucket_filled,
     prefix_sums, Params,
 };
+use super::super::sorts::utils_simd::{
+    fill_histograms_u32, simd_histograms_available, KeyTransform,
+};
 use super::super::Radixable;
 
 const UNROLL_SIZE: usize = 4;
@@ -49,6 +56,19 @@ pub fn copy_by_histogram(
 }
 
 fn get_full_histograms(arr: &mut [u32], p: &Params) -> Vec<Vec<usize>> {
+    if simd_histograms_available(arr.len(), p.radix) {
+        let mut histograms =
+            get_empty_histograms(p.max_level, p.radix_range);
+        fill_histograms_u32(
+            arr,
+            p.radix,
+            p.max_level,
+            KeyTransform::Identity,
+            &mut histograms,
+        );
+        return histograms;
+    }
+
     let mut histograms = get_empty_histograms(p.max_level, p.radix_range);
     let default_mask = arr[0].default_mask(p.radix);
     let shift = p.radix as u32;
@@ -229,14 +249,25 @@ pub fn lsd_radixsort_body(arr: &mut [u32], p: Params) {
         let (mask, shift) = dummy.get_mask_and_shift(&p.new_level(level));
         let (_, mut heads, _) = prefix_sums(&histograms[level]);
 
-        copy_by_histogram(
-            source.len(),
-            &mut source,
-            &mut destination,
-            &mut heads,
-            mask,
-            shift,
-        );
+        if p.write_combining {
+            copy_by_histogram_wc(
+                source.len(),
+                &mut source,
+                &mut destination,
+                &mut heads,
+                mask,
+                shift,
+            );
+        } else {
+            copy_by_histogram(
+                source.len(),
+                &mut source,
+                &mut destination,
+                &mut heads,
+                mask,
+                shift,
+            );
+        }
 
         index = 1 - index;
 
@@ -269,7 +300,9 @@ pub fn lsd_u32(arr: &mut [u32]) {
         return;
     }
 
-    let params = Params::new(0, radix, offset, max_level);
+    // Above this size, the scatter steps are bound by the TLB and cache misses.
+    let params = Params::new(0, radix, offset, max_level)
+        .with_write_combining(arr.len() >= 50_000_000);
 
     let mut separators = verge_sort_preprocessing(arr, radix, &|arr, _| {
         lsd_radixsort_body(arr, params);
@@ -0,0 +1,27 @@
+use alloc::vec::Vec;
+
+use super::super::sorts::utils::Params;
+use super::super::sorts::utils_simd::{fill_histograms_u64, KeyTransform};
+
+#[inline]
+fn key(v: u64) -> u64 { v }
+
+fn simd_histograms(arr: &[u64], p: &Params, histograms: &mut [Vec<usize>]) {
+    fill_histograms_u64(
+        arr,
+        p.radix,
+        p.max_level,
+        KeyTransform::Identity,
+        histograms,
+    );
+}
+
+lsd_kernel! {
+    /// # LSD sort for `u64`
+    ///
+    /// A LSD radix sort (radix 8) dedicated to `u64`, without the `Radixable`
+    /// indirection in its inner loops.
+    ///
+    /// This sort is an unstable radix sort.
+    lsd_u64, u64, key: key, simd: simd_histograms
+}
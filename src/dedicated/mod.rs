@@ -1,3 +1,11 @@
+#[macro_use]
+mod lsd_kernel;
+
 pub mod cs_u16;
 pub mod lsd_f32;
+pub mod lsd_f64;
+pub mod lsd_i32;
+pub mod lsd_i64;
+pub mod lsd_u128;
 pub mod lsd_u32;
+pub mod lsd_u64;
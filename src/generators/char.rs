@@ -60,6 +60,16 @@ pub fn helper_random_array_charset_vden_char(size: usize) -> Vec<char> {
         .collect::<Vec<char>>()
 }
 
+pub fn helper_random_array_clustered_char(size: usize) -> Vec<char> {
+    (0..size)
+        .into_par_iter()
+        .map(|_| {
+            let offset: u32 = thread_rng().gen_range(0, 256);
+            std::char::from_u32(0x4e00 + offset).unwrap()
+        })
+        .collect::<Vec<char>>()
+}
+
 pub fn generators_char(
 ) -> Vec<(&'static dyn Fn(usize) -> Vec<char>, &'static str)> {
     vec![
@@ -68,5 +78,6 @@ pub fn generators_char(
         (&helper_random_array_charset_char, "-- Charset    :"),
         (&helper_random_array_charset_den_char, "-- Charset Den:"),
         (&helper_random_array_charset_vden_char, "-- Charset VDe:"),
+        (&helper_random_array_clustered_char, "-- Clustered  :"),
     ]
 }
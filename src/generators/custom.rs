@@ -384,3 +384,56 @@ pub fn generators_structusize(
 ) -> Vec<(&'static dyn Fn(usize) -> Vec<StructUsize>, &'static str)> {
     vec![(&helper_random_array_uniform_structusize, "-- Unif       :")]
 }
+
+// Uniform
+pub fn helper_random_array_uniform_structownedu64(
+    size: usize,
+) -> Vec<StructOwnedU64> {
+    (0..size)
+        .into_par_iter()
+        .map(|_| {
+            let mut rng = thread_rng();
+            let value = rng.gen::<u64>();
+            StructOwnedU64 { value, other: value.to_string() }
+        })
+        .collect::<Vec<StructOwnedU64>>()
+}
+
+// Small keys, a lot of duplicates
+pub fn helper_random_array_small_structownedu64(
+    size: usize,
+) -> Vec<StructOwnedU64> {
+    (0..size)
+        .into_par_iter()
+        .map(|i| {
+            let value = thread_rng().gen_range(0, 1000);
+            StructOwnedU64 { value, other: i.to_string() }
+        })
+        .collect::<Vec<StructOwnedU64>>()
+}
+
+pub fn generators_structownedu64(
+) -> Vec<(&'static dyn Fn(usize) -> Vec<StructOwnedU64>, &'static str)> {
+    vec![
+        (&helper_random_array_uniform_structownedu64, "-- Unif       :"),
+        (&helper_random_array_small_structownedu64, "-- Small      :"),
+    ]
+}
+
+// Uniform
+pub fn helper_random_array_uniform_structownedf32(
+    size: usize,
+) -> Vec<StructOwnedF32> {
+    (0..size)
+        .into_par_iter()
+        .map(|i| {
+            let value = thread_rng().gen::<f32>() * 2.0 - 1.0;
+            StructOwnedF32 { value, other: i.to_le_bytes().to_vec() }
+        })
+        .collect::<Vec<StructOwnedF32>>()
+}
+
+pub fn generators_structownedf32(
+) -> Vec<(&'static dyn Fn(usize) -> Vec<StructOwnedF32>, &'static str)> {
+    vec![(&helper_random_array_uniform_structownedf32, "-- Unif       :")]
+}
@@ -269,6 +269,14 @@ pub fn helper_random_array_push_middle_f64(size: usize) -> Vec<f64> {
     array
 }
 
+// Clustered
+pub fn helper_random_array_clustered_f64(size: usize) -> Vec<f64> {
+    (0..size)
+        .into_par_iter()
+        .map(|_| thread_rng().gen_range(1000.0, 1001.0))
+        .collect::<Vec<f64>>()
+}
+
 pub fn generators_f64(
 ) -> Vec<(&'static dyn Fn(usize) -> Vec<f64>, &'static str)> {
     vec![
@@ -276,6 +284,7 @@ pub fn generators_f64(
         (&helper_random_array_small_f64, "-- Small 10^9 :"),
         (&helper_random_array_small_plus_f64, "-- Small+10^9 :"),
         (&helper_random_array_small_minus_f64, "-- Small-10^9 :"),
+        (&helper_random_array_clustered_f64, "-- Clustered  :"),
         (&helper_random_array_very_small_f64, "-- Small 10^6 :"),
         (&helper_random_array_very_small_plus_f64, "-- Small+10^6 :"),
         (&helper_random_array_very_small_minus_f64, "-- Small-10^6 :"),
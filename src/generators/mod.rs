@@ -8,7 +8,8 @@
 #[allow(dead_code)] pub mod signed_i32;
 #[allow(dead_code)] pub mod signed_i64;
 #[allow(dead_code)] pub mod signed_i8;
-// #[allow(dead_code)] pub mod string;
+#[allow(dead_code)] pub mod string;
+#[allow(dead_code)] pub mod tuple;
 #[allow(dead_code)] pub mod unsigned_u128;
 #[allow(dead_code)] pub mod unsigned_u16;
 #[allow(dead_code)] pub mod unsigned_u32;
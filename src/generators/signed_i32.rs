@@ -231,12 +231,21 @@ pub fn helper_random_array_normale_30_i32(size: usize) -> Vec<i32> {
     helper_normal(size, 1_000_000_000.0)
 }
 
+// Clustered
+pub fn helper_random_array_clustered_i32(size: usize) -> Vec<i32> {
+    (0..size)
+        .into_par_iter()
+        .map(|_| thread_rng().gen_range(1_000_000_000, 1_000_065_536))
+        .collect::<Vec<i32>>()
+}
+
 pub fn generators_i32(
 ) -> Vec<(&'static dyn Fn(usize) -> Vec<i32>, &'static str)> {
     vec![
         (&helper_random_array_uniform_i32, "-- Unif       :"),
         (&helper_random_array_109_i32, "-- +-10^9     :"),
         (&helper_random_array_small_i32, "-- Small      :"),
+        (&helper_random_array_clustered_i32, "-- Clustered  :"),
         (&helper_random_array_ascending_i32, "-- Asc        :"),
         (&helper_random_array_descending_i32, "-- Desc       :"),
         (&helper_random_array_allequals_i32, "-- Equal      :"),
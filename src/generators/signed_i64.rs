@@ -296,11 +296,20 @@ pub fn helper_random_array_normale_63_i64(size: usize) -> Vec<i64> {
     helper_normal(size, 4_000_000_000_000_000_000.0)
 }
 
+// Clustered
+pub fn helper_random_array_clustered_i64(size: usize) -> Vec<i64> {
+    (0..size)
+        .into_par_iter()
+        .map(|_| -5_000_000_000_000 + thread_rng().gen_range(0, 1 << 20))
+        .collect::<Vec<i64>>()
+}
+
 pub fn generators_i64(
 ) -> Vec<(&'static dyn Fn(usize) -> Vec<i64>, &'static str)> {
     vec![
         (&helper_random_array_uniform_i64, "-- Unif       :"),
         (&helper_random_array_109_i64, "-- +-10^9     :"),
+        (&helper_random_array_clustered_i64, "-- Clustered  :"),
         (&helper_random_array_ascending_i64, "-- Asc        :"),
         (&helper_random_array_asc1pm_i64, "-- Asc1pm     :"),
         (&helper_random_array_asc1pct_i64, "-- Asc1pct    :"),
@@ -2,18 +2,80 @@ use rand::distributions::Alphanumeric;
 use rand::{thread_rng, Rng};
 use rayon::prelude::*;
 
+// Uniform
 pub fn helper_random_array_uniform_string(
     size: usize,
     string_size: usize,
 ) -> Vec<String> {
-    (0..size).into_par_iter().map(|_|
-        thread_rng().sample_iter(&Alphanumeric)
-            .take(string_size)
-            .collect::<String>()
-    ).collect::<Vec<u64>>()
+    (0..size)
+        .into_par_iter()
+        .map(|_| {
+            thread_rng()
+                .sample_iter(&Alphanumeric)
+                .take(string_size)
+                .collect::<String>()
+        })
+        .collect::<Vec<String>>()
+}
+
+// Random length between 0 and string_size
+pub fn helper_random_array_variable_length_string(
+    size: usize,
+    string_size: usize,
+) -> Vec<String> {
+    (0..size)
+        .into_par_iter()
+        .map(|_| {
+            let mut rng = thread_rng();
+            let length = rng.gen_range(0, string_size + 1);
+            rng.sample_iter(&Alphanumeric).take(length).collect::<String>()
+        })
+        .collect::<Vec<String>>()
+}
+
+// Long common prefix, a lot of duplicates
+pub fn helper_random_array_prefix_string(
+    size: usize,
+    string_size: usize,
+) -> Vec<String> {
+    let prefix = "voracious_".repeat(string_size / 10 + 1);
+    (0..size)
+        .into_par_iter()
+        .map(|_| {
+            let mut rng = thread_rng();
+            let length = rng.gen_range(0, 3);
+            let suffix = (0..length)
+                .map(|_| rng.gen_range(b'a', b'd') as char)
+                .collect::<String>();
+            format!("{}{}", prefix, suffix)
+        })
+        .collect::<Vec<String>>()
+}
+
+// Non ASCII characters
+pub fn helper_random_array_unicode_string(
+    size: usize,
+    string_size: usize,
+) -> Vec<String> {
+    let chars = ['a', 'é', 'ß', '字', '🦀', 'Z'];
+    (0..size)
+        .into_par_iter()
+        .map(|_| {
+            let mut rng = thread_rng();
+            let length = rng.gen_range(0, string_size + 1);
+            (0..length)
+                .map(|_| chars[rng.gen_range(0, chars.len())])
+                .collect::<String>()
+        })
+        .collect::<Vec<String>>()
 }
 
 pub fn generators_string(
 ) -> Vec<(&'static dyn Fn(usize, usize) -> Vec<String>, &'static str)> {
-    vec![(&helper_random_array_uniform_string, "-- Unif       :")]
+    vec![
+        (&helper_random_array_uniform_string, "-- Unif       :"),
+        (&helper_random_array_variable_length_string, "-- Var length :"),
+        (&helper_random_array_prefix_string, "-- Prefix     :"),
+        (&helper_random_array_unicode_string, "-- Unicode    :"),
+    ]
 }
@@ -0,0 +1,108 @@
+use rand::{thread_rng, Rng};
+use rayon::prelude::*;
+
+type TupleU32U64 = (u32, u64);
+type TupleI16F32Char = (i16, f32, char);
+type TupleU8I8U16Bool = (u8, i8, u16, bool);
+
+// Uniform
+pub fn helper_random_array_uniform_tuple_u32_u64(
+    size: usize,
+) -> Vec<TupleU32U64> {
+    (0..size)
+        .into_par_iter()
+        .map(|_| {
+            let mut rng = thread_rng();
+            (rng.gen::<u32>(), rng.gen::<u64>())
+        })
+        .collect::<Vec<TupleU32U64>>()
+}
+
+// Small first field, so the next fields are used to break ties
+pub fn helper_random_array_small_tuple_u32_u64(
+    size: usize,
+) -> Vec<TupleU32U64> {
+    (0..size)
+        .into_par_iter()
+        .map(|_| {
+            let mut rng = thread_rng();
+            (rng.gen_range(0, 16), rng.gen::<u64>())
+        })
+        .collect::<Vec<TupleU32U64>>()
+}
+
+pub fn generators_tuple_u32_u64(
+) -> Vec<(&'static dyn Fn(usize) -> Vec<TupleU32U64>, &'static str)> {
+    vec![
+        (&helper_random_array_uniform_tuple_u32_u64, "-- Unif       :"),
+        (&helper_random_array_small_tuple_u32_u64, "-- Small      :"),
+    ]
+}
+
+// Small first and second fields, so the next fields are used to break ties
+pub fn helper_random_array_small_tuple_i16_f32_char(
+    size: usize,
+) -> Vec<TupleI16F32Char> {
+    (0..size)
+        .into_par_iter()
+        .map(|_| {
+            let mut rng = thread_rng();
+            let f = rng.gen_range(-4, 4) as f32 / 2.0;
+            (rng.gen_range(-8, 8), f, rng.gen::<char>())
+        })
+        .collect::<Vec<TupleI16F32Char>>()
+}
+
+pub fn generators_tuple_i16_f32_char(
+) -> Vec<(&'static dyn Fn(usize) -> Vec<TupleI16F32Char>, &'static str)> {
+    vec![(&helper_random_array_small_tuple_i16_f32_char, "-- Small      :")]
+}
+
+// Small fields, so the last fields are used to break ties
+pub fn helper_random_array_small_tuple_u8_i8_u16_bool(
+    size: usize,
+) -> Vec<TupleU8I8U16Bool> {
+    (0..size)
+        .into_par_iter()
+        .map(|_| {
+            let mut rng = thread_rng();
+            (
+                rng.gen_range(0, 4),
+                rng.gen_range(-4, 4),
+                rng.gen_range(0, 4),
+                rng.gen::<bool>(),
+            )
+        })
+        .collect::<Vec<TupleU8I8U16Bool>>()
+}
+
+pub fn generators_tuple_u8_i8_u16_bool(
+) -> Vec<(&'static dyn Fn(usize) -> Vec<TupleU8I8U16Bool>, &'static str)> {
+    vec![(&helper_random_array_small_tuple_u8_i8_u16_bool, "-- Small      :")]
+}
+
+// Uniform
+pub fn helper_random_array_uniform_bytes_5(size: usize) -> Vec<[u8; 5]> {
+    (0..size)
+        .into_par_iter()
+        .map(|_| thread_rng().gen::<[u8; 5]>())
+        .collect::<Vec<[u8; 5]>>()
+}
+
+pub fn generators_bytes_5(
+) -> Vec<(&'static dyn Fn(usize) -> Vec<[u8; 5]>, &'static str)> {
+    vec![(&helper_random_array_uniform_bytes_5, "-- Unif       :")]
+}
+
+// Uniform
+pub fn helper_random_array_uniform_bytes_16(size: usize) -> Vec<[u8; 16]> {
+    (0..size)
+        .into_par_iter()
+        .map(|_| thread_rng().gen::<[u8; 16]>())
+        .collect::<Vec<[u8; 16]>>()
+}
+
+pub fn generators_bytes_16(
+) -> Vec<(&'static dyn Fn(usize) -> Vec<[u8; 16]>, &'static str)> {
+    vec![(&helper_random_array_uniform_bytes_16, "-- Unif       :")]
+}
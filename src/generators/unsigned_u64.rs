@@ -427,11 +427,20 @@ pub fn helper_random_array_normale_63_u64(size: usize) -> Vec<u64> {
     helper_normal(size, 4_000_000_000_000_000_000.0)
 }
 
+// Clustered (millisecond timestamps)
+pub fn helper_random_array_clustered_u64(size: usize) -> Vec<u64> {
+    (0..size)
+        .into_par_iter()
+        .map(|_| 1_700_000_000_000 + thread_rng().gen_range(0, 1 << 24))
+        .collect::<Vec<u64>>()
+}
+
 pub fn generators_u64(
 ) -> Vec<(&'static dyn Fn(usize) -> Vec<u64>, &'static str)> {
     vec![
         (&helper_random_array_uniform_u64, "-- Unif       :"),
         (&helper_random_array_uniform_10_9_u64, "-- Unif 10^9  :"),
+        (&helper_random_array_clustered_u64, "-- Clustered  :"),
         (&helper_random_array_small_size1_u64, "-- Small 1    :"),
         (&helper_random_array_small_size2_u64, "-- Small 2    :"),
         (&helper_random_array_small_size3_u64, "-- Small 3    :"),
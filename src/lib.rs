@@ -32,23 +32,41 @@
 //! - [`usize`](https://doc.rust-lang.org/stable/std/primitive.usize.html)
 //! - [`struct`](https://doc.rust-lang.org/std/keyword.struct.html)
 //!   - The struct must be mapped to a key. The key must be among the aforementioned types (bool, char, f32, etc...).
-//!   - **Single thread** version: the struct must implement **`PartialOrd`**, **`PartialEq`**, **`Copy`** and **`Radixable`** traits.
-//!   - **Multi thread** version: the struct must implement **`PartialOrd`**, **`PartialEq`**, **`Copy`**, **`Send`**, **`Sync`** and **`Radixable`** traits.
-//!
-//! Vocarious sort can only sort in ascending order. You can call the
-//! [`reverse`](https://doc.rust-lang.org/std/vec/struct.Vec.html#method.reverse)
-//! method if desired.
+//!   - **Single thread** version: the struct must implement **`Copy`** and **`Radixable`** traits.
+//!   - **Multi thread** version: the struct must implement **`Copy`**, **`Send`**, **`Sync`** and **`Radixable`** traits.
+//!   - The elements are only compared by their key, even by the comparison sorts used for the small arrays, so `PartialOrd` is not needed.
+//!
+//! Vocarious sort sorts in ascending order by default. Each method has a
+//! descending order counterpart (suffixed by `_desc`): the radix key is
+//! flipped, so there is no extra pass and the stable sort stays stable.
+//!
+//! Tuples of up to 4 of the aforementioned types, and byte arrays `[u8; N]`,
+//! can be sorted too, and can be used as the key of a struct. The bits of the
+//! fields are concatenated, the first field being the most significant, so
+//! the tuple is sorted in lexicographic order in a single radix sort. The
+//! concatenated key cannot be wider than 128 bits (e.g. `(u32, u64)`,
+//! `(i16, f32, char)` or `[u8; 16]`). A wider byte array does not build, but
+//! a wider tuple, even a valid-looking one such as `(u64, u64, u64)`, panics
+//! at runtime with "A composite key cannot be wider than 128 bits". It is
+//! the same for the composite keys of `radixable!`.
+//!
+//! ```compile_fail
+//! use voracious_radix_sort::RadixSort;
 //!
-//! Because of Rust Orphan Rule, we chose not to support tuple sorting. You
-//! can use [struct](https://doc.rust-lang.org/std/keyword.struct.html) instead.
+//! let mut arr: Vec<[u8; 32]> = vec![[0; 32]; 10];
+//! arr.voracious_sort();
+//! ```
 //!
 //! ## Version
 //!
+//! Minimum supported Rust version: 1.63.0 (`std::thread::scope`,
+//! `try_reserve_exact` and the panics in constants).
+//!
 //! Last version tested/used:
-//! - Rustc: 1.46.0 stable
-//! - Rustfmt: 1.4.18 stable
-//! - Cargo: 1.46.0 stable
-//! - Clippy: 0.0.212
+//! - Rustc: 1.95.0 stable
+//! - Rustfmt: 1.9.0 stable
+//! - Cargo: 1.95.0 stable
+//! - Clippy: 0.1.95
 //!
 //! ## License
 //!
@@ -68,6 +86,31 @@
 //! voracious_radix_sort = { version = "1.2.0", features = ["voracious_multithread"] }
 //! ```
 //!
+//! If you want a multithread sort without the Rayon dependency:
+//! ```toml
+//! [dependencies]
+//! voracious_radix_sort = { version = "1.2.0", features = ["voracious_multithread_std"] }
+//! ```
+//!
+//! With this feature, the multithread methods below run on
+//! `std::thread::scope` threads with a small internal work queue: the
+//! unstable sorts use the `peeka_std_sort` function, and the stable sorts
+//! merge chunks sorted by the single thread stable sort. With both features,
+//! the Rayon sorts are used. The methods taking a Rayon `ThreadPool`, and the
+//! other multithread sorts, still need the "`voracious_multithread`" feature.
+//!
+//! ### `no_std`
+//!
+//! The crate only needs `core` and `alloc`. Without the default "`std`"
+//! feature, it is `no_std`, and the stable sorts fallback on the crate's own
+//! merge sort instead of the Rust stable sort:
+//! ```toml
+//! [dependencies]
+//! voracious_radix_sort = { version = "1.2.0", default-features = false }
+//! ```
+//!
+//! The multithread features need `std`.
+//!
 //! ### Environment variable
 //!
 //! To fully benefit from Voracious sort, it is better to add the environment
@@ -79,10 +122,52 @@
 //!
 //! ### Methods
 //!
-//! When the Crate is imported, three methods are added to vectors and slices:
+//! When the Crate is imported, these methods are added to vectors and slices:
 //! - `voracious_sort()` (single thread).
 //! - `voracious_stable_sort()` (single thread).
-//! - `voracious_mt_sort()` (multi thread). (with the "`voracious_multithread`" feature)
+//! - `voracious_mt_sort()` (multi thread). (with a multithread feature)
+//! - `voracious_mt_stable_sort()` (multi thread). (with a multithread feature)
+//!
+//! And their descending order counterparts:
+//! - `voracious_sort_desc()` (single thread).
+//! - `voracious_stable_sort_desc()` (single thread).
+//! - `voracious_mt_sort_desc()` (multi thread). (with a multithread feature)
+//! - `voracious_mt_stable_sort_desc()` (multi thread). (with a multithread feature)
+//!
+//! And their fallible counterparts, which return a `Result<(), SortError>`
+//! instead of aborting when the scratch memory cannot be allocated:
+//! - `try_voracious_sort()` (single thread). If the allocation fails, an
+//!   in place radix sort is used instead, and `SortError::AllocationFailed`
+//!   is returned only if its histograms cannot be allocated either.
+//! - `try_voracious_stable_sort()` (single thread). If the allocation fails,
+//!   an in place merge sort is used instead, so it never returns an error.
+//!
+//! To cap the memory used by the sort, `voracious_sort_with_memory_limit(arr,
+//! bytes)` switches to an in place radix sort when the scratch memory of the
+//! sort chosen by `voracious_sort` would be larger than `bytes`, and to a
+//! comparison sort when even the histograms of the in place sort do not fit.
+//!
+//! ### Thread pools
+//!
+//! The multithread sorts take a number of threads `thread_n` and run on a new
+//! Rayon thread pool of `thread_n` threads. If `thread_n` is 0, no thread pool
+//! is created: the sort runs on the current Rayon pool (the global pool, or
+//! the pool of the calling Rayon task) with all its threads. This is the best
+//! choice when sorting from inside an existing parallel pipeline.
+//!
+//! To run on a given pool, use `voracious_mt_sort_in_pool(&pool)` or
+//! `voracious_mt_stable_sort_in_pool(&pool)`.
+//!
+//! ```ignore
+//! use voracious_radix_sort::RadixSort;
+//!
+//! let pool = rayon::ThreadPoolBuilder::new().num_threads(4).build().unwrap();
+//! let mut array: Vec<u64> = (0..2_000_000).rev().collect();
+//!
+//! array.voracious_mt_sort_in_pool(&pool);
+//! // Or, on the global Rayon pool:
+//! array.voracious_mt_stable_sort(0);
+//! ```
 //!
 //! ### Example
 //!
@@ -108,6 +193,236 @@
 //! array.voracious_mt_sort(4);
 //!
 //! assert_eq!(array, vec![1, 2, 2, 7, 7, 8, 8, 9, 9, 41, 45, 56, 65, 74]);
+//!
+//! let mut array = vec![2, 45, 8, 7, 9, 65, 8, 74, 1, 2, 56, 9, 7, 41];
+//!
+//! array.voracious_sort_desc();
+//!
+//! assert_eq!(array, vec![74, 65, 56, 45, 41, 9, 9, 8, 8, 7, 7, 2, 2, 1]);
+//! ```
+//!
+//! ### Sorting by key
+//!
+//! With the `RadixSortByKey` trait, any vector or slice can be sorted by a key
+//! extracted with a closure, as long as the key is among the aforementioned
+//! primitive types. The elements don't need to implement any trait, not even
+//! `Copy`:
+//! - `voracious_sort_by_key(f)` (single thread).
+//! - `voracious_stable_sort_by_key(f)` (single thread).
+//! - `voracious_mt_sort_by_key(f, thread_n)` (multi thread). (with the "`voracious_multithread`" feature)
+//!
+//! The keys are extracted once, sorted with their index and then the elements
+//! are moved to their final position.
+//!
+//! ```
+//! use voracious_radix_sort::RadixSortByKey;
+//!
+//! let mut array = vec![
+//!     (String::from("c"), 3u32),
+//!     (String::from("a"), 1u32),
+//!     (String::from("b"), 2u32),
+//! ];
+//!
+//! array.voracious_sort_by_key(|item| item.1);
+//!
+//! assert_eq!(array, vec![
+//!     (String::from("a"), 1u32),
+//!     (String::from("b"), 2u32),
+//!     (String::from("c"), 3u32),
+//! ]);
+//! ```
+//!
+//! ### Argsort
+//!
+//! With the `RadixArgSort` trait, the permutation that sorts a vector or a
+//! slice can be computed without moving the data, for example to reorder
+//! several parallel arrays:
+//! - `voracious_argsort()` (single thread).
+//! - `voracious_stable_argsort()` (single thread).
+//! - `voracious_mt_argsort(thread_n)` (multi thread). (with the "`voracious_multithread`" feature)
+//!
+//! Only the indices are radix sorted, by the key of `arr[index]`: the extra
+//! memory is one `usize` per element for `voracious_argsort`, two for
+//! `voracious_stable_argsort`. The `voracious_argsort_u32()`,
+//! `voracious_stable_argsort_u32()` and `voracious_mt_argsort_u32(thread_n)`
+//! variants return `u32` indices instead, which halves this memory; they
+//! panic if the array has more than 2^32 elements.
+//!
+//! ```
+//! use voracious_radix_sort::RadixArgSort;
+//!
+//! let prices = vec![3.5, -1.0, 2.25, 0.0];
+//! let names = vec!["c", "a", "b", "z"];
+//!
+//! let indices = prices.voracious_stable_argsort();
+//!
+//! assert_eq!(indices, vec![1, 3, 2, 0]);
+//! let names: Vec<&str> = indices.iter().map(|i| names[*i]).collect();
+//! assert_eq!(names, vec!["a", "z", "b", "c"]);
+//! ```
+//!
+//! ### Sorting pairs (structure of arrays)
+//!
+//! When the keys and the payloads are stored in two separate slices, they can
+//! be sorted together with `voracious_sort_pairs(keys, values)` and
+//! `voracious_stable_sort_pairs(keys, values)`. Only the keys are read, the
+//! values are moved with their key. The keys can be any `Radixable` type, the
+//! values any `Copy` type.
+//!
+//! ```
+//! use voracious_radix_sort::voracious_stable_sort_pairs;
+//!
+//! let mut keys = vec![30u32, 10, 20, 10];
+//! let mut values = vec!['c', 'a', 'b', 'z'];
+//!
+//! voracious_stable_sort_pairs(&mut keys, &mut values);
+//!
+//! assert_eq!(keys, vec![10, 10, 20, 30]);
+//! assert_eq!(values, vec!['a', 'z', 'b', 'c']);
+//! ```
+//!
+//! ### Sorting without allocation
+//!
+//! The radix sorts allocate a buffer as long as the array on each call. When
+//! many small arrays are sorted in a hot loop, the memory can be provided by
+//! the caller with `voracious_sort_with_buffer(arr, &mut buffer)`, or kept
+//! between two sorts by a `SortContext`. Once a context has sorted its largest
+//! array (or has been created with `SortContext::with_capacity`), sorting does
+//! not allocate at all. Both use a stable LSD radix sort.
+//!
+//! ```
+//! use voracious_radix_sort::{voracious_sort_with_buffer, SortContext};
+//!
+//! let mut buffer = vec![0u32; 1000];
+//! let mut arr: Vec<u32> = (0..1000).rev().collect();
+//! voracious_sort_with_buffer(&mut arr, &mut buffer);
+//! assert_eq!(arr, (0..1000).collect::<Vec<u32>>());
+//!
+//! let mut context = SortContext::new();
+//! for batch in 0..10 {
+//!     let mut arr: Vec<f64> =
+//!         (0..500).map(|i| (i % 7 - batch) as f64).collect();
+//!     context.voracious_sort(&mut arr);
+//!     assert!(arr.windows(2).all(|w| w[0] <= w[1]));
+//! }
+//! ```
+//!
+//! ### Partial sort and selection
+//!
+//! When only the smallest or the largest elements are needed, a full sort can
+//! be avoided:
+//! - `voracious_select_nth_unstable(arr, k)` puts the element of rank `k` at
+//!   index `k`, the lower elements before it and the greater elements after it.
+//! - `voracious_partial_sort(arr, k)` sorts the `k` smallest elements into
+//!   `arr[..k]`.
+//! - `voracious_top_k(arr, k)` returns the `k` largest elements, in descending
+//!   order, at the end of the array.
+//!
+//! They are MSD radix sorts which only recurse into the bucket that contains
+//! the rank `k`. Their multithread counterparts are `voracious_mt_*`. (with the
+//! "`voracious_multithread`" feature)
+//!
+//! ```
+//! use voracious_radix_sort::{
+//!     voracious_partial_sort, voracious_select_nth_unstable, voracious_top_k,
+//! };
+//!
+//! let mut arr: Vec<u32> = (0..1000).map(|i| (i * 7919) % 1000).collect();
+//!
+//! let (_, median, _) = voracious_select_nth_unstable(&mut arr, 500);
+//! assert_eq!(*median, 500);
+//!
+//! voracious_partial_sort(&mut arr, 3);
+//! assert_eq!(&arr[..3], &[0, 1, 2]);
+//!
+//! assert_eq!(voracious_top_k(&mut arr, 3), &[999, 998, 997]);
+//! ```
+//!
+//! ### Presorted inputs
+//!
+//! `voracious_sort_adaptive(arr)` goes through the Verge sort pre-processing
+//! for any key type and any length: the long runs of the array (at least
+//! `n / log2(n)` elements) are kept, for example a sorted or reverse sorted
+//! array, sorted arrays concatenated, or a sorted array with a few elements
+//! appended. Only the elements out of these runs are radix sorted, then the
+//! runs are merged. `voracious_mt_sort_adaptive(arr, thread_n)` is its
+//! multithread counterpart. (with the "`voracious_multithread`" feature)
+//!
+//! `find_runs(arr)` returns the long runs found by the heuristic, from left
+//! to right.
+//!
+//! ```
+//! use voracious_radix_sort::{find_runs, voracious_sort_adaptive, Run};
+//!
+//! let mut arr: Vec<i32> = (0..1000).chain((0..500).rev()).collect();
+//!
+//! assert_eq!(find_runs(&arr), vec![
+//!     Run { start: 0, end: 1000, descending: false },
+//!     Run { start: 1000, end: 1500, descending: true },
+//! ]);
+//!
+//! voracious_sort_adaptive(&mut arr);
+//! assert!(arr.windows(2).all(|w| w[0] <= w[1]));
+//! ```
+//!
+//! ### Sorting strings
+//!
+//! With the `RadixSortString` trait, vectors and slices of `String`, `&str`
+//! and `Vec<u8>` can be sorted with a byte-wise MSD radix sort, in
+//! lexicographic order:
+//! - `voracious_sort()` (single thread).
+//! - `voracious_stable_sort()` (single thread).
+//! - `voracious_mt_sort()` (multi thread). (with a multithread feature)
+//!
+//! ```
+//! use voracious_radix_sort::RadixSortString;
+//!
+//! let mut array = vec!["voracious", "radix", "sort", "rad", "", "sorted"];
+//!
+//! array.voracious_sort();
+//!
+//! assert_eq!(array, vec!["", "rad", "radix", "sort", "sorted", "voracious"]);
+//! ```
+//!
+//! ### Sorting structs which are not `Copy`
+//!
+//! A struct holding a `String`, a `Vec` or a `Box` cannot implement
+//! `Radixable`, which requires `Copy`. It can implement `RadixableOwned`
+//! instead, and then be sorted with the `RadixSortOwned` trait:
+//! - `voracious_sort()` (single thread, in place MSD radix sort).
+//! - `voracious_stable_sort()` (single thread, out of place LSD radix sort).
+//!
+//! The keys are extracted once and the elements are moved with their key,
+//! they are never cloned. The sorts are also available as functions:
+//! `msd_radixsort_owned`, `lsd_stable_radixsort_owned` and
+//! `thiel_radixsort_owned` (out of place stable Thiel sort).
+//!
+//! ```
+//! use voracious_radix_sort::{RadixSortOwned, RadixableOwned};
+//!
+//! #[derive(Debug)]
+//! pub struct Record {
+//!     id: u64,
+//!     name: String,
+//! }
+//! impl RadixableOwned<u64> for Record {
+//!     type Key = u64;
+//!     #[inline]
+//!     fn key(&self) -> Self::Key {
+//!         self.id
+//!     }
+//! }
+//!
+//! let mut array = vec![
+//!     Record { id: 3, name: String::from("c") },
+//!     Record { id: 1, name: String::from("a") },
+//!     Record { id: 2, name: String::from("b") },
+//! ];
+//!
+//! array.voracious_stable_sort();
+//!
+//! let names: Vec<&str> = array.iter().map(|r| r.name.as_str()).collect();
+//! assert_eq!(names, vec!["a", "b", "c"]);
 //! ```
 //!
 //! ### Implementing a custom `struct`
@@ -115,8 +430,6 @@
 //! Let's do it through an example.
 //!
 //! ```
-//! use std::cmp::Ordering;
-//!
 //! // We need a struct.
 //! // We want, for example, to sort these structs by the key: "value".
 //! // This struct must implement the Copy and Clone traits, we can just derive them.
@@ -127,11 +440,8 @@
 //!     value: f32,
 //!     other: usize,
 //! }
-//! impl PartialOrd for Custom {
-//!     fn partial_cmp(&self, other: &Custom) -> Option<Ordering> {
-//!         self.value.partial_cmp(&other.value)
-//!     }
-//! }
+//! // The sorts only compare the keys: `PartialEq` is only needed by the
+//! // `assert_eq!` of the test below.
 //! impl PartialEq for Custom {
 //!     fn eq(&self, other: &Self) -> bool {
 //!         self.value == other.value
@@ -141,18 +451,12 @@
 //!
 //! And then we have to implement the `Radixable` traits:
 //! ```
-//! # use std::cmp::Ordering;
 //! use voracious_radix_sort::Radixable;
 //! # #[derive(Copy, Clone, Debug)]
 //! # pub struct Custom {
 //! #     value: f32,
 //! #     other: usize,
 //! # }
-//! # impl PartialOrd for Custom {
-//! #     fn partial_cmp(&self, other: &Custom) -> Option<Ordering> {
-//! #         self.value.partial_cmp(&other.value)
-//! #     }
-//! # }
 //! # impl PartialEq for Custom {
 //! #     fn eq(&self, other: &Self) -> bool {
 //! #         self.value == other.value
@@ -174,17 +478,11 @@
 //! ```
 //! use voracious_radix_sort::RadixSort;
 //! # use voracious_radix_sort::Radixable;
-//! # use std::cmp::Ordering;
 //! # #[derive(Copy, Clone, Debug)]
 //! # pub struct Custom {
 //! #     value: f32,
 //! #     other: usize,
 //! # }
-//! # impl PartialOrd for Custom {
-//! #     fn partial_cmp(&self, other: &Custom) -> Option<Ordering> {
-//! #         self.value.partial_cmp(&other.value)
-//! #     }
-//! # }
 //! # impl PartialEq for Custom {
 //! #     fn eq(&self, other: &Self) -> bool {
 //! #         self.value == other.value
@@ -245,18 +543,112 @@
 //! ]);
 //! ```
 //!
-//! ### Panics
+//! ### The `radixable!` macro
+//!
+//! The `radixable!` macro writes the `PartialOrd`, `PartialEq` and
+//! `Radixable` implementations from a field, a path of fields, several
+//! fields for a composite key (128 bits at most, as the tuples), or a key
+//! expression. It also implements them for a C-like enum, keyed by its
+//! discriminant. The generated `PartialOrd` compares the keys as the radix
+//! sorts do.
+//!
+//! ```
+//! use voracious_radix_sort::{radixable, RadixSort};
+//!
+//! #[derive(Copy, Clone, Debug)]
+//! pub struct Custom {
+//!     value: f32,
+//!     other: usize,
+//! }
+//! radixable!(Custom, f32, value);
+//!
+//! let mut array = vec![
+//!     Custom { value: 5.7, other: 29 },
+//!     Custom { value: 2.7, other: 23 },
+//! ];
+//! array.voracious_sort();
+//! assert_eq!(array[0].other, 23);
+//! ```
+//!
+//! ### Implementing a custom key type
+//!
+//! A key type which is not in this crate can be sorted too. It needs an
+//! order-preserving unsigned integer encoding (`into_keytype`) and its bit
+//! width (`type_size`) through the `RadixKey` trait, and an empty
+//! `Dispatcher` implementation. The sorts are then chosen from the bit
+//! width: `lsd_radixsort` for the narrow keys, `rollercoaster_sort` for the
+//! wider keys, and `peeka_sort` for the multithread sort.
+//!
+//! ```
+//! use voracious_radix_sort::{Dispatcher, RadixKey, RadixSort, Radixable};
+//!
+//! // Signed fixed point number, with 16 bits for the fraction.
+//! #[derive(Copy, Clone, Debug, PartialEq)]
+//! pub struct Fixed(i32);
+//!
+//! impl RadixKey for Fixed {
+//!     type Key = u32;
+//!     #[inline]
+//!     fn into_keytype(&self) -> u32 { (self.0 as u32) ^ 0x8000_0000 }
+//!     #[inline]
+//!     fn type_size(&self) -> usize { 32 }
+//!     #[inline]
+//!     fn usize_to_keytype(&self, item: usize) -> u32 { item as u32 }
+//!     #[inline]
+//!     fn keytype_to_usize(&self, item: u32) -> usize { item as usize }
+//!     #[inline]
+//!     fn default_key(&self) -> u32 { 0 }
+//!     #[inline]
+//!     fn one(&self) -> u32 { 1 }
+//! }
+//! impl<T: Radixable<Fixed>> Dispatcher<T, Fixed> for Fixed {}
+//! impl Radixable<Fixed> for Fixed {
+//!     type Key = Fixed;
+//!     #[inline]
+//!     fn key(&self) -> Fixed { *self }
+//! }
+//!
+//! let mut arr = vec![Fixed(3 << 16), Fixed(-1 << 15), Fixed(0)];
+//! arr.voracious_sort();
+//! assert_eq!(arr, vec![Fixed(-1 << 15), Fixed(0), Fixed(3 << 16)]);
+//! ```
+//!
+//! The `bits_sort`, `bits_stable_sort`, `bits_mt_sort` and
+//! `bits_in_place_sort` functions are the default methods of `Dispatcher`, so
+//! they can also be called from a `Dispatcher` implementation which overrides
+//! only some of the methods. A `Dispatcher` which overrides `voracious_sort`
+//! should also override `voracious_sort_scratch`, the bound of its scratch
+//! memory used by `voracious_sort_with_memory_limit`.
+//!
+//! ### Floats, NaN and signed zeros
+//!
+//! The radix sorts order the [`f32`](https://doc.rust-lang.org/stable/std/primitive.f32.html)
+//! and [`f64`](https://doc.rust-lang.org/stable/std/primitive.f64.html) keys
+//! like `total_cmp`: the negative NaNs first, then `-inf`, `-0.0` before
+//! `0.0`, `+inf`, and the positive NaNs last. The comparison fallbacks of the
+//! sorts compare the keys in the same order, so they do not panic on a NaN.
 //!
-//! For [`f32`](https://doc.rust-lang.org/stable/std/primitive.f32.html) and
-//! [`f64`](https://doc.rust-lang.org/stable/std/primitive.f64.html), if there is a
-//! [`NaN`](https://doc.rust-lang.org/stable/std/f64/constant.NAN.html) value or an
-//! [`INFINITY`](https://doc.rust-lang.org/std/f32/constant.INFINITY.html) or a
-//! [`NEG_INFINITY`](https://doc.rust-lang.org/std/f32/constant.INFINITY.html)
-//! in the [`vector`](https://doc.rust-lang.org/stable/std/vec/) or the
-//! [`slice`](https://doc.rust-lang.org/stable/std/primitive.slice.html), the
-//! behavior is not guaranteed.
+//! To choose another order, `voracious_sort_floats(arr, order)`,
+//! `voracious_stable_sort_floats(arr, order)` and
+//! `voracious_mt_sort_floats(arr, order, thread_n)` take a `FloatOrder`: the
+//! NaNs first, last, in the `total_cmp` order, or an error, and whether
+//! `-0.0` and `0.0` are equal.
 //!
-//! It might panic or not sort correctly the array.
+//! ```
+//! use voracious_radix_sort::{
+//!     voracious_sort_floats, FloatOrder, NanPolicy, SortError,
+//! };
+//!
+//! let mut arr = vec![1.0, f64::NAN, -0.0, f64::NEG_INFINITY, 0.0, -f64::NAN];
+//!
+//! voracious_sort_floats(&mut arr, FloatOrder::new(NanPolicy::Last)).unwrap();
+//! assert_eq!(&arr[..4], &[f64::NEG_INFINITY, -0.0, 0.0, 1.0]);
+//! assert!(arr[4].is_nan() && arr[5].is_nan());
+//! assert!(arr[1].is_sign_negative() && arr[2].is_sign_positive());
+//!
+//! let order = FloatOrder::new(NanPolicy::Error);
+//! assert_eq!(voracious_sort_floats(&mut arr, order), Err(SortError::NanKey));
+//! ```
 //!
 //! ## Dependencies
 //!
@@ -269,7 +661,11 @@
 //! - First, please, read: [PROFILING.md](https://github.com/lakwet/voracious_sort/blob/master/PROFILING.md).
 //! - These results are from the v1.0.0 version. It might vary a bit with v1.1.0.
 //! - Performances can vary depending on the profile you are using.
-//! - Please notice that dedicated sorts are faster than generic sorts.
+//! - Please notice that dedicated sorts are faster than the generic sort they
+//!   specialize (`lsd_radixsort`). The `voracious_sort` of `u64`, `i64`,
+//!   `f64`, `i32` and `u128` does not use them, because its DLSD,
+//!   Rollercoaster and LSD heuristic sorts are faster than a plain LSD sort on
+//!   these types.
 //! - Tests have been done on an AMD Ryzen 9 3950x,  32GB DDR4 RAM, MB X570 TUF
 //! Gaming.
 //! - For more benchmarks, please visit our [GitHub](https://github.com/lakwet/voracious_sort).
@@ -395,6 +791,13 @@
 //! For this purpose, I highly recommend you to clone the github project and use
 //! the provided benchmark.
 
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+#[macro_use]
+mod macros;
+
 mod algo;
 mod dedicated;
 #[cfg(feature = "voracious_multithread")]
@@ -410,25 +813,81 @@ mod types;
 pub use traits::dispatcher::Dispatcher;
 pub use traits::radix_key::RadixKey;
 pub use traits::radixable::Radixable;
+pub use traits::radixable_owned::RadixableOwned;
 pub use traits::radixsort::RadixSort;
+pub use traits::radixsort_argsort::RadixArgSort;
+pub use traits::radixsort_by_key::RadixSortByKey;
+pub use traits::radixsort_owned::RadixSortOwned;
+pub use traits::radixsort_string::{ByteString, RadixSortString};
 
+pub use sorts::adaptive_sort::{find_runs, voracious_sort_adaptive, Run};
 pub use sorts::american_flag_sort::american_flag_sort;
+pub use sorts::bits_sort::{bits_in_place_sort, bits_sort, bits_stable_sort};
 pub use sorts::boolean_sort::boolean_sort;
+pub use sorts::boolean_sort::boolean_sort_desc;
 pub use sorts::comparative_sort::insertion_sort;
+pub use sorts::comparative_sort::merge_sort;
 pub use sorts::counting_sort::counting_sort;
 pub use sorts::dlsd_sort::dlsd_radixsort;
+pub use sorts::dlsd_sort::dlsd_radixsort_wc;
+pub use sorts::float_sort::{
+    voracious_sort_floats, voracious_stable_sort_floats, FloatKey, FloatOrder,
+    NanPolicy,
+};
 pub use sorts::lsd_sort::lsd_radixsort;
+pub use sorts::lsd_sort::lsd_radixsort_wc;
 pub use sorts::lsd_stable_sort::lsd_stable_radixsort;
 pub use sorts::msd_sort::msd_radixsort;
+pub use sorts::msd_sort::msd_radixsort_wc;
 pub use sorts::msd_stable_sort::msd_stable_radixsort;
+pub use sorts::owned_sort::lsd_stable_radixsort_owned;
+pub use sorts::owned_sort::msd_radixsort_owned;
+pub use sorts::owned_sort::thiel_radixsort_owned;
+pub use sorts::pairs_sort::lsd_stable_radixsort_pairs;
+pub use sorts::pairs_sort::rollercoaster_sort_pairs;
+pub use sorts::pairs_sort::voracious_sort_pairs;
+pub use sorts::pairs_sort::voracious_stable_sort_pairs;
 pub use sorts::rollercoaster_sort::rollercoaster_sort;
+pub use sorts::select_sort::{
+    voracious_partial_sort, voracious_select_nth_unstable, voracious_top_k,
+};
 pub use sorts::ska_sort::ska_sort;
+pub use sorts::sort_context::voracious_sort_with_buffer;
+pub use sorts::sort_context::SortContext;
+pub use sorts::string_sort::string_sort;
+pub use sorts::string_sort::string_stable_sort;
 pub use sorts::thiel_sort::thiel_radixsort;
+pub use sorts::try_sort::voracious_sort_with_memory_limit;
+pub use sorts::try_sort::SortError;
 pub use sorts::voracious_sort::voracious_sort;
 
+#[cfg(feature = "voracious_multithread")]
+pub use sorts::adaptive_sort::voracious_mt_sort_adaptive;
+#[cfg(feature = "voracious_multithread")]
+pub use sorts::bits_sort::bits_mt_sort;
+#[cfg(feature = "voracious_multithread")]
+pub use sorts::float_sort::voracious_mt_sort_floats;
+#[cfg(feature = "voracious_multithread")]
+pub use sorts::lsd_mt_sort::lsd_mt_radixsort;
+#[cfg(feature = "voracious_multithread")]
+pub use sorts::msd_mt_stable_sort::msd_mt_stable_radixsort;
 #[cfg(feature = "voracious_multithread")]
 pub use sorts::peeka_sort::peeka_sort;
+#[cfg(feature = "voracious_multithread")]
+pub use sorts::select_mt_sort::{
+    voracious_mt_partial_sort, voracious_mt_select_nth_unstable,
+    voracious_mt_top_k,
+};
+#[cfg(feature = "voracious_multithread")]
+pub use sorts::string_sort::string_mt_sort;
+#[cfg(feature = "voracious_multithread_std")]
+pub use sorts::peeka_std_sort::peeka_std_sort;
 
 pub use dedicated::cs_u16::cs_u16;
 pub use dedicated::lsd_f32::lsd_f32;
+pub use dedicated::lsd_f64::lsd_f64;
+pub use dedicated::lsd_i32::lsd_i32;
+pub use dedicated::lsd_i64::lsd_i64;
+pub use dedicated::lsd_u128::lsd_u128;
 pub use dedicated::lsd_u32::lsd_u32;
+pub use dedicated::lsd_u64::lsd_u64;
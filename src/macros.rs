@@ -0,0 +1,79 @@
+/// # Radixable macro
+///
+/// Implements `PartialOrd`, `PartialEq` and `Radixable` for a type, from its
+/// key. The generated `PartialOrd` and `PartialEq` compare the radix
+/// encodings of the keys (`into_key_type`), so they always agree with the
+/// order of the radix sorts, floats included.
+///
+/// The type must be `Copy`, and `Send` and `Sync` for the multithread sorts.
+///
+/// - `radixable!(Type, Key, field)`: the key is a field, or a path of fields
+///   like `a.b` or `0`.
+/// - `radixable!(Type, (KeyA, KeyB), (field_a, field_b))`: a composite key
+///   made of several fields, compared in order. As for the tuples, the key
+///   cannot be wider than 128 bits: a wider one, like `(u64, u64, u64)`,
+///   panics when the elements are compared or sorted.
+/// - `radixable!(Type, Key, |item| expression)`: the key is an expression of
+///   the element.
+/// - `radixable!(enum Type, Key)`: a C-like enum, keyed by its discriminant
+///   (`Key` is an integer type, like the `repr` of the enum).
+///
+/// ```
+/// use voracious_radix_sort::{radixable, RadixSort};
+///
+/// #[derive(Copy, Clone, Debug)]
+/// struct Point { x: f32, y: f32 }
+/// radixable!(Point, f32, x);
+///
+/// #[derive(Copy, Clone, Debug)]
+/// struct Task { day: u16, rank: u32, name: &'static str }
+/// radixable!(Task, (u16, u32), (day, rank));
+///
+/// #[derive(Copy, Clone, Debug)]
+/// enum Level { Low = 1, High = 10, Mid = 5 }
+/// radixable!(enum Level, u8);
+///
+/// let mut levels = vec![Level::High, Level::Low, Level::Mid];
+/// levels.voracious_sort();
+/// assert_eq!(levels, vec![Level::Low, Level::Mid, Level::High]);
+/// ```
+#[macro_export]
+macro_rules! radixable {
+    (enum $name:ty, $key:ty) => {
+        $crate::radixable!($name, $key, |item| *item as $key);
+    };
+    ($name:ty, $key:ty, |$item:ident| $expr:expr) => {
+        impl $crate::Radixable<$key> for $name {
+            type Key = $key;
+            #[inline]
+            fn key(&self) -> Self::Key {
+                let $item = self;
+                $expr
+            }
+        }
+        impl ::core::cmp::PartialOrd for $name {
+            #[inline]
+            fn partial_cmp(
+                &self,
+                other: &Self,
+            ) -> ::core::option::Option<::core::cmp::Ordering> {
+                let a = $crate::Radixable::<$key>::into_key_type(self);
+                let b = $crate::Radixable::<$key>::into_key_type(other);
+                ::core::option::Option::Some(::core::cmp::Ord::cmp(&a, &b))
+            }
+        }
+        impl ::core::cmp::PartialEq for $name {
+            #[inline]
+            fn eq(&self, other: &Self) -> bool {
+                $crate::Radixable::<$key>::into_key_type(self)
+                    == $crate::Radixable::<$key>::into_key_type(other)
+            }
+        }
+    };
+    ($name:ty, $key:ty, ($($($field:tt).+),+)) => {
+        $crate::radixable!($name, $key, |item| ($(item.$($field).+),+));
+    };
+    ($name:ty, $key:ty, $($field:tt).+) => {
+        $crate::radixable!($name, $key, |item| item.$($field).+);
+    };
+}
@@ -0,0 +1,70 @@
+use alloc::vec::Vec;
+
+use super::super::algo::verge_sort_heuristic::find_long_runs;
+pub use super::super::algo::verge_sort_heuristic::Run;
+use super::super::{RadixKey, RadixSort, Radixable};
+#[cfg(feature = "voracious_multithread")]
+use super::peeka_sort::peeka_sort;
+use super::voracious_sort::voracious_sort;
+
+const ADAPTIVE_THRESHOLD: usize = 200;
+
+/// # Find runs
+///
+/// The long runs of the array (at least `n / log2(n)` elements), from left to
+/// right, as found by the Verge sort pre-processing heuristic. The elements
+/// are compared by their key.
+///
+/// The elements between two runs are not sorted, and an array without long
+/// run has no run.
+pub fn find_runs<T, K>(arr: &[T]) -> Vec<Run>
+where
+    T: Radixable<K>,
+    K: RadixKey,
+{
+    find_long_runs(arr)
+}
+
+/// # Voracious adaptive sort
+///
+/// Same as `voracious_sort`, but it always goes through the Verge sort
+/// pre-processing, whatever the key type and the length of the array: the
+/// long runs of the array (an already sorted or reverse sorted array, sorted
+/// arrays concatenated, or a sorted array with some elements appended) are
+/// kept, only the elements out of them are radix sorted, then the runs are
+/// merged.
+///
+/// The array is scanned once: the elements out of the runs are sorted by the
+/// Voracious sort without its own pre-processing.
+///
+/// This sort is unstable.
+pub fn voracious_sort_adaptive<T, K>(arr: &mut [T])
+where
+    T: Radixable<K>,
+    K: RadixKey,
+{
+    if arr.len() <= ADAPTIVE_THRESHOLD {
+        arr.voracious_sort();
+    } else {
+        voracious_sort(arr, 8);
+    }
+}
+
+/// # Voracious multithread adaptive sort
+///
+/// Same as `voracious_sort_adaptive`, with the Peeka sort on `thread_n`
+/// threads for the elements out of the runs.
+///
+/// This sort is unstable.
+#[cfg(feature = "voracious_multithread")]
+pub fn voracious_mt_sort_adaptive<T, K>(arr: &mut [T], thread_n: usize)
+where
+    T: Radixable<K>,
+    K: RadixKey,
+{
+    if arr.len() <= ADAPTIVE_THRESHOLD {
+        arr.voracious_sort();
+    } else {
+        peeka_sort(arr, 8, 650_000, thread_n);
+    }
+}
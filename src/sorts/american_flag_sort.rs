@@ -1,6 +1,9 @@
+use alloc::collections::TryReserveError;
+use alloc::vec::Vec;
+
 use super::super::{RadixKey, Radixable};
 use super::comparative_sort::insertion_sort;
-use super::utils::{get_histogram, prefix_sums, Params};
+use super::utils::{get_histogram, prefix_sums, prefix_sums_into, Params};
 
 fn serial_swap<T: Radixable<K>, K: RadixKey>(
     arr: &mut [T],
@@ -85,3 +88,83 @@ pub fn american_flag_sort<T: Radixable<K>, K: RadixKey>(
     let params = Params::new(0, radix, raw_offset, max_level);
     serial_radixsort_rec(arr, params);
 }
+
+// Same as `serial_radixsort_rec`, but the histogram and the heads of each
+// level are the first `2 * radix_range` counts of `counts`, the next levels
+// take the rest: the recursion does not allocate.
+fn serial_radixsort_rec_in<T: Radixable<K>, K: RadixKey>(
+    arr: &mut [T],
+    p: Params,
+    counts: &mut [usize],
+) {
+    if arr.len() <= 64 {
+        insertion_sort(arr);
+        return;
+    }
+
+    let (level_counts, next_counts) = counts.split_at_mut(2 * p.radix_range);
+    let (histogram, heads) = level_counts.split_at_mut(p.radix_range);
+
+    let dummy = arr[0];
+    let (mask, shift) = dummy.get_mask_and_shift_from_left(&p);
+    histogram.iter_mut().for_each(|count| *count = 0);
+    arr.iter().for_each(|item| histogram[item.extract(mask, shift)] += 1);
+    prefix_sums_into(histogram, heads);
+
+    let mut tail = 0;
+    for i in 0..(p.radix_range) - 1 {
+        tail += histogram[i];
+        while heads[i] < tail {
+            let mut bucket = arr[heads[i]].extract(mask, shift);
+            while bucket != i {
+                arr.swap(heads[i], heads[bucket]);
+                heads[bucket] += 1;
+                bucket = arr[heads[i]].extract(mask, shift);
+            }
+            heads[i] += 1;
+        }
+    }
+
+    let mut rest = arr;
+    if p.level < p.max_level - 1 {
+        for count in histogram.iter() {
+            let (bucket, second_part) = rest.split_at_mut(*count);
+            rest = second_part;
+            if *count > 1 {
+                let new_params = p.new_level(p.level + 1);
+                serial_radixsort_rec_in(bucket, new_params, next_counts);
+            }
+        }
+    }
+}
+
+// The American flag sort, with the histograms and the heads of all the
+// levels allocated at once with `try_reserve`: nothing else is allocated, so
+// an allocation failure is returned instead of aborting.
+pub fn try_american_flag_sort<T: Radixable<K>, K: RadixKey>(
+    arr: &mut [T],
+    radix: usize,
+) -> Result<(), TryReserveError> {
+    if arr.len() <= 64 {
+        insertion_sort(arr);
+        return Ok(());
+    }
+
+    let dummy = arr[0];
+    let (_, raw_offset) = dummy.compute_offset(arr, radix);
+    let max_level = dummy.compute_max_level(raw_offset, radix);
+
+    if max_level == 0 {
+        return Ok(());
+    }
+
+    let params = Params::new(0, radix, raw_offset, max_level);
+    let size = 2 * max_level * params.radix_range;
+    let mut counts: Vec<usize> = Vec::new();
+    counts.try_reserve_exact(size)?;
+    counts.resize(size, 0);
+
+    serial_radixsort_rec_in(arr, params, &mut counts);
+
+    Ok(())
+}
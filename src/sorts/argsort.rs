@@ -0,0 +1,349 @@
+use alloc::vec;
+use alloc::vec::Vec;
+
+use super::super::{RadixKey, Radixable};
+#[cfg(feature = "voracious_multithread")]
+use super::peeka_sort::{global_sorting, local_sorting_with};
+use super::ska_sort::ska_swap_by;
+use super::utils::{
+    compute_offset, get_full_histograms, get_histogram_by,
+    only_one_bucket_filled, prefix_sums, Params,
+};
+#[cfg(feature = "voracious_multithread")]
+use super::utils_mt::run_in_pool;
+
+// Below this size, the multithread argsort is the single thread one.
+#[cfg(feature = "voracious_multithread")]
+const MT_THRESHOLD: usize = 100_000;
+
+// The argsorts never move the elements: only the indices are moved, by the
+// digits of `arr[index]`. The keys are read again at each pass, so the only
+// allocation proportional to the array is the index array (and a second one
+// for the stable argsort).
+
+// The indices are `usize`, or `u32` to halve their memory.
+trait ArgsortIndex: Copy + Send + Sync {
+    fn from_usize(index: usize) -> Self;
+    fn to_usize(self) -> usize;
+}
+
+impl ArgsortIndex for usize {
+    #[inline]
+    fn from_usize(index: usize) -> Self { index }
+    #[inline]
+    fn to_usize(self) -> usize { self }
+}
+
+impl ArgsortIndex for u32 {
+    #[inline]
+    fn from_usize(index: usize) -> Self { index as u32 }
+    #[inline]
+    fn to_usize(self) -> usize { self as usize }
+}
+
+fn identity<I: ArgsortIndex>(size: usize) -> Vec<I> {
+    (0..size).map(I::from_usize).collect()
+}
+
+fn check_u32_indices(size: usize) {
+    assert!(
+        size as u64 <= u32::MAX as u64 + 1,
+        "[Argsort] An array of more than 2^32 elements cannot be argsorted \
+         with u32 indices."
+    );
+}
+
+fn argsort_params<T, K>(arr: &[T], radix: usize) -> Option<Params>
+where
+    T: Radixable<K>,
+    K: RadixKey,
+{
+    let dummy = arr[0];
+    let (offset, _) = compute_offset(arr, radix);
+    let max_level = dummy.compute_max_level(offset, radix);
+
+    // All the keys are zero: the identity is already the permutation.
+    if max_level == 0 {
+        return None;
+    }
+
+    Some(Params::new(0, radix, offset, max_level))
+}
+
+fn lsd_stable_argsort_aux<T, K, I>(arr: &[T], radix: usize) -> Vec<I>
+where
+    T: Radixable<K>,
+    K: RadixKey,
+    I: ArgsortIndex,
+{
+    let mut indices: Vec<I> = identity(arr.len());
+    if arr.len() <= 128 {
+        indices.sort_by_key(|index| arr[index.to_usize()].into_key_type());
+        return indices;
+    }
+
+    let p = match argsort_params(arr, radix) {
+        Some(p) => p,
+        None => return indices,
+    };
+    let dummy = arr[0];
+    let histograms = get_full_histograms(arr, &p);
+    let mut buffer = vec![I::from_usize(0); arr.len()];
+
+    for level in (0..p.max_level).rev() {
+        if only_one_bucket_filled(&histograms[level]) {
+            continue;
+        }
+
+        let (mask, shift) = dummy.get_mask_and_shift(&p.new_level(level));
+        let (_, mut heads, _) = prefix_sums(&histograms[level]);
+
+        for index in indices.iter() {
+            let bucket = arr[index.to_usize()].extract(mask, shift);
+            buffer[heads[bucket]] = *index;
+            heads[bucket] += 1;
+        }
+
+        core::mem::swap(&mut indices, &mut buffer);
+    }
+
+    indices
+}
+
+/// # LSD stable argsort
+///
+/// Returns the permutation which sorts the array: `arr[indices[0]]` is the
+/// smallest element. The array is not modified.
+///
+/// The histograms of all the levels are computed in one pass over the array,
+/// then each LSD pass scatters the indices by the digit of `arr[index]`.
+///
+/// This argsort is stable: the indices of equal elements are in ascending
+/// order.
+pub fn lsd_stable_argsort<T, K>(arr: &[T], radix: usize) -> Vec<usize>
+where
+    T: Radixable<K>,
+    K: RadixKey,
+{
+    lsd_stable_argsort_aux(arr, radix)
+}
+
+/// # LSD stable argsort with `u32` indices
+///
+/// Same as `lsd_stable_argsort`, with half the memory for the indices.
+///
+/// Panics if the array has more than `2^32` elements.
+pub fn lsd_stable_argsort_u32<T, K>(arr: &[T], radix: usize) -> Vec<u32>
+where
+    T: Radixable<K>,
+    K: RadixKey,
+{
+    check_u32_indices(arr.len());
+    lsd_stable_argsort_aux(arr, radix)
+}
+
+// Permutes the indices in place (as the Ska sort) by the digit of
+// `arr[index]` at level `p.level`, and returns the histogram.
+fn msd_argsort_partition<T, K, I>(
+    arr: &[T],
+    indices: &mut [I],
+    p: &Params,
+) -> Vec<usize>
+where
+    T: Radixable<K>,
+    K: RadixKey,
+    I: ArgsortIndex,
+{
+    let dummy = arr[0];
+    let (mask, shift) = dummy.get_mask_and_shift(p);
+    let digit = |index: &I| arr[index.to_usize()].extract(mask, shift);
+    let histogram = get_histogram_by(indices, p, digit);
+    let (_, mut heads, tails) = prefix_sums(&histogram);
+
+    ska_swap_by(indices, &mut heads, &tails, digit);
+
+    histogram
+}
+
+fn msd_argsort_rec<T, K, I>(arr: &[T], indices: &mut [I], p: Params)
+where
+    T: Radixable<K>,
+    K: RadixKey,
+    I: ArgsortIndex,
+{
+    if indices.len() <= 64 {
+        indices
+            .sort_unstable_by_key(|index| arr[index.to_usize()].into_key_type());
+        return;
+    }
+
+    let histogram = msd_argsort_partition(arr, indices, &p);
+
+    if p.level < p.max_level - 1 {
+        let mut rest = indices;
+        for count in histogram.iter() {
+            let (bucket, second_part) = rest.split_at_mut(*count);
+            rest = second_part;
+            if *count > 1 {
+                msd_argsort_rec(arr, bucket, p.new_level(p.level + 1));
+            }
+        }
+    }
+}
+
+fn msd_argsort_aux<T, K, I>(arr: &[T], radix: usize) -> Vec<I>
+where
+    T: Radixable<K>,
+    K: RadixKey,
+    I: ArgsortIndex,
+{
+    let mut indices: Vec<I> = identity(arr.len());
+    if arr.len() <= 64 {
+        indices
+            .sort_unstable_by_key(|index| arr[index.to_usize()].into_key_type());
+        return indices;
+    }
+
+    if let Some(p) = argsort_params(arr, radix) {
+        msd_argsort_rec(arr, &mut indices, p);
+    }
+
+    indices
+}
+
+/// # MSD argsort
+///
+/// Returns the permutation which sorts the array: `arr[indices[0]]` is the
+/// smallest element. The array is not modified.
+///
+/// The indices are permuted in place, by the digit of `arr[index]`, as in the
+/// Ska sort: the index array is the only allocation proportional to the
+/// array.
+///
+/// This argsort is unstable.
+pub fn msd_argsort<T, K>(arr: &[T], radix: usize) -> Vec<usize>
+where
+    T: Radixable<K>,
+    K: RadixKey,
+{
+    msd_argsort_aux(arr, radix)
+}
+
+/// # MSD argsort with `u32` indices
+///
+/// Same as `msd_argsort`, with half the memory for the indices.
+///
+/// Panics if the array has more than `2^32` elements.
+pub fn msd_argsort_u32<T, K>(arr: &[T], radix: usize) -> Vec<u32>
+where
+    T: Radixable<K>,
+    K: RadixKey,
+{
+    check_u32_indices(arr.len());
+    msd_argsort_aux(arr, radix)
+}
+
+// The Peeka sort on the indices: the blocks of indices are permuted in
+// parallel (Local Sorting Phase), then the regions graph moves them to their
+// buckets (Global Sorting Phase), and the buckets are argsorted in parallel.
+#[cfg(feature = "voracious_multithread")]
+fn msd_mt_argsort_rec<T, K, I>(
+    arr: &[T],
+    indices: &mut [I],
+    p: Params,
+    block_size: usize,
+) where
+    T: Radixable<K>,
+    K: RadixKey,
+    I: ArgsortIndex,
+{
+    if indices.len() <= block_size {
+        msd_argsort_rec(arr, indices, p);
+        return;
+    }
+
+    let histograms = local_sorting_with(indices, block_size, |block| {
+        msd_argsort_partition(arr, block, &p)
+    });
+
+    rayon::scope(|s| {
+        global_sorting(indices, &p, &histograms, |bucket| {
+            if p.level < p.max_level - 1 && bucket.len() > 1 {
+                let new_params = p.new_level(p.level + 1);
+                s.spawn(move |_| {
+                    msd_mt_argsort_rec(arr, bucket, new_params, block_size)
+                });
+            }
+        });
+    });
+}
+
+#[cfg(feature = "voracious_multithread")]
+fn msd_mt_argsort_aux<T, K, I>(
+    arr: &[T],
+    radix: usize,
+    thread_n: usize,
+) -> Vec<I>
+where
+    T: Radixable<K>,
+    K: RadixKey,
+    I: ArgsortIndex,
+{
+    if arr.len() <= MT_THRESHOLD {
+        return msd_argsort_aux(arr, radix);
+    }
+
+    let mut indices: Vec<I> = identity(arr.len());
+    let p = match argsort_params(arr, radix) {
+        Some(p) => p,
+        None => return indices,
+    };
+
+    run_in_pool(thread_n, || {
+        let block_size =
+            (arr.len() / rayon::current_num_threads()).max(MT_THRESHOLD);
+        msd_mt_argsort_rec(arr, &mut indices, p, block_size);
+    });
+
+    indices
+}
+
+/// # MSD multithread argsort
+///
+/// Same as `msd_argsort`, on `thread_n` threads: the indices are partitioned
+/// as in the Peeka sort (the blocks of indices are permuted in parallel, then
+/// moved to their buckets with the regions graph), and the buckets are
+/// argsorted in parallel.
+///
+/// This argsort is unstable.
+#[cfg(feature = "voracious_multithread")]
+pub fn msd_mt_argsort<T, K>(
+    arr: &[T],
+    radix: usize,
+    thread_n: usize,
+) -> Vec<usize>
+where
+    T: Radixable<K>,
+    K: RadixKey,
+{
+    msd_mt_argsort_aux(arr, radix, thread_n)
+}
+
+/// # MSD multithread argsort with `u32` indices
+///
+/// Same as `msd_mt_argsort`, with half the memory for the indices.
+///
+/// Panics if the array has more than `2^32` elements.
+#[cfg(feature = "voracious_multithread")]
+pub fn msd_mt_argsort_u32<T, K>(
+    arr: &[T],
+    radix: usize,
+    thread_n: usize,
+) -> Vec<u32>
+where
+    T: Radixable<K>,
+    K: RadixKey,
+{
+    check_u32_indices(arr.len());
+    msd_mt_argsort_aux(arr, radix, thread_n)
+}
@@ -0,0 +1,106 @@
+use super::super::{RadixKey, Radixable};
+use super::american_flag_sort::american_flag_sort;
+use super::comparative_sort::{insertion_sort, key_cmp, stable_sort};
+use super::lsd_sort::lsd_radixsort;
+use super::lsd_stable_sort::lsd_stable_radixsort;
+use super::msd_stable_sort::msd_stable_radixsort;
+#[cfg(feature = "voracious_multithread")]
+use super::peeka_sort::peeka_sort;
+#[cfg(all(
+    feature = "voracious_multithread_std",
+    not(feature = "voracious_multithread")
+))]
+use super::peeka_std_sort::peeka_std_sort;
+use super::rollercoaster_sort::rollercoaster_sort;
+use super::scratch::{lsd_scratch, rollercoaster_scratch};
+use super::ska_sort::ska_sort;
+
+/// # Bits sort
+///
+/// Generic unstable sort for any key type: the sort is chosen from the bit
+/// width of the key (`type_size`), and not from the key type. The narrow keys
+/// are sorted with the LSD sort, the wider keys with the Rollercoaster sort.
+///
+/// It is the default `voracious_sort` of a `Dispatcher` implemented without
+/// methods, so a downstream key type only needs a `RadixKey` implementation.
+pub fn bits_sort<T: Radixable<K>, K: RadixKey>(arr: &mut [T]) {
+    if arr.len() <= 200 {
+        arr.sort_unstable_by(key_cmp);
+        return;
+    }
+
+    let bits = arr[0].type_size();
+    if bits <= 32 || (bits <= 64 && arr.len() <= 8_000) {
+        lsd_radixsort(arr, 8);
+    } else {
+        rollercoaster_sort(arr, 8);
+    }
+}
+
+// Upper bound of the scratch memory (in bytes) of `bits_sort` on `arr`.
+pub fn bits_sort_scratch<T: Radixable<K>, K: RadixKey>(arr: &[T]) -> usize {
+    if arr.len() <= 200 {
+        return 0;
+    }
+
+    let bits = arr[0].type_size();
+    if bits <= 32 || (bits <= 64 && arr.len() <= 8_000) {
+        lsd_scratch(arr, 8)
+    } else {
+        rollercoaster_scratch(arr, 8)
+    }
+}
+
+/// # Bits in place sort
+///
+/// Same as `bits_sort`, but only the histograms are allocated: the American
+/// flag sort for the keys of at most 16 bits (one or two passes), the Ska
+/// sort for the wider keys.
+///
+/// This sort is an in place unstable radix sort.
+pub fn bits_in_place_sort<T: Radixable<K>, K: RadixKey>(arr: &mut [T]) {
+    if arr.len() <= 64 {
+        insertion_sort(arr);
+    } else if arr[0].type_size() <= 16 {
+        american_flag_sort(arr, 8);
+    } else {
+        ska_sort(arr, 8);
+    }
+}
+
+/// # Bits stable sort
+///
+/// Same as `bits_sort`, but the sort is stable: a LSD sort, or a MSD sort for
+/// the keys wider than 64 bits which would need too many LSD passes.
+pub fn bits_stable_sort<T: Radixable<K>, K: RadixKey>(arr: &mut [T]) {
+    if arr.len() <= 200 {
+        stable_sort(arr);
+    } else if arr[0].type_size() <= 64 {
+        lsd_stable_radixsort(arr, 8);
+    } else {
+        msd_stable_radixsort(arr, 8);
+    }
+}
+
+/// # Bits multithread sort
+///
+/// Same as `bits_sort`, with the Peeka sort on `thread_n` threads (the
+/// `peeka_std_sort` without the "`voracious_multithread`" feature). Small
+/// arrays are sorted with `bits_sort`.
+#[cfg(any(
+    feature = "voracious_multithread",
+    feature = "voracious_multithread_std"
+))]
+pub fn bits_mt_sort<T: Radixable<K>, K: RadixKey>(
+    arr: &mut [T],
+    thread_n: usize,
+) {
+    if arr.len() < 100_000 {
+        bits_sort(arr);
+    } else {
+        #[cfg(feature = "voracious_multithread")]
+        peeka_sort(arr, 8, 650_000, thread_n);
+        #[cfg(not(feature = "voracious_multithread"))]
+        peeka_std_sort(arr, 8, 650_000, thread_n);
+    }
+}
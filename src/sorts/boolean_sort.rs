@@ -21,10 +21,8 @@ fn boolean_sort_aux(arr: &mut [bool], shift: usize, count: usize, value: bool) {
     }
 }
 
-/// # Boolean sort
-///
-/// A dedicated sort for boolean.
-pub fn boolean_sort(arr: &mut [bool]) {
+#[inline]
+fn count_false(arr: &[bool]) -> usize {
     let mut count_false = 0;
 
     let quotient = arr.len() / 4;
@@ -53,6 +51,15 @@ pub fn boolean_sort(arr: &mut [bool]) {
         }
     }
 
+    count_false
+}
+
+/// # Boolean sort
+///
+/// A dedicated sort for boolean.
+pub fn boolean_sort(arr: &mut [bool]) {
+    let count_false = count_false(arr);
+
     if count_false == arr.len() || count_false == 0 {
         return;
     }
@@ -60,3 +67,17 @@ pub fn boolean_sort(arr: &mut [bool]) {
     boolean_sort_aux(arr, 0, count_false, false);
     boolean_sort_aux(arr, count_false, arr.len() - count_false, true);
 }
+
+/// # Boolean sort (descending order)
+///
+/// A dedicated sort for boolean, `true` values come first.
+pub fn boolean_sort_desc(arr: &mut [bool]) {
+    let count_true = arr.len() - count_false(arr);
+
+    if count_true == arr.len() || count_true == 0 {
+        return;
+    }
+
+    boolean_sort_aux(arr, 0, count_true, true);
+    boolean_sort_aux(arr, count_true, arr.len() - count_true, false);
+}
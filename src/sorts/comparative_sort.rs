@@ -1,18 +1,36 @@
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+
 use super::super::{RadixKey, Radixable};
 use super::utils::Params;
 
 const TRY_THRESHOLD: u8 = 32;
+const MERGE_THRESHOLD: usize = 32;
+
+// Compares two elements by their radix key. It is the order of the radix
+// sorts, which is a total order, even with the float keys (the order of
+// `total_cmp`).
+#[inline]
+pub fn key_cmp<T: Radixable<K>, K: RadixKey>(a: &T, b: &T) -> Ordering {
+    a.into_key_type().cmp(&b.into_key_type())
+}
+
+// `a > b` in the order of the keys.
+#[inline]
+fn key_gt<T: Radixable<K>, K: RadixKey>(a: &T, b: &T) -> bool {
+    a.into_key_type() > b.into_key_type()
+}
 
 /// # Insertion sort
 ///
 /// An implementation of the
 /// [Insertion sort](https://en.wikipedia.org/wiki/Insertion_sort)
-/// algorithm.
-pub fn insertion_sort<T: PartialOrd>(arr: &mut [T]) {
+/// algorithm. The elements are compared by their key.
+pub fn insertion_sort<T: Radixable<K>, K: RadixKey>(arr: &mut [T]) {
     for i in 1..arr.len() {
-        if arr[i - 1] > arr[i] {
+        if key_gt(&arr[i - 1], &arr[i]) {
             let mut j = i;
-            while j > 0 && arr[j - 1] > arr[j] {
+            while j > 0 && key_gt(&arr[j - 1], &arr[j]) {
                 arr.swap(j - 1, j);
                 j -= 1;
             }
@@ -20,11 +38,137 @@ pub fn insertion_sort<T: PartialOrd>(arr: &mut [T]) {
     }
 }
 
-fn insertion_sort_start_at<T: PartialOrd>(arr: &mut [T], start: usize) {
+fn merge_sort_rec<T: Radixable<K>, K: RadixKey>(
+    arr: &mut [T],
+    buffer: &mut [T],
+) {
+    if arr.len() <= MERGE_THRESHOLD {
+        insertion_sort(arr);
+        return;
+    }
+
+    let middle = arr.len() / 2;
+    {
+        let (left, right) = arr.split_at_mut(middle);
+        let (buffer_left, buffer_right) = buffer.split_at_mut(middle);
+        merge_sort_rec(left, buffer_left);
+        merge_sort_rec(right, buffer_right);
+    }
+
+    if !key_gt(&arr[middle - 1], &arr[middle]) {
+        return;
+    }
+
+    let buffer = &mut buffer[..arr.len()];
+    buffer.copy_from_slice(arr);
+    let (left, right) = buffer.split_at(middle);
+    let mut i = 0;
+    let mut j = 0;
+    for item in arr.iter_mut() {
+        // Equal elements are taken from the left part first.
+        if j == right.len()
+            || (i < left.len() && !key_gt(&left[i], &right[j]))
+        {
+            *item = left[i];
+            i += 1;
+        } else {
+            *item = right[j];
+            j += 1;
+        }
+    }
+}
+
+/// # Merge sort
+///
+/// An implementation of the
+/// [Merge sort](https://en.wikipedia.org/wiki/Merge_sort)
+/// algorithm, with an insertion sort for the small slices. It uses a buffer
+/// as long as the array.
+///
+/// This sort is stable. The elements are compared by their key.
+pub fn merge_sort<T: Radixable<K>, K: RadixKey>(arr: &mut [T]) {
+    if arr.len() <= MERGE_THRESHOLD {
+        insertion_sort(arr);
+        return;
+    }
+
+    let mut buffer = arr.to_vec();
+    merge_sort_rec(arr, &mut buffer);
+}
+
+// Merges the sorted `arr[..middle]` and `arr[middle..]` without buffer: the
+// first half of the longer part is cut, the other part is cut at the same
+// key, the two middle pieces are swapped by a rotation, then both sides are
+// merged recursively.
+fn merge_in_place<T: Radixable<K>, K: RadixKey>(arr: &mut [T], middle: usize) {
+    let len = arr.len();
+    if middle == 0 || middle == len || !key_gt(&arr[middle - 1], &arr[middle])
+    {
+        return;
+    }
+    if len == 2 {
+        arr.swap(0, 1);
+        return;
+    }
+
+    let (left_cut, right_cut) = if middle > len - middle {
+        // The right elements lower than the pivot go before it.
+        let left_cut = middle / 2;
+        let pivot = arr[left_cut];
+        let right_cut =
+            middle + arr[middle..].partition_point(|e| key_gt(&pivot, e));
+        (left_cut, right_cut)
+    } else {
+        // The left elements greater than the pivot go after it.
+        let right_cut = middle + (len - middle) / 2;
+        let pivot = arr[right_cut];
+        let left_cut = arr[..middle].partition_point(|e| !key_gt(e, &pivot));
+        (left_cut, right_cut)
+    };
+
+    arr[left_cut..right_cut].rotate_left(middle - left_cut);
+    let new_middle = left_cut + right_cut - middle;
+    merge_in_place(&mut arr[..new_middle], left_cut);
+    merge_in_place(&mut arr[new_middle..], middle - left_cut);
+}
+
+/// # In place merge sort
+///
+/// Same as the merge sort, but the halves are merged in place by rotations,
+/// so it does not allocate at all. It runs in `O(n log² n)`.
+///
+/// This sort is stable. The elements are compared by their key.
+pub fn in_place_merge_sort<T: Radixable<K>, K: RadixKey>(arr: &mut [T]) {
+    if arr.len() <= MERGE_THRESHOLD {
+        insertion_sort(arr);
+        return;
+    }
+
+    let middle = arr.len() / 2;
+    in_place_merge_sort(&mut arr[..middle]);
+    in_place_merge_sort(&mut arr[middle..]);
+    merge_in_place(arr, middle);
+}
+
+// Stable comparison sort used as the fallback of the stable radix sorts:
+// the Rust stable sort with std, the merge sort without.
+#[inline]
+pub fn stable_sort<T: Radixable<K>, K: RadixKey>(arr: &mut [T]) {
+    #[cfg(feature = "std")]
+    arr.sort_by(key_cmp);
+    #[cfg(not(feature = "std"))]
+    merge_sort(arr);
+}
+
+fn insertion_sort_start_at<T, K>(arr: &mut [T], start: usize)
+where
+    T: Radixable<K>,
+    K: RadixKey,
+{
     for i in start..arr.len() {
-        if arr[i - 1] > arr[i] {
+        if key_gt(&arr[i - 1], &arr[i]) {
             let mut j = i;
-            while j > 0 && arr[j - 1] > arr[j] {
+            while j > 0 && key_gt(&arr[j - 1], &arr[j]) {
                 arr.swap(j - 1, j);
                 j -= 1;
             }
@@ -127,7 +271,7 @@ pub fn insertion_sort_try<T: Radixable<K>, K: RadixKey>(
     let mut high_bits = arr[0].into_key_type() & mask;
     let mut misplaced_count = 0;
     loop {
-        if arr[i - 1] > arr[i] {
+        if key_gt(&arr[i - 1], &arr[i]) {
             let current_high_bits = arr[i].into_key_type() & mask;
             if current_high_bits == high_bits {
                 misplaced_count += 1;
@@ -137,7 +281,7 @@ pub fn insertion_sort_try<T: Radixable<K>, K: RadixKey>(
             }
 
             let mut j = i;
-            while j > 0 && arr[j - 1] > arr[j] {
+            while j > 0 && key_gt(&arr[j - 1], &arr[j]) {
                 arr.swap(j - 1, j);
                 j -= 1;
             }
@@ -1,3 +1,5 @@
+use alloc::vec;
+
 use super::super::{RadixKey, Radixable};
 use super::utils::Params;
 
@@ -1,11 +1,17 @@
+use alloc::vec;
+use alloc::vec::Vec;
+
 use super::super::algo::k_way_merge::k_way_merge;
 use super::super::algo::verge_sort_heuristic::verge_sort_preprocessing;
 use super::super::{RadixKey, Radixable};
-use super::comparative_sort::insertion_sort_try;
+use super::comparative_sort::{insertion_sort_try, key_cmp};
 use super::lsd_sort::lsd_radixsort_body;
-use super::msd_sort::copy_by_histogram;
+use super::key_range::{
+    compute_key_range, get_full_histograms_reduced, reduce_key_range,
+};
+use super::msd_sort::scatter_by_histogram;
 use super::utils::{
-    copy_nonoverlapping, get_partial_histograms, offset_from_bits,
+    ceil, copy_nonoverlapping, get_partial_histograms, log2, offset_from_bits,
     only_one_bucket_filled, prefix_sums, Params,
 };
 use super::voracious_sort::voracious_sort_rec;
@@ -19,8 +25,8 @@ fn get_best_radix_size_and_runs(size: usize) -> (usize, usize) {
     for r in 7..10 {
         let diversion_threshold = (2usize.pow(r as u32) as f64) as usize;
         let mut required_bytes =
-            (((size as f64) / (diversion_threshold as f64)).log2() / r as f64)
-                .ceil() as usize;
+            ceil(log2((size as f64) / (diversion_threshold as f64)) / r as f64)
+                as usize;
         let mut estimated_final_size = (size as f64)
             / 2usize.pow(r as u32).pow(required_bytes as u32) as f64;
         if estimated_final_size > 1.0 {
@@ -53,7 +59,7 @@ pub fn dlsd_radixsort_body<T: Radixable<K>, K: RadixKey>(
     let size = arr.len();
 
     if size <= 128 {
-        arr.sort_unstable_by(|a, b| a.partial_cmp(b).unwrap());
+        arr.sort_unstable_by(key_cmp);
         return;
     }
 
@@ -62,10 +68,12 @@ pub fn dlsd_radixsort_body<T: Radixable<K>, K: RadixKey>(
 
     let mut buffer: Vec<T> = vec![arr[0]; size];
 
-    let histograms = if diversion {
-        get_partial_histograms(arr, &p, rbd)
-    } else {
-        dummy.get_full_histograms(arr, &p)
+    // The diversion extracts the digits from the left, so it is never used on
+    // reduced keys.
+    let histograms = match (diversion, p.key_min) {
+        (true, _) => get_partial_histograms(arr, &p, rbd),
+        (false, Some(min)) => get_full_histograms_reduced(arr, &p, min),
+        (false, None) => dummy.get_full_histograms(arr, &p),
     };
 
     let mut t1 = arr;
@@ -78,7 +86,7 @@ pub fn dlsd_radixsort_body<T: Radixable<K>, K: RadixKey>(
             continue;
         }
 
-        let (mut source, mut destination) =
+        let (source, destination) =
             if index == 0 { (t1, t2) } else { (t2, t1) };
         let (mask, shift) = if diversion {
             dummy.get_mask_and_shift_from_left(&p.new_level(level))
@@ -87,10 +95,11 @@ pub fn dlsd_radixsort_body<T: Radixable<K>, K: RadixKey>(
         };
         let (_, mut heads, _) = prefix_sums(&histograms[level]);
 
-        copy_by_histogram(
+        scatter_by_histogram(
+            &p,
             size,
-            &mut source,
-            &mut destination,
+            source,
+            destination,
             &mut heads,
             mask,
             shift,
@@ -126,7 +135,7 @@ pub fn dlsd_radixsort_body<T: Radixable<K>, K: RadixKey>(
 
         unsorted_parts.iter().for_each(|(i, j)| {
             if j - i <= 250 {
-                t1[*i..*j].sort_unstable_by(|a, b| a.partial_cmp(b).unwrap());
+                t1[*i..*j].sort_unstable_by(key_cmp);
             } else if j - i > 3000 && new_max_level <= 4 {
                 lsd_radixsort_body(&mut t1[*i..*j], new_params_lsd);
             } else {
@@ -136,13 +145,16 @@ pub fn dlsd_radixsort_body<T: Radixable<K>, K: RadixKey>(
     }
 }
 
-fn dlsd_radixsort_aux<T, K>(arr: &mut [T], radix: usize)
-where
+fn dlsd_radixsort_aux<T, K>(
+    arr: &mut [T],
+    radix: usize,
+    write_combining: bool,
+) where
     T: Radixable<K>,
     K: RadixKey,
 {
     if arr.len() <= 128 {
-        arr.sort_unstable_by(|a, b| a.partial_cmp(b).unwrap());
+        arr.sort_unstable_by(key_cmp);
         return;
     }
 
@@ -150,7 +162,21 @@ where
 
     let (sugg_radix, required_bytes) = get_best_radix_size_and_runs(arr.len());
 
-    let max_key = dummy.get_max_key(arr);
+    let (min_key, max_key) = compute_key_range(arr);
+
+    // The keys are clustered: a plain LSD sort on the reduced keys needs
+    // fewer passes than the diversion.
+    if let Some((offset, _, key_min)) =
+        reduce_key_range(&dummy, min_key, max_key, radix)
+    {
+        let max_level = dummy.compute_max_level(offset, radix);
+        let params = Params::new(0, radix, offset, max_level)
+            .with_write_combining(write_combining)
+            .with_key_min(Some(key_min));
+        dlsd_radixsort_body(arr, params, max_level, false);
+        return;
+    }
+
     let bits = dummy.type_size();
     let zero = dummy.default_key();
     let one = dummy.one();
@@ -182,6 +208,8 @@ where
         (Params::new(0, radix, offset, max_level), false, max_level)
     };
 
+    let params = params.with_write_combining(write_combining);
+
     dlsd_radixsort_body(arr, params, rbd, diversion);
 }
 
@@ -210,12 +238,37 @@ where
     K: RadixKey,
 {
     if arr.len() <= 128 {
-        arr.sort_unstable_by(|a, b| a.partial_cmp(b).unwrap());
+        arr.sort_unstable_by(key_cmp);
+        return;
+    }
+
+    let mut separators = verge_sort_preprocessing(arr, radix, &|arr, radix| {
+        dlsd_radixsort_aux(arr, radix, false)
+    });
+    k_way_merge(arr, &mut separators);
+}
+
+/// # DLSD sort with write-combining scatter
+///
+/// Same as `dlsd_radixsort`, but the elements are staged in small per-bucket
+/// buffers, one cache line each, and flushed to their buckets by blocks. It is
+/// faster with very large arrays, when the scatter step thrashes the TLB and
+/// the cache.
+///
+/// The DLSD sort is an out of place unstable radix sort. The core algorithm
+/// is stable but fallback and diversion are unstable.
+pub fn dlsd_radixsort_wc<T, K>(arr: &mut [T], radix: usize)
+where
+    T: Radixable<K>,
+    K: RadixKey,
+{
+    if arr.len() <= 128 {
+        arr.sort_unstable_by(key_cmp);
         return;
     }
 
     let mut separators = verge_sort_preprocessing(arr, radix, &|arr, radix| {
-        dlsd_radixsort_aux(arr, radix)
+        dlsd_radixsort_aux(arr, radix, true)
     });
     k_way_merge(arr, &mut separators);
 }
@@ -0,0 +1,226 @@
+use super::super::types::keyed::KeyIndex;
+use super::super::{RadixKey, RadixSort, RadixSortByKey, Radixable};
+use super::try_sort::SortError;
+
+/// # NaN policy
+///
+/// Where the NaN keys are put by the float sorts with a `FloatOrder`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum NanPolicy {
+    /// The NaNs are put before all the other keys.
+    First,
+    /// The NaNs are put after all the other keys.
+    Last,
+    /// The order of `total_cmp`: the negative NaNs before `-inf`, the positive
+    /// NaNs after `+inf`, and the NaNs ordered by their payload. It is the
+    /// order of the radix sorts.
+    TotalOrder,
+    /// The sort fails with `SortError::NanKey` if a key is NaN. The array is
+    /// left untouched.
+    Error,
+}
+
+/// # Float order
+///
+/// The order of the float sorts: the NaN policy, and whether `-0.0` and `0.0`
+/// are equal. When they are not, `-0.0` is lower than `0.0`.
+///
+/// The default order is `NanPolicy::TotalOrder`, with distinct zeros, which is
+/// the order of `total_cmp`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct FloatOrder {
+    pub nan: NanPolicy,
+    pub equal_zeros: bool,
+}
+
+impl FloatOrder {
+    pub fn new(nan: NanPolicy) -> FloatOrder {
+        FloatOrder { nan, equal_zeros: false }
+    }
+    pub fn with_equal_zeros(&self, equal_zeros: bool) -> FloatOrder {
+        FloatOrder { equal_zeros, ..(*self) }
+    }
+}
+
+impl Default for FloatOrder {
+    fn default() -> FloatOrder { FloatOrder::new(NanPolicy::TotalOrder) }
+}
+
+/// # Float key
+///
+/// Implemented for `f32` and `f64`: the keys which can be sorted with a
+/// `FloatOrder`.
+pub trait FloatKey: RadixKey {
+    /// Unsigned integer with the same width as the float.
+    type Bits: RadixKey;
+
+    fn is_nan_key(&self) -> bool;
+    fn is_negative_key(&self) -> bool;
+    /// Unsigned integer whose order is the order of the floats with `order`.
+    fn ordered_bits(&self, order: &FloatOrder) -> Self::Bits;
+}
+
+macro_rules! float_key {
+    ($float:ty, $bits:ty, $sign:expr) => {
+        impl FloatKey for $float {
+            type Bits = $bits;
+
+            #[inline]
+            fn is_nan_key(&self) -> bool { self.is_nan() }
+            #[inline]
+            fn is_negative_key(&self) -> bool { self.is_sign_negative() }
+            #[inline]
+            fn ordered_bits(&self, order: &FloatOrder) -> $bits {
+                let bits = if self.is_nan() {
+                    match order.nan {
+                        NanPolicy::First => return <$bits>::MIN,
+                        NanPolicy::Last => return <$bits>::MAX,
+                        _ => self.to_bits(),
+                    }
+                } else if order.equal_zeros && *self == 0.0 {
+                    0
+                } else {
+                    self.to_bits()
+                };
+
+                if bits & $sign == $sign {
+                    !bits
+                } else {
+                    bits ^ $sign
+                }
+            }
+        }
+    };
+}
+
+float_key!(f32, u32, 0x8000_0000);
+float_key!(f64, u64, 0x8000_0000_0000_0000);
+
+// Moves the elements which satisfy `pred` to the front of the array, and
+// returns how many they are. This partition is not stable.
+fn partition_front<T, F: Fn(&T) -> bool>(arr: &mut [T], pred: F) -> usize {
+    let mut count = 0;
+    for i in 0..arr.len() {
+        if pred(&arr[i]) {
+            arr.swap(count, i);
+            count += 1;
+        }
+    }
+    count
+}
+
+fn check_nan<T, K>(arr: &[T], order: &FloatOrder) -> Result<(), SortError>
+where
+    T: Radixable<K, Key = K>,
+    K: FloatKey,
+{
+    if order.nan == NanPolicy::Error
+        && arr.iter().any(|item| item.key().is_nan_key())
+    {
+        Err(SortError::NanKey)
+    } else {
+        Ok(())
+    }
+}
+
+// Unstable sort with a `FloatOrder`: the NaNs are moved out of the array
+// first, so `sort` only sees ordered keys. `sort` orders the keys by their
+// bits, so `-0.0` is already before `0.0`.
+fn sort_floats_with<T, K, F>(arr: &mut [T], order: &FloatOrder, sort: F)
+where
+    T: Radixable<K, Key = K>,
+    K: FloatKey,
+    F: Fn(&mut [T]),
+{
+    let is_nan = |item: &T| item.key().is_nan_key();
+    let (head, rest) = match order.nan {
+        NanPolicy::First | NanPolicy::TotalOrder => {
+            let nan_first = if order.nan == NanPolicy::First {
+                partition_front(arr, is_nan)
+            } else {
+                partition_front(arr, |item: &T| {
+                    is_nan(item) && item.key().is_negative_key()
+                })
+            };
+            arr.split_at_mut(nan_first)
+        },
+        NanPolicy::Last | NanPolicy::Error => arr.split_at_mut(0),
+    };
+    let (values, tail) = match order.nan {
+        NanPolicy::Error => rest.split_at_mut(rest.len()),
+        _ => {
+            let values_n = partition_front(rest, |item: &T| !is_nan(item));
+            rest.split_at_mut(values_n)
+        },
+    };
+
+    if order.nan == NanPolicy::TotalOrder {
+        head.sort_unstable_by_key(|item| item.into_key_type());
+        tail.sort_unstable_by_key(|item| item.into_key_type());
+    }
+
+    sort(values);
+}
+
+/// # Voracious sort with a float order
+///
+/// Sorts an array whose keys are `f32` or `f64` with the given NaN and signed
+/// zero policy. The result does not depend on the length of the array.
+///
+/// With `NanPolicy::Error`, it fails if a key is NaN. When `equal_zeros` is
+/// set, `-0.0` and `0.0` may be in any order.
+///
+/// This sort is unstable.
+pub fn voracious_sort_floats<T, K>(
+    arr: &mut [T],
+    order: FloatOrder,
+) -> Result<(), SortError>
+where
+    T: Radixable<K, Key = K>,
+    K: FloatKey,
+{
+    check_nan(arr, &order)?;
+    sort_floats_with(arr, &order, |values| values.voracious_sort());
+    Ok(())
+}
+
+/// # Voracious stable sort with a float order
+///
+/// Same as `voracious_sort_floats`, but the sort is stable: the elements with
+/// equal keys (the NaNs with `NanPolicy::First` or `NanPolicy::Last`, and the
+/// zeros when `equal_zeros` is set) keep their order.
+///
+/// The array is sorted by the integer encoding of its keys in `order`.
+pub fn voracious_stable_sort_floats<T, K>(
+    arr: &mut [T],
+    order: FloatOrder,
+) -> Result<(), SortError>
+where
+    T: Radixable<K, Key = K>,
+    K: FloatKey,
+    KeyIndex<K::Bits>: Radixable<K::Bits>,
+{
+    check_nan(arr, &order)?;
+    arr.voracious_stable_sort_by_key(|item| item.key().ordered_bits(&order));
+    Ok(())
+}
+
+/// # Voracious multithread sort with a float order
+///
+/// Same as `voracious_sort_floats`, with `voracious_mt_sort`.
+///
+/// This sort is unstable.
+#[cfg(feature = "voracious_multithread")]
+pub fn voracious_mt_sort_floats<T, K>(
+    arr: &mut [T],
+    order: FloatOrder,
+    thread_n: usize,
+) -> Result<(), SortError>
+where
+    T: Radixable<K, Key = K>,
+    K: FloatKey,
+{
+    check_nan(arr, &order)?;
+    sort_floats_with(arr, &order, |values| values.voracious_mt_sort(thread_n));
+    Ok(())
+}
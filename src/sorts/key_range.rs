@@ -0,0 +1,148 @@
+use alloc::vec::Vec;
+
+use super::super::{RadixKey, Radixable};
+use super::utils::{get_empty_histograms, Params};
+
+// Key range reduction: when the keys are clustered far from zero (timestamps,
+// signed values around a constant, floats in a narrow range...), the minimum
+// key is subtracted from every key before the digits are extracted. The sorts
+// then only need enough passes to cover `max - min` instead of `max`.
+//
+// The reduced key `key - min` always fits in the width of the key type, so the
+// masks and shifts of the `Params` stay valid. It is computed in `u128`, since
+// all the key types convert into it.
+
+#[inline]
+pub fn reduced_key<T, K>(item: &T, min: u128) -> u128
+where
+    T: Radixable<K>,
+    K: RadixKey,
+{
+    let key: u128 = item.into_key_type().into();
+    key - min
+}
+
+#[inline]
+pub fn reduced_digit<T, K>(
+    item: &T,
+    min: u128,
+    mask: u128,
+    shift: usize,
+) -> usize
+where
+    T: Radixable<K>,
+    K: RadixKey,
+{
+    ((reduced_key(item, min) & mask) >> shift) as usize
+}
+
+pub fn compute_key_range<T, K>(
+    arr: &[T],
+) -> (
+    <<T as Radixable<K>>::Key as RadixKey>::Key,
+    <<T as Radixable<K>>::Key as RadixKey>::Key,
+)
+where
+    T: Radixable<K>,
+    K: RadixKey,
+{
+    let first = arr[0].into_key_type();
+    arr.iter().fold((first, first), |(min, max), item| {
+        let key = item.into_key_type();
+        (if key < min { key } else { min }, if key > max { key } else { max })
+    })
+}
+
+#[inline]
+fn significant_bits(v: u128) -> usize { 128 - v.leading_zeros() as usize }
+
+// Same computation as `offset_from_bits`, from the number of significant bits.
+fn offsets_from_count(
+    count: usize,
+    bits: usize,
+    radix: usize,
+) -> (usize, usize) {
+    let offset = bits.saturating_sub(((count + radix - 1) / radix) * radix);
+
+    (offset, bits - count)
+}
+
+/// Returns the offset, the raw offset and the minimum key to subtract, if
+/// subtracting the minimum key saves at least one pass at this radix.
+pub fn reduce_key_range<T, K>(
+    dummy: &T,
+    min: <<T as Radixable<K>>::Key as RadixKey>::Key,
+    max: <<T as Radixable<K>>::Key as RadixKey>::Key,
+    radix: usize,
+) -> Option<(usize, usize, u128)>
+where
+    T: Radixable<K>,
+    K: RadixKey,
+{
+    let (min, max): (u128, u128) = (min.into(), max.into());
+    let plain_count = significant_bits(max);
+    let reduced_count = significant_bits(max - min);
+
+    if (reduced_count + radix - 1) / radix < (plain_count + radix - 1) / radix {
+        let (offset, raw_offset) =
+            offsets_from_count(reduced_count, dummy.type_size(), radix);
+        Some((offset, raw_offset, min))
+    } else {
+        None
+    }
+}
+
+/// Same as `compute_offset`, but the minimum and the maximum keys are
+/// computed in the same pass. If subtracting the minimum key saves at least
+/// one pass, the offsets are the offsets of the reduced keys, and the minimum
+/// key is returned too.
+pub fn compute_offset_reduced<T, K>(
+    arr: &[T],
+    radix: usize,
+) -> (usize, usize, Option<u128>)
+where
+    T: Radixable<K>,
+    K: RadixKey,
+{
+    let dummy = arr[0];
+    let (min, max) = compute_key_range(arr);
+
+    match reduce_key_range(&dummy, min, max, radix) {
+        Some((offset, raw_offset, key_min)) => {
+            (offset, raw_offset, Some(key_min))
+        },
+        None => {
+            let max: u128 = max.into();
+            let (offset, raw_offset) = offsets_from_count(
+                significant_bits(max),
+                dummy.type_size(),
+                radix,
+            );
+            (offset, raw_offset, None)
+        },
+    }
+}
+
+// Same as `get_full_histograms`, on the reduced keys.
+pub fn get_full_histograms_reduced<T, K>(
+    arr: &[T],
+    p: &Params,
+    min: u128,
+) -> Vec<Vec<usize>>
+where
+    T: Radixable<K>,
+    K: RadixKey,
+{
+    let mut histograms = get_empty_histograms(p.max_level, p.radix_range);
+    let mask = (p.radix_range - 1) as u128;
+
+    for item in arr.iter() {
+        let mut v = reduced_key(item, min);
+        for histogram in histograms.iter_mut().rev() {
+            histogram[(v & mask) as usize] += 1;
+            v >>= p.radix;
+        }
+    }
+
+    histograms
+}
@@ -0,0 +1,147 @@
+use rayon::iter::ParallelIterator;
+use rayon::slice::ParallelSliceMut;
+
+use super::super::algo::k_way_merge::k_way_merge;
+use super::super::algo::verge_sort_heuristic::verge_sort_preprocessing_stable;
+use super::super::{RadixKey, Radixable};
+use super::comparative_sort::key_cmp;
+use super::lsd_sort::lsd_radixsort_body;
+use super::utils::{
+    copy_nonoverlapping, get_histogram, only_one_bucket_filled, Params,
+};
+use super::utils_mt::{
+    aggregate_histograms, run_in_pool, scatter_by_histograms_mt,
+};
+
+const FALLBACK_THRESHOLD: usize = 128;
+
+pub fn lsd_mt_radixsort_body<T, K>(arr: &mut [T], p: Params)
+where
+    T: Radixable<K>,
+    K: RadixKey,
+{
+    let thread_n = rayon::current_num_threads();
+    if thread_n == 1 {
+        lsd_radixsort_body(arr, p);
+        return;
+    }
+
+    let size = arr.len();
+    let dummy = arr[0];
+    let chunk_size = (size + thread_n - 1) / thread_n;
+
+    // The histograms of all the levels are computed in one read of each chunk.
+    let chunks_histograms: Vec<Vec<Vec<usize>>> = arr
+        .par_chunks_mut(chunk_size)
+        .map(|chunk| dummy.get_full_histograms(chunk, &p))
+        .collect();
+    let level_histograms = |level: usize| -> Vec<Vec<usize>> {
+        chunks_histograms.iter().map(|h| h[level].to_vec()).collect()
+    };
+
+    let mut buffer: Vec<T> = vec![arr[0]; size];
+    let mut index = 0;
+    let mut first_pass = true;
+
+    for level in (p.level..p.max_level).rev() {
+        let global_histogram = aggregate_histograms(&level_histograms(level));
+        if only_one_bucket_filled(&global_histogram) {
+            continue;
+        }
+
+        let (mask, shift) = dummy.get_mask_and_shift(&p.new_level(level));
+        let (source, destination) = if index == 0 {
+            (&mut *arr, buffer.as_mut_slice())
+        } else {
+            (buffer.as_mut_slice(), &mut *arr)
+        };
+
+        // Until the first pass, each chunk still holds its own elements.
+        let histograms = if first_pass {
+            level_histograms(level)
+        } else {
+            source
+                .par_chunks_mut(chunk_size)
+                .map(|chunk| get_histogram(chunk, &p, mask, shift))
+                .collect()
+        };
+
+        scatter_by_histograms_mt(
+            source,
+            destination,
+            chunk_size,
+            &histograms,
+            &global_histogram,
+            &p,
+            mask,
+            shift,
+        );
+
+        first_pass = false;
+        index = 1 - index;
+    }
+
+    if index == 1 {
+        copy_nonoverlapping(&mut buffer, arr, size);
+    }
+}
+
+fn lsd_mt_radixsort_aux<T, K>(arr: &mut [T], radix: usize)
+where
+    T: Radixable<K>,
+    K: RadixKey,
+{
+    if arr.len() <= FALLBACK_THRESHOLD {
+        arr.sort_by(key_cmp);
+        return;
+    }
+
+    let dummy = arr[0];
+    let (offset, _) = dummy.compute_offset_mt(arr, radix);
+    let max_level = dummy.compute_max_level(offset, radix);
+
+    if max_level == 0 {
+        return;
+    }
+
+    let params = Params::new(0, radix, offset, max_level);
+
+    lsd_mt_radixsort_body(arr, params);
+}
+
+/// # LSD multithread sort
+///
+/// A multithread implementation of the
+/// [LSD sort](https://en.wikipedia.org/wiki/Radix_sort)
+/// algorithm.
+///
+/// The array is cut into one chunk per thread. Each thread computes the
+/// histograms of all the levels of its chunk in one read, the histograms are
+/// aggregated (prefix sums over the threads), then for each pass each thread
+/// scatters its chunk into the buffer from its own heads.
+///
+/// The Verge sort pre-processing heuristic is also added.
+///
+/// The sort runs on a new pool of `thread_n` threads, or on the current rayon
+/// pool if `thread_n` is 0.
+///
+/// This LSD sort is an out of place stable radix sort.
+pub fn lsd_mt_radixsort<T, K>(arr: &mut [T], radix: usize, thread_n: usize)
+where
+    T: Radixable<K>,
+    K: RadixKey,
+{
+    if arr.len() <= FALLBACK_THRESHOLD {
+        arr.sort_by(key_cmp);
+        return;
+    }
+
+    run_in_pool(thread_n, || {
+        let mut separators = verge_sort_preprocessing_stable(
+            arr,
+            radix,
+            &lsd_mt_radixsort_aux,
+        );
+        k_way_merge(arr, &mut separators);
+    });
+}
@@ -1,10 +1,15 @@
+use alloc::vec;
+use alloc::vec::Vec;
+
 use super::super::algo::k_way_merge::k_way_merge;
 use super::super::algo::verge_sort_heuristic::verge_sort_preprocessing;
 use super::super::{RadixKey, Radixable};
+use super::comparative_sort::key_cmp;
 use super::counting_sort::counting_sort;
-use super::msd_sort::copy_by_histogram;
+use super::key_range::{compute_offset_reduced, get_full_histograms_reduced};
+use super::msd_sort::scatter_by_histogram;
 use super::utils::{
-    copy_nonoverlapping, only_one_bucket_filled, prefix_sums, Params,
+    copy_nonoverlapping, only_one_bucket_filled, prefix_sums_into, Params,
 };
 
 pub fn lsd_radixsort_body<T, K>(arr: &mut [T], p: Params)
@@ -13,36 +18,65 @@ where
     K: RadixKey,
 {
     if arr.len() <= 128 {
-        arr.sort_unstable_by(|a, b| a.partial_cmp(b).unwrap());
+        arr.sort_unstable_by(key_cmp);
         return;
     }
 
     let size = arr.len();
     let dummy = arr[0];
     let mut buffer: Vec<T> = vec![arr[0]; size];
-    let mut index = 0;
+    let mut heads = vec![0; p.radix_range];
+
+    let histograms = match p.key_min {
+        Some(min) => get_full_histograms_reduced(arr, &p, min),
+        None => dummy.get_full_histograms(arr, &p),
+    };
+
+    lsd_radixsort_body_with_buffer(
+        arr,
+        &mut buffer,
+        &histograms,
+        &mut heads,
+        p,
+    );
+}
 
-    let histograms = dummy.get_full_histograms(arr, &p);
+// LSD sort without any allocation: the buffer (at least as long as `arr`),
+// the histograms (computed by `fill_full_histograms`) and the heads (at least
+// `p.radix_range` long) are provided by the caller.
+pub fn lsd_radixsort_body_with_buffer<T, K>(
+    arr: &mut [T],
+    buffer: &mut [T],
+    histograms: &[Vec<usize>],
+    heads: &mut [usize],
+    p: Params,
+) where
+    T: Radixable<K>,
+    K: RadixKey,
+{
+    let size = arr.len();
+    let dummy = arr[0];
+    let mut index = 0;
 
     let mut t1 = arr;
-    let t2 = &mut buffer;
-    let mut t2 = t2.as_mut_slice();
+    let mut t2 = &mut buffer[0..size];
 
     for level in (p.level..p.max_level).rev() {
         if only_one_bucket_filled(&histograms[level]) {
             continue;
         }
 
-        let (mut source, mut destination) =
+        let (source, destination) =
             if index == 0 { (t1, t2) } else { (t2, t1) };
         let (mask, shift) = dummy.get_mask_and_shift(&p.new_level(level));
-        let (_, mut heads, _) = prefix_sums(&histograms[level]);
+        prefix_sums_into(&histograms[level], heads);
 
-        copy_by_histogram(
+        scatter_by_histogram(
+            &p,
             source.len(),
-            &mut source,
-            &mut destination,
-            &mut heads,
+            source,
+            destination,
+            heads,
             mask,
             shift,
         );
@@ -68,14 +102,15 @@ pub fn lsd_radixsort_aux<T: Radixable<K>, K: RadixKey>(
     radix: usize,
     heuristic: bool,
     min_cs2: usize,
+    write_combining: bool,
 ) {
     if arr.len() <= 128 {
-        arr.sort_unstable_by(|a, b| a.partial_cmp(b).unwrap());
+        arr.sort_unstable_by(key_cmp);
         return;
     }
 
     let dummy = arr[0];
-    let (offset, _) = dummy.compute_offset(arr, radix);
+    let (offset, _, key_min) = compute_offset_reduced(arr, radix);
     let max_level = dummy.compute_max_level(offset, radix);
 
     let (offset_reg, _) = dummy.compute_offset(arr, 8);
@@ -85,7 +120,9 @@ pub fn lsd_radixsort_aux<T: Radixable<K>, K: RadixKey>(
         return;
     }
 
-    let params = Params::new(0, radix, offset, max_level);
+    let params = Params::new(0, radix, offset, max_level)
+        .with_write_combining(write_combining)
+        .with_key_min(key_min);
 
     if heuristic {
         if max_level_reg == 1 {
@@ -124,12 +161,12 @@ where
     K: RadixKey,
 {
     if arr.len() <= 128 {
-        arr.sort_unstable_by(|a, b| a.partial_cmp(b).unwrap());
+        arr.sort_unstable_by(key_cmp);
         return;
     }
 
     let mut separators = verge_sort_preprocessing(arr, radix, &|arr, radix| {
-        lsd_radixsort_aux(arr, radix, false, 0)
+        lsd_radixsort_aux(arr, radix, false, 0, false)
     });
     k_way_merge(arr, &mut separators);
 }
@@ -140,12 +177,37 @@ where
     K: RadixKey,
 {
     if arr.len() <= 128 {
-        arr.sort_unstable_by(|a, b| a.partial_cmp(b).unwrap());
+        arr.sort_unstable_by(key_cmp);
+        return;
+    }
+
+    let mut separators = verge_sort_preprocessing(arr, radix, &|arr, radix| {
+        lsd_radixsort_aux(arr, radix, true, min_cs2, false)
+    });
+    k_way_merge(arr, &mut separators);
+}
+
+/// # LSD sort with write-combining scatter
+///
+/// Same as `lsd_radixsort`, but the elements are staged in small per-bucket
+/// buffers, one cache line each, and flushed to their buckets by blocks. It is
+/// faster with a large radix or a very large array, when the scatter step
+/// thrashes the TLB and the cache.
+///
+/// This LSD sort is an out of place unstable radix sort. The core algorithm is
+/// stable, but fallback is unstable.
+pub fn lsd_radixsort_wc<T, K>(arr: &mut [T], radix: usize)
+where
+    T: Radixable<K>,
+    K: RadixKey,
+{
+    if arr.len() <= 128 {
+        arr.sort_unstable_by(key_cmp);
         return;
     }
 
     let mut separators = verge_sort_preprocessing(arr, radix, &|arr, radix| {
-        lsd_radixsort_aux(arr, radix, true, min_cs2)
+        lsd_radixsort_aux(arr, radix, false, 0, true)
     });
     k_way_merge(arr, &mut separators);
 }
@@ -1,6 +1,7 @@
 use super::super::algo::k_way_merge::k_way_merge;
-use super::super::algo::verge_sort_heuristic::verge_sort_preprocessing;
+use super::super::algo::verge_sort_heuristic::verge_sort_preprocessing_stable;
 use super::super::{RadixKey, Radixable};
+use super::comparative_sort::stable_sort;
 use super::lsd_sort::lsd_radixsort_aux;
 
 /// # LSD stable sort
@@ -26,16 +27,17 @@ where
     K: RadixKey,
 {
     if arr.len() <= 128 {
-        arr.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        stable_sort(arr);
         return;
     }
 
-    let mut separators = verge_sort_preprocessing(arr, radix, &|arr, radix| {
-        if arr.len() <= 128 {
-            arr.sort_by(|a, b| a.partial_cmp(b).unwrap());
-        } else {
-            lsd_radixsort_aux(arr, radix, false, 0)
-        }
-    });
+    let mut separators =
+        verge_sort_preprocessing_stable(arr, radix, &|arr, radix| {
+            if arr.len() <= 128 {
+                stable_sort(arr);
+            } else {
+                lsd_radixsort_aux(arr, radix, false, 0, false)
+            }
+        });
     k_way_merge(arr, &mut separators);
 }
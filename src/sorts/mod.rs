@@ -1,16 +1,35 @@
+pub mod adaptive_sort;
 pub mod american_flag_sort;
+pub mod argsort;
+pub mod bits_sort;
 pub mod boolean_sort;
 pub mod comparative_sort;
 pub mod counting_sort;
 pub mod dlsd_sort;
+pub mod float_sort;
+pub mod key_range;
+#[cfg(feature = "voracious_multithread")] pub mod lsd_mt_sort;
 pub mod lsd_sort;
 pub mod lsd_stable_sort;
+#[cfg(feature = "voracious_multithread")] pub mod msd_mt_stable_sort;
 pub mod msd_sort;
 pub mod msd_stable_sort;
+pub mod owned_sort;
+pub mod pairs_sort;
 #[cfg(feature = "voracious_multithread")] pub mod peeka_sort;
+#[cfg(feature = "voracious_multithread_std")] pub mod peeka_std_sort;
 pub mod rollercoaster_sort;
+pub mod scratch;
+#[cfg(feature = "voracious_multithread")] pub mod select_mt_sort;
+pub mod select_sort;
 pub mod ska_sort;
+pub mod sort_context;
+#[cfg(feature = "voracious_multithread_std")] pub mod std_mt_stable_sort;
+pub mod string_sort;
 pub mod thiel_sort;
+pub mod try_sort;
 pub mod utils;
+pub mod utils_simd;
 #[cfg(feature = "voracious_multithread")] pub mod utils_mt;
 pub mod voracious_sort;
+#[cfg(feature = "voracious_multithread_std")] pub mod work_queue;
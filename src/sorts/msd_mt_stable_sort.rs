@@ -0,0 +1,114 @@
+use super::super::algo::k_way_merge::k_way_merge;
+use super::super::algo::verge_sort_heuristic::verge_sort_preprocessing_stable;
+use super::super::{RadixKey, Radixable};
+use super::comparative_sort::key_cmp;
+use super::msd_stable_sort::msd_stable_radixsort_rec;
+use super::utils::Params;
+use super::utils_mt::{run_in_pool, stable_pass_mt};
+
+const FALLBACK_THRESHOLD: usize = 100_000;
+
+fn msd_mt_stable_radixsort_rec<T, K>(arr: &mut [T], p: Params)
+where
+    T: Radixable<K>,
+    K: RadixKey,
+{
+    if arr.len() <= FALLBACK_THRESHOLD {
+        msd_stable_radixsort_rec(arr, p);
+        return;
+    }
+
+    let dummy = arr[0];
+    let (mask, shift) = dummy.get_mask_and_shift_from_left(&p);
+    let mut buffer = arr.to_vec();
+
+    let histogram = match stable_pass_mt(&mut buffer, arr, &p, mask, shift) {
+        Some(histogram) => histogram,
+        None => {
+            // Only one bucket: the whole array goes to the next level.
+            if p.level < p.max_level - 1 {
+                msd_mt_stable_radixsort_rec(arr, p.new_level(p.level + 1));
+            }
+            return;
+        },
+    };
+    drop(buffer);
+
+    if p.level < p.max_level - 1 {
+        rayon::scope(|s| {
+            let mut rest = arr;
+            for count in histogram.iter() {
+                let (bucket, snd) = rest.split_at_mut(*count);
+                rest = snd;
+                if *count > 1 {
+                    s.spawn(move |_| {
+                        let new_params = p.new_level(p.level + 1);
+                        msd_mt_stable_radixsort_rec(bucket, new_params);
+                    });
+                }
+            }
+        });
+    }
+}
+
+fn msd_mt_stable_radixsort_aux<T, K>(arr: &mut [T], radix: usize)
+where
+    T: Radixable<K>,
+    K: RadixKey,
+{
+    if arr.len() <= 128 {
+        arr.sort_by(key_cmp);
+        return;
+    }
+
+    let dummy = arr[0];
+    let (_, raw_offset) = dummy.compute_offset_mt(arr, radix);
+    let max_level = dummy.compute_max_level(raw_offset, radix);
+
+    if max_level == 0 {
+        return;
+    }
+
+    let params = Params::new(0, radix, raw_offset, max_level);
+
+    msd_mt_stable_radixsort_rec(arr, params);
+}
+
+/// # MSD multithread stable sort
+///
+/// A multithread implementation of the
+/// [MSD sort](https://en.wikipedia.org/wiki/Radix_sort)
+/// algorithm, meant for wide keys (`u128`, `i128`, tuples, ...) where a LSD
+/// sort would do too many passes.
+///
+/// The first levels are distributed by all the threads, like the LSD
+/// multithread sort, then the buckets are sorted in parallel.
+///
+/// The Verge sort pre-processing heuristic is also added.
+///
+/// The sort runs on a new pool of `thread_n` threads, or on the current rayon
+/// pool if `thread_n` is 0.
+///
+/// This MSD sort is an out of place stable radix sort.
+pub fn msd_mt_stable_radixsort<T, K>(
+    arr: &mut [T],
+    radix: usize,
+    thread_n: usize,
+) where
+    T: Radixable<K>,
+    K: RadixKey,
+{
+    if arr.len() <= 128 {
+        arr.sort_by(key_cmp);
+        return;
+    }
+
+    run_in_pool(thread_n, || {
+        let mut separators = verge_sort_preprocessing_stable(
+            arr,
+            radix,
+            &msd_mt_stable_radixsort_aux,
+        );
+        k_way_merge(arr, &mut separators);
+    });
+}
@@ -1,60 +1,220 @@
+use alloc::vec;
+use alloc::vec::Vec;
+
 use super::super::algo::k_way_merge::k_way_merge;
 use super::super::algo::verge_sort_heuristic::verge_sort_preprocessing;
 use super::super::{RadixKey, Radixable};
+use super::comparative_sort::key_cmp;
+use super::key_range::{compute_offset_reduced, reduced_digit};
 use super::utils::{get_histogram, prefix_sums, Params};
 
 const UNROLL_SIZE: usize = 4;
 
+// Size in bytes of a staging buffer of the write-combining scatter.
+const WC_LINE_SIZE: usize = 64;
+
 #[inline]
 pub fn copy_by_histogram<T, K>(
     size: usize,
     source: &mut [T],
     destination: &mut [T],
-    heads: &mut Vec<usize>,
+    heads: &mut [usize],
     mask: <<T as Radixable<K>>::Key as RadixKey>::Key,
     shift: usize,
 ) where
     T: Radixable<K>,
     K: RadixKey,
+{
+    copy_by_histogram_by(size, source, destination, heads, |item: &T| {
+        item.extract(mask, shift)
+    });
+}
+
+// Same as `copy_by_histogram`, the bucket of an element is given by `digit`.
+#[inline]
+pub fn copy_by_histogram_by<T, F>(
+    size: usize,
+    source: &mut [T],
+    destination: &mut [T],
+    heads: &mut [usize],
+    digit: F,
+) where
+    T: Copy,
+    F: Fn(&T) -> usize,
+{
+    copy_by_histogram_with(size, source, destination, heads, digit, |_, _| {});
+}
+
+// Same as `copy_by_histogram_by`, and `moved(i, j)` is called once the element
+// `i` of the source is copied at the position `j` of the destination, so the
+// data kept out of the elements (the values of the pairs sorts) can follow.
+#[inline]
+pub fn copy_by_histogram_with<T, F, M>(
+    size: usize,
+    source: &mut [T],
+    destination: &mut [T],
+    heads: &mut [usize],
+    digit: F,
+    mut moved: M,
+) where
+    T: Copy,
+    F: Fn(&T) -> usize,
+    M: FnMut(usize, usize),
 {
     let source = &mut source[0..size];
     let remainder = size % UNROLL_SIZE;
     let (source_fst, source_snd) = source.split_at_mut(size - remainder);
 
-    source_fst.chunks_exact(UNROLL_SIZE).for_each(|chunk| unsafe {
-        let b0 = chunk.get_unchecked(0).extract(mask, shift);
-        let b1 = chunk.get_unchecked(1).extract(mask, shift);
-        let b2 = chunk.get_unchecked(2).extract(mask, shift);
-        let b3 = chunk.get_unchecked(3).extract(mask, shift);
-
-        let d0 = *heads.get_unchecked(b0);
-        heads[b0] += 1;
-        let d1 = *heads.get_unchecked(b1);
-        heads[b1] += 1;
-        let d2 = *heads.get_unchecked(b2);
-        heads[b2] += 1;
-        let d3 = *heads.get_unchecked(b3);
-        heads[b3] += 1;
-
-        destination[d0] = *chunk.get_unchecked(0);
-        destination[d1] = *chunk.get_unchecked(1);
-        destination[d2] = *chunk.get_unchecked(2);
-        destination[d3] = *chunk.get_unchecked(3);
-    });
+    source_fst.chunks_exact(UNROLL_SIZE).enumerate().for_each(
+        |(c, chunk)| unsafe {
+            let b0 = digit(chunk.get_unchecked(0));
+            let b1 = digit(chunk.get_unchecked(1));
+            let b2 = digit(chunk.get_unchecked(2));
+            let b3 = digit(chunk.get_unchecked(3));
 
-    for item in source_snd.iter() {
-        let target_bucket = item.extract(mask, shift);
+            let d0 = *heads.get_unchecked(b0);
+            heads[b0] += 1;
+            let d1 = *heads.get_unchecked(b1);
+            heads[b1] += 1;
+            let d2 = *heads.get_unchecked(b2);
+            heads[b2] += 1;
+            let d3 = *heads.get_unchecked(b3);
+            heads[b3] += 1;
+
+            destination[d0] = *chunk.get_unchecked(0);
+            destination[d1] = *chunk.get_unchecked(1);
+            destination[d2] = *chunk.get_unchecked(2);
+            destination[d3] = *chunk.get_unchecked(3);
+
+            let i = c * UNROLL_SIZE;
+            moved(i, d0);
+            moved(i + 1, d1);
+            moved(i + 2, d2);
+            moved(i + 3, d3);
+        },
+    );
+
+    let start = size - remainder;
+    for (i, item) in source_snd.iter().enumerate() {
+        let target_bucket = digit(item);
         destination[heads[target_bucket]] = *item;
+        moved(start + i, heads[target_bucket]);
         heads[target_bucket] += 1;
     }
 }
 
+// Same as `copy_by_histogram`, but the elements are first staged in a small
+// buffer per bucket (one cache line), and a buffer is flushed to its bucket
+// only once full. The destination is written by blocks instead of by
+// elements, which spares the TLB and the cache with a large radix or a large
+// array. The order of the elements within a bucket is kept.
+pub fn copy_by_histogram_wc<T, K>(
+    size: usize,
+    source: &mut [T],
+    destination: &mut [T],
+    heads: &mut [usize],
+    mask: <<T as Radixable<K>>::Key as RadixKey>::Key,
+    shift: usize,
+) where
+    T: Radixable<K>,
+    K: RadixKey,
+{
+    copy_by_histogram_wc_by(size, source, destination, heads, |item: &T| {
+        item.extract(mask, shift)
+    });
+}
+
+// Same as `copy_by_histogram_wc`, the bucket of an element is given by
+// `digit`.
+pub fn copy_by_histogram_wc_by<T, F>(
+    size: usize,
+    source: &mut [T],
+    destination: &mut [T],
+    heads: &mut [usize],
+    digit: F,
+) where
+    T: Copy,
+    F: Fn(&T) -> usize,
+{
+    let slots = WC_LINE_SIZE / core::mem::size_of::<T>().max(1);
+    if slots < 2 || size == 0 {
+        copy_by_histogram_by(size, source, destination, heads, digit);
+        return;
+    }
+
+    let source = &source[0..size];
+    let bucket_n = heads.len();
+    let mut staging: Vec<T> = vec![source[0]; bucket_n * slots];
+    let mut filled: Vec<u8> = vec![0; bucket_n];
+
+    for item in source.iter() {
+        let b = digit(item);
+        unsafe {
+            let f = *filled.get_unchecked(b) as usize;
+            *staging.get_unchecked_mut(b * slots + f) = *item;
+            if f + 1 == slots {
+                let head = *heads.get_unchecked(b);
+                destination[head..head + slots]
+                    .copy_from_slice(&staging[b * slots..(b + 1) * slots]);
+                *heads.get_unchecked_mut(b) = head + slots;
+                *filled.get_unchecked_mut(b) = 0;
+            } else {
+                *filled.get_unchecked_mut(b) = (f + 1) as u8;
+            }
+        }
+    }
+
+    for (b, f) in filled.iter().enumerate() {
+        let f = *f as usize;
+        let head = heads[b];
+        destination[head..head + f]
+            .copy_from_slice(&staging[b * slots..b * slots + f]);
+        heads[b] = head + f;
+    }
+}
+
+// Scatter step of the LSD, DLSD and MSD sorts: picks the write-combining
+// scatter and the reduced digits according to `p`.
+pub fn scatter_by_histogram<T, K>(
+    p: &Params,
+    size: usize,
+    source: &mut [T],
+    destination: &mut [T],
+    heads: &mut [usize],
+    mask: <<T as Radixable<K>>::Key as RadixKey>::Key,
+    shift: usize,
+) where
+    T: Radixable<K>,
+    K: RadixKey,
+{
+    match (p.key_min, p.write_combining) {
+        (None, false) => {
+            copy_by_histogram(size, source, destination, heads, mask, shift)
+        },
+        (None, true) => {
+            copy_by_histogram_wc(size, source, destination, heads, mask, shift)
+        },
+        (Some(min), false) => {
+            let mask = mask.into();
+            copy_by_histogram_by(size, source, destination, heads, |item| {
+                reduced_digit(item, min, mask, shift)
+            })
+        },
+        (Some(min), true) => {
+            let mask = mask.into();
+            copy_by_histogram_wc_by(size, source, destination, heads, |item| {
+                reduced_digit(item, min, mask, shift)
+            })
+        },
+    }
+}
+
 pub fn msd_radixsort_rec<T: Radixable<K>, K: RadixKey>(
     arr: &mut [T],
     p: Params,
 ) {
     if arr.len() <= 128 {
-        arr.sort_unstable_by(|a, b| a.partial_cmp(b).unwrap());
+        arr.sort_unstable_by(key_cmp);
         return;
     }
 
@@ -65,7 +225,15 @@ pub fn msd_radixsort_rec<T: Radixable<K>, K: RadixKey>(
 
     let mut buffer = arr.to_vec();
 
-    copy_by_histogram(arr.len(), &mut buffer, arr, &mut heads, mask, shift);
+    scatter_by_histogram(
+        &p,
+        arr.len(),
+        &mut buffer,
+        arr,
+        &mut heads,
+        mask,
+        shift,
+    );
 
     let mut rest = arr;
     if p.level < p.max_level - 1 {
@@ -76,7 +244,7 @@ pub fn msd_radixsort_rec<T: Radixable<K>, K: RadixKey>(
             if histogram[i] > 1 {
                 if first_part.len() <= 128 {
                     first_part
-                        .sort_unstable_by(|a, b| a.partial_cmp(b).unwrap());
+                        .sort_unstable_by(key_cmp);
                 } else {
                     let new_params = p.new_level(p.level + 1);
                     msd_radixsort_rec(first_part, new_params);
@@ -89,21 +257,24 @@ pub fn msd_radixsort_rec<T: Radixable<K>, K: RadixKey>(
 fn msd_radixsort_aux<T: Radixable<K>, K: RadixKey>(
     arr: &mut [T],
     radix: usize,
+    write_combining: bool,
 ) {
     if arr.len() <= 128 {
-        arr.sort_unstable_by(|a, b| a.partial_cmp(b).unwrap());
+        arr.sort_unstable_by(key_cmp);
         return;
     }
 
     let dummy = arr[0];
-    let (_, raw_offset) = dummy.compute_offset(arr, radix);
+    let (_, raw_offset, key_min) = compute_offset_reduced(arr, radix);
     let max_level = dummy.compute_max_level(raw_offset, radix);
 
     if max_level == 0 {
         return;
     }
 
-    let params = Params::new(0, radix, raw_offset, max_level);
+    let params = Params::new(0, radix, raw_offset, max_level)
+        .with_write_combining(write_combining)
+        .with_key_min(key_min);
 
     msd_radixsort_rec(arr, params);
 }
@@ -130,11 +301,35 @@ pub fn msd_radixsort<T: Radixable<K>, K: RadixKey>(
     radix: usize,
 ) {
     if arr.len() <= 128 {
-        arr.sort_unstable_by(|a, b| a.partial_cmp(b).unwrap());
+        arr.sort_unstable_by(key_cmp);
         return;
     }
 
-    let mut separators =
-        verge_sort_preprocessing(arr, radix, &msd_radixsort_aux);
+    let mut separators = verge_sort_preprocessing(arr, radix, &|arr, radix| {
+        msd_radixsort_aux(arr, radix, false)
+    });
+    k_way_merge(arr, &mut separators);
+}
+
+/// # MSD sort with write-combining scatter
+///
+/// Same as `msd_radixsort`, but the elements are staged in small per-bucket
+/// buffers, one cache line each, and flushed to their buckets by blocks. It is
+/// faster with a large radix or a very large array, when the scatter step
+/// thrashes the TLB and the cache.
+///
+/// This MSD sort is an out of place unstable radix sort.
+pub fn msd_radixsort_wc<T: Radixable<K>, K: RadixKey>(
+    arr: &mut [T],
+    radix: usize,
+) {
+    if arr.len() <= 128 {
+        arr.sort_unstable_by(key_cmp);
+        return;
+    }
+
+    let mut separators = verge_sort_preprocessing(arr, radix, &|arr, radix| {
+        msd_radixsort_aux(arr, radix, true)
+    });
     k_way_merge(arr, &mut separators);
 }
@@ -1,17 +1,18 @@
 use super::super::algo::k_way_merge::k_way_merge;
-use super::super::algo::verge_sort_heuristic::verge_sort_preprocessing;
+use super::super::algo::verge_sort_heuristic::verge_sort_preprocessing_stable;
 use super::super::{RadixKey, Radixable};
+use super::comparative_sort::stable_sort;
 use super::msd_sort::copy_by_histogram;
 use super::utils::{get_histogram, prefix_sums, Params};
 
 const FALLBACK_THRESHOLD: usize = 128;
 
-fn msd_stable_radixsort_rec<T: Radixable<K>, K: RadixKey>(
+pub fn msd_stable_radixsort_rec<T: Radixable<K>, K: RadixKey>(
     arr: &mut [T],
     p: Params,
 ) {
     if arr.len() <= FALLBACK_THRESHOLD {
-        arr.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        stable_sort(arr);
         return;
     }
 
@@ -43,7 +44,7 @@ fn msd_stable_radixsort_aux<T: Radixable<K>, K: RadixKey>(
     radix: usize,
 ) {
     if arr.len() <= FALLBACK_THRESHOLD {
-        arr.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        stable_sort(arr);
         return;
     }
 
@@ -81,11 +82,11 @@ pub fn msd_stable_radixsort<T: Radixable<K>, K: RadixKey>(
     radix: usize,
 ) {
     if arr.len() <= FALLBACK_THRESHOLD {
-        arr.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        stable_sort(arr);
         return;
     }
 
     let mut separators =
-        verge_sort_preprocessing(arr, radix, &msd_stable_radixsort_aux);
+        verge_sort_preprocessing_stable(arr, radix, &msd_stable_radixsort_aux);
     k_way_merge(arr, &mut separators);
 }
@@ -0,0 +1,431 @@
+use alloc::vec;
+use alloc::vec::Vec;
+use core::mem::MaybeUninit;
+use core::ptr;
+
+use super::super::{RadixKey, RadixableOwned};
+use super::utils::{
+    compute_max_level, get_empty_histograms, only_one_bucket_filled,
+    prefix_sums, Params,
+};
+
+// Elements which are not `Copy` cannot be duplicated. The keys are extracted
+// once, before any element is moved, then the keys and the elements are moved
+// together. No user code (`key`, `partial_cmp`, ...) runs while an element is
+// moved, so a panic cannot leave the array with a duplicated element.
+
+fn extract_keys<T, K>(
+    arr: &[T],
+) -> Vec<<<T as RadixableOwned<K>>::Key as RadixKey>::Key>
+where
+    T: RadixableOwned<K>,
+    K: RadixKey,
+{
+    arr.iter().map(|item| item.into_key_type()).collect()
+}
+
+#[inline]
+fn digit<KT: Copy + Into<u128>>(key: KT, mask: u128, shift: usize) -> usize {
+    ((key.into() >> shift) & mask) as usize
+}
+
+#[inline]
+fn default_mask(radix: usize) -> u128 { (1 << radix) - 1 }
+
+// Returns `None` when all the keys are zero: the array is already sorted.
+fn compute_params<KT: Copy + Into<u128>>(
+    keys: &[KT],
+    bits: usize,
+    radix: usize,
+) -> Option<Params> {
+    let all_bits = keys.iter().fold(0u128, |acc, key| acc | (*key).into());
+
+    if all_bits == 0 {
+        return None;
+    }
+
+    let offset = all_bits.leading_zeros() as usize - (128 - bits);
+    let max_level = compute_max_level(bits, offset, radix);
+
+    Some(Params::new(0, radix, offset, max_level))
+}
+
+fn get_keys_histograms<KT: Copy + Into<u128>>(
+    keys: &[KT],
+    p: &Params,
+) -> Vec<Vec<usize>> {
+    let mut histograms = get_empty_histograms(p.max_level, p.radix_range);
+    let mask = default_mask(p.radix);
+
+    keys.iter().for_each(|key| {
+        for (level, histogram) in histograms.iter_mut().enumerate() {
+            let shift = p.radix * (p.max_level - level - 1);
+            histogram[digit(*key, mask, shift)] += 1;
+        }
+    });
+
+    histograms
+}
+
+fn insertion_sort_by_keys<T, KT: Copy + Ord>(arr: &mut [T], keys: &mut [KT]) {
+    for i in 1..keys.len() {
+        let mut j = i;
+        while j > 0 && keys[j - 1] > keys[j] {
+            keys.swap(j - 1, j);
+            arr.swap(j - 1, j);
+            j -= 1;
+        }
+    }
+}
+
+// Moves the elements from `source` to `destination` in the order of their
+// digit at `shift`, and their keys from `keys` to `keys_destination`.
+unsafe fn move_by_digit<T, KT: Copy + Into<u128>>(
+    source: *const T,
+    destination: *mut T,
+    keys: &[KT],
+    keys_destination: &mut [KT],
+    heads: &mut [usize],
+    mask: u128,
+    shift: usize,
+) {
+    for (i, key) in keys.iter().enumerate() {
+        let bucket = digit(*key, mask, shift);
+        let position = heads[bucket];
+        heads[bucket] += 1;
+        keys_destination[position] = *key;
+        ptr::copy_nonoverlapping(source.add(i), destination.add(position), 1);
+    }
+}
+
+/// # LSD stable sort for owned elements
+///
+/// An implementation of the
+/// [LSD sort](https://en.wikipedia.org/wiki/Radix_sort)
+/// algorithm for elements which are not `Copy` (structs holding a `String`,
+/// a `Vec`, a `Box`, ...).
+///
+/// The keys are extracted once, then each pass moves the keys and the
+/// elements into a buffer with `ptr::copy_nonoverlapping`. Elements are never
+/// cloned nor dropped by the sort.
+///
+/// This LSD sort is an out of place stable radix sort.
+pub fn lsd_stable_radixsort_owned<T, K>(arr: &mut [T], radix: usize)
+where
+    T: RadixableOwned<K>,
+    K: RadixKey,
+{
+    if arr.len() <= 128 {
+        arr.sort_by_key(|e| e.into_key_type());
+        return;
+    }
+
+    let size = arr.len();
+    let bits = arr[0].key().type_size();
+    let mut keys = extract_keys(arr);
+
+    let p = match compute_params(&keys, bits, radix) {
+        Some(p) => p,
+        None => return,
+    };
+    let mask = default_mask(radix);
+    let histograms = get_keys_histograms(&keys, &p);
+
+    let mut keys_buffer = keys.to_vec();
+    // The buffer never owns the elements: its length is set so the
+    // `MaybeUninit` slots can be written, and they are never dropped.
+    let mut buffer: Vec<MaybeUninit<T>> = Vec::with_capacity(size);
+    unsafe {
+        buffer.set_len(size);
+    }
+
+    let arr_ptr = arr.as_mut_ptr();
+    let mut source = arr_ptr;
+    let mut destination = buffer.as_mut_ptr() as *mut T;
+
+    for level in (0..p.max_level).rev() {
+        if only_one_bucket_filled(&histograms[level]) {
+            continue;
+        }
+
+        let shift = radix * (p.max_level - level - 1);
+        let (_, mut heads, _) = prefix_sums(&histograms[level]);
+
+        unsafe {
+            move_by_digit(
+                source,
+                destination,
+                &keys,
+                &mut keys_buffer,
+                &mut heads,
+                mask,
+                shift,
+            );
+        }
+
+        core::mem::swap(&mut keys, &mut keys_buffer);
+        core::mem::swap(&mut source, &mut destination);
+    }
+
+    if source != arr_ptr {
+        unsafe {
+            ptr::copy_nonoverlapping(source, arr_ptr, size);
+        }
+    }
+}
+
+fn msd_radixsort_owned_rec<T, KT: Copy + Ord + Into<u128>>(
+    arr: &mut [T],
+    keys: &mut [KT],
+    p: Params,
+) {
+    if arr.len() <= 64 {
+        insertion_sort_by_keys(arr, keys);
+        return;
+    }
+
+    let mask = default_mask(p.radix);
+    let shift = p.radix * (p.max_level - p.level - 1);
+    let mut histogram = vec![0; p.radix_range];
+    keys.iter()
+        .for_each(|key| histogram[digit(*key, mask, shift)] += 1);
+    let (p_sums, mut heads, tails) = prefix_sums(&histogram);
+
+    for i in 0..(p.radix_range) - 1 {
+        while heads[i] < tails[i] {
+            let mut bucket = digit(keys[heads[i]], mask, shift);
+            while bucket != i {
+                keys.swap(heads[i], heads[bucket]);
+                arr.swap(heads[i], heads[bucket]);
+                heads[bucket] += 1;
+                bucket = digit(keys[heads[i]], mask, shift);
+            }
+            heads[i] += 1;
+        }
+    }
+
+    let mut rest = arr;
+    let mut rest_keys = keys;
+    if p.level < p.max_level - 1 {
+        for i in 0..(p.radix_range) {
+            let bucket_end = p_sums[i + 1] - p_sums[i];
+            let (first_part, second_part) = rest.split_at_mut(bucket_end);
+            let (first_keys, second_keys) =
+                rest_keys.split_at_mut(bucket_end);
+            rest = second_part;
+            rest_keys = second_keys;
+            if histogram[i] > 1 {
+                let new_params = p.new_level(p.level + 1);
+                msd_radixsort_owned_rec(first_part, first_keys, new_params);
+            }
+        }
+    }
+}
+
+/// # MSD sort for owned elements
+///
+/// An implementation of the
+/// [American flag sort](https://en.wikipedia.org/wiki/American_flag_sort)
+/// algorithm for elements which are not `Copy` (structs holding a `String`,
+/// a `Vec`, a `Box`, ...).
+///
+/// The keys are extracted once, then the keys and the elements are swapped
+/// together. Elements are never cloned nor dropped by the sort.
+///
+/// This MSD sort is an in place unstable radix sort (only the keys are
+/// allocated).
+pub fn msd_radixsort_owned<T, K>(arr: &mut [T], radix: usize)
+where
+    T: RadixableOwned<K>,
+    K: RadixKey,
+{
+    if arr.len() <= 64 {
+        arr.sort_unstable_by_key(|e| e.into_key_type());
+        return;
+    }
+
+    let bits = arr[0].key().type_size();
+    let mut keys = extract_keys(arr);
+
+    if let Some(p) = compute_params(&keys, bits, radix) {
+        msd_radixsort_owned_rec(arr, &mut keys, p);
+    }
+}
+
+/// # Thiel sort for owned elements
+///
+/// An implementation of the
+/// [Fast radix sort](https://github.com/AwardOfSky/Fast-Radix-Sort)
+/// algorithm for elements which are not `Copy` (structs holding a `String`,
+/// a `Vec`, a `Box`, ...).
+///
+/// The first pass moves the elements into buckets of an estimated size. The
+/// elements which do not fit in their bucket are kept at the start of the
+/// array, and the second pass gathers both. The next passes are regular LSD
+/// passes. Elements are never cloned nor dropped by the sort.
+///
+/// Unlike `thiel_radixsort`, there is no Verge sort pre-processing and no
+/// unstable fallback: this Thiel sort is an out of place stable radix sort.
+pub fn thiel_radixsort_owned<T, K>(arr: &mut [T], radix: usize)
+where
+    T: RadixableOwned<K>,
+    K: RadixKey,
+{
+    if arr.len() <= 128 {
+        arr.sort_by_key(|e| e.into_key_type());
+        return;
+    }
+
+    let size = arr.len();
+    let bits = arr[0].key().type_size();
+    let mut keys = extract_keys(arr);
+
+    let p = match compute_params(&keys, bits, radix) {
+        Some(p) => p,
+        None => return,
+    };
+    let mask = default_mask(radix);
+    let histograms = get_keys_histograms(&keys, &p);
+    let arr_ptr = arr.as_mut_ptr();
+
+    // `estimated_size * radix_range` is at least `size`: the buffer is also
+    // used by the LSD passes.
+    let estimated_size = (size >> radix) + size % radix + 1;
+    let buffer_size = estimated_size * p.radix_range;
+    let mut keys_buffer = vec![keys[0]; buffer_size];
+    let mut buffer: Vec<MaybeUninit<T>> = Vec::with_capacity(buffer_size);
+    unsafe {
+        buffer.set_len(buffer_size);
+    }
+    let buffer_ptr = buffer.as_mut_ptr() as *mut T;
+
+    if p.max_level < 2 {
+        let (_, mut heads, _) = prefix_sums(&histograms[0]);
+        unsafe {
+            move_by_digit(
+                arr_ptr,
+                buffer_ptr,
+                &keys,
+                &mut keys_buffer,
+                &mut heads,
+                mask,
+                0,
+            );
+            ptr::copy_nonoverlapping(buffer_ptr, arr_ptr, size);
+        }
+        return;
+    }
+
+    // First pass, on the last digit: the elements of a full bucket are moved,
+    // in order, to the start of the array.
+    let mut estimated_heads: Vec<usize> =
+        (0..p.radix_range).map(|bucket| bucket * estimated_size).collect();
+    let mut overflow_histogram = vec![0; p.radix_range];
+    let mut overflow_size = 0;
+    for i in 0..size {
+        let key = keys[i];
+        let bucket = digit(key, mask, 0);
+        let position = estimated_heads[bucket];
+        if position < (bucket + 1) * estimated_size {
+            estimated_heads[bucket] += 1;
+            keys_buffer[position] = key;
+            unsafe {
+                ptr::copy_nonoverlapping(
+                    arr_ptr.add(i),
+                    buffer_ptr.add(position),
+                    1,
+                );
+            }
+        } else {
+            overflow_histogram[bucket] += 1;
+            keys[overflow_size] = key;
+            unsafe {
+                ptr::copy(arr_ptr.add(i), arr_ptr.add(overflow_size), 1);
+            }
+            overflow_size += 1;
+        }
+    }
+
+    // The overflow elements are grouped by bucket.
+    let (_, mut overflow_heads, _) = prefix_sums(&overflow_histogram);
+    let overflow_starts = overflow_heads.to_vec();
+    let mut overflow_keys = vec![keys[0]; overflow_size];
+    let mut overflow: Vec<MaybeUninit<T>> = Vec::with_capacity(overflow_size);
+    unsafe {
+        overflow.set_len(overflow_size);
+    }
+    let overflow_ptr = overflow.as_mut_ptr() as *mut T;
+    unsafe {
+        move_by_digit(
+            arr_ptr,
+            overflow_ptr,
+            &keys[..overflow_size],
+            &mut overflow_keys,
+            &mut overflow_heads,
+            mask,
+            0,
+        );
+    }
+
+    // Second pass: each bucket of the first pass is read from the buffer,
+    // then from the overflow elements, which came after in the array.
+    let (_, mut heads, _) = prefix_sums(&histograms[p.max_level - 2]);
+    for bucket in 0..p.radix_range {
+        let start = bucket * estimated_size;
+        let end = estimated_heads[bucket];
+        let overflow_start = overflow_starts[bucket];
+        let overflow_end = overflow_heads[bucket];
+        unsafe {
+            move_by_digit(
+                buffer_ptr.add(start),
+                arr_ptr,
+                &keys_buffer[start..end],
+                &mut keys,
+                &mut heads,
+                mask,
+                radix,
+            );
+            move_by_digit(
+                overflow_ptr.add(overflow_start),
+                arr_ptr,
+                &overflow_keys[overflow_start..overflow_end],
+                &mut keys,
+                &mut heads,
+                mask,
+                radix,
+            );
+        }
+    }
+
+    let mut source = arr_ptr;
+    let mut destination = buffer_ptr;
+    for level in (0..p.max_level - 2).rev() {
+        if only_one_bucket_filled(&histograms[level]) {
+            continue;
+        }
+
+        let shift = radix * (p.max_level - level - 1);
+        let (_, mut heads, _) = prefix_sums(&histograms[level]);
+
+        unsafe {
+            move_by_digit(
+                source,
+                destination,
+                &keys[..size],
+                &mut keys_buffer,
+                &mut heads,
+                mask,
+                shift,
+            );
+        }
+
+        core::mem::swap(&mut keys, &mut keys_buffer);
+        core::mem::swap(&mut source, &mut destination);
+    }
+
+    if source != arr_ptr {
+        unsafe {
+            ptr::copy_nonoverlapping(source, arr_ptr, size);
+        }
+    }
+}
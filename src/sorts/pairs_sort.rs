@@ -0,0 +1,269 @@
+use alloc::vec;
+use alloc::vec::Vec;
+
+use super::super::{RadixKey, Radixable};
+use super::msd_sort::copy_by_histogram_with;
+use super::ska_sort::ska_swap_with;
+use super::utils::{
+    copy_nonoverlapping, get_histogram, only_one_bucket_filled, prefix_sums,
+    Params,
+};
+
+// Structure of arrays sorts: the keys and the values are in two separate
+// slices of the same length. Histograms and buckets are computed on the keys
+// only, the values are moved along with their key.
+
+fn insertion_sort_pairs<T, K, V>(keys: &mut [T], values: &mut [V])
+where
+    T: Radixable<K>,
+    K: RadixKey,
+{
+    for i in 1..keys.len() {
+        let mut j = i;
+        while j > 0 && keys[j - 1].into_key_type() > keys[j].into_key_type() {
+            keys.swap(j - 1, j);
+            values.swap(j - 1, j);
+            j -= 1;
+        }
+    }
+}
+
+fn lsd_radixsort_pairs_body<T, K, V>(
+    keys: &mut [T],
+    values: &mut [V],
+    p: Params,
+) where
+    T: Radixable<K>,
+    K: RadixKey,
+    V: Copy,
+{
+    if keys.len() <= 128 {
+        insertion_sort_pairs(keys, values);
+        return;
+    }
+
+    let size = keys.len();
+    let dummy = keys[0];
+    let mut keys_buffer: Vec<T> = vec![keys[0]; size];
+    let mut values_buffer: Vec<V> = vec![values[0]; size];
+    let mut index = 0;
+
+    let histograms = dummy.get_full_histograms(keys, &p);
+
+    let k1 = keys;
+    let v1 = values;
+    let k2 = keys_buffer.as_mut_slice();
+    let v2 = values_buffer.as_mut_slice();
+
+    for level in (p.level..p.max_level).rev() {
+        if only_one_bucket_filled(&histograms[level]) {
+            continue;
+        }
+
+        let (mask, shift) = dummy.get_mask_and_shift(&p.new_level(level));
+        let (_, mut heads, _) = prefix_sums(&histograms[level]);
+
+        let digit = |key: &T| key.extract(mask, shift);
+        if index == 0 {
+            copy_by_histogram_with(size, k1, k2, &mut heads, digit, |i, j| {
+                v2[j] = v1[i]
+            });
+        } else {
+            copy_by_histogram_with(size, k2, k1, &mut heads, digit, |i, j| {
+                v1[j] = v2[i]
+            });
+        }
+
+        index = 1 - index;
+    }
+
+    if index == 1 {
+        copy_nonoverlapping(k2, k1, size);
+        copy_nonoverlapping(v2, v1, size);
+    }
+}
+
+/// # LSD stable sort for pairs
+///
+/// An implementation of the
+/// [LSD sort](https://en.wikipedia.org/wiki/Radix_sort)
+/// algorithm for a structure of arrays: `keys` is sorted, and `values` is
+/// permuted the same way.
+///
+/// Only the keys are read to compute the histograms and the destinations, so
+/// the payload does not need to be in the sorted struct.
+///
+/// This LSD sort is an out of place stable radix sort.
+///
+/// The two slices must have the same length, otherwise the sort panics.
+pub fn lsd_stable_radixsort_pairs<T, K, V>(
+    keys: &mut [T],
+    values: &mut [V],
+    radix: usize,
+) where
+    T: Radixable<K>,
+    K: RadixKey,
+    V: Copy,
+{
+    assert_eq!(keys.len(), values.len());
+
+    if keys.len() <= 128 {
+        insertion_sort_pairs(keys, values);
+        return;
+    }
+
+    let dummy = keys[0];
+    let (offset, _) = dummy.compute_offset(keys, radix);
+    let max_level = dummy.compute_max_level(offset, radix);
+
+    if max_level == 0 {
+        return;
+    }
+
+    let params = Params::new(0, radix, offset, max_level);
+
+    lsd_radixsort_pairs_body(keys, values, params);
+}
+
+fn fallback_pairs<T, K, V>(keys: &mut [T], values: &mut [V], p: Params)
+where
+    T: Radixable<K>,
+    K: RadixKey,
+    V: Copy,
+{
+    if keys.len() <= 128 {
+        insertion_sort_pairs(keys, values);
+        return;
+    }
+
+    let remaining_level = p.max_level - p.level;
+    let new_offset = ((p.offset / p.radix) * p.radix) + (p.level * p.radix);
+    let p = Params::new(0, p.radix, new_offset, remaining_level);
+    lsd_radixsort_pairs_body(keys, values, p);
+}
+
+fn rollercoaster_sort_pairs_rec<T, K, V>(
+    keys: &mut [T],
+    values: &mut [V],
+    p: Params,
+    first_pass: bool,
+) where
+    T: Radixable<K>,
+    K: RadixKey,
+    V: Copy,
+{
+    if !first_pass && keys.len() <= 128_000 {
+        fallback_pairs(keys, values, p);
+        return;
+    }
+
+    let dummy = keys[0];
+    let (mask, shift) = dummy.get_mask_and_shift_from_left(&p);
+    let histogram = get_histogram(keys, &p, mask, shift);
+    let (p_sums, mut heads, tails) = prefix_sums(&histogram);
+
+    ska_swap_with(
+        keys,
+        &mut heads,
+        &tails,
+        |key: &T| key.extract(mask, shift),
+        |i, j| values.swap(i, j),
+    );
+
+    let mut rest_keys = keys;
+    let mut rest_values = values;
+    if p.level < p.max_level - 1 {
+        for i in 0..(p.radix_range) {
+            let bucket_end = p_sums[i + 1] - p_sums[i];
+            let (first_keys, second_keys) =
+                rest_keys.split_at_mut(bucket_end);
+            let (first_values, second_values) =
+                rest_values.split_at_mut(bucket_end);
+            rest_keys = second_keys;
+            rest_values = second_values;
+            if histogram[i] > 1 {
+                let new_params = p.new_level(p.level + 1);
+                rollercoaster_sort_pairs_rec(
+                    first_keys,
+                    first_values,
+                    new_params,
+                    false,
+                );
+            }
+        }
+    }
+}
+
+/// # Rollercoaster sort for pairs
+///
+/// The Rollercoaster sort for a structure of arrays: `keys` is sorted, and
+/// `values` is permuted the same way. It starts as an in place MSD radix sort
+/// and switches to a LSD radix sort for the small buckets.
+///
+/// Only the keys are read to compute the histograms and the destinations, so
+/// the payload does not need to be in the sorted struct.
+///
+/// This Rollercoaster sort is an out of place unstable radix sort.
+///
+/// The two slices must have the same length, otherwise the sort panics.
+pub fn rollercoaster_sort_pairs<T, K, V>(
+    keys: &mut [T],
+    values: &mut [V],
+    radix: usize,
+) where
+    T: Radixable<K>,
+    K: RadixKey,
+    V: Copy,
+{
+    assert_eq!(keys.len(), values.len());
+
+    if keys.len() <= 128 {
+        insertion_sort_pairs(keys, values);
+        return;
+    }
+
+    let dummy = keys[0];
+    let (_, raw_offset) = dummy.compute_offset(keys, radix);
+    let max_level = dummy.compute_max_level(raw_offset, radix);
+
+    if max_level == 0 {
+        return;
+    }
+
+    let params = Params::new(0, radix, raw_offset, max_level);
+
+    rollercoaster_sort_pairs_rec(keys, values, params, true);
+}
+
+/// # Voracious sort for pairs
+///
+/// Sort `keys` and permute `values` the same way (structure of arrays).
+///
+/// The two slices must have the same length, otherwise the sort panics.
+///
+/// This sort is an unstable radix sort.
+pub fn voracious_sort_pairs<T, K, V>(keys: &mut [T], values: &mut [V])
+where
+    T: Radixable<K>,
+    K: RadixKey,
+    V: Copy,
+{
+    rollercoaster_sort_pairs(keys, values, 8);
+}
+
+/// # Voracious stable sort for pairs
+///
+/// Sort `keys` and permute `values` the same way (structure of arrays). The
+/// relative order of equal keys is kept.
+///
+/// The two slices must have the same length, otherwise the sort panics.
+///
+/// This sort is a stable radix sort.
+pub fn voracious_stable_sort_pairs<T, K, V>(keys: &mut [T], values: &mut [V])
+where
+    T: Radixable<K>,
+    K: RadixKey,
+    V: Copy,
+{
+    lsd_stable_radixsort_pairs(keys, values, 8);
+}
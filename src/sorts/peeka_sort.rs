@@ -1,5 +1,3 @@
-use rayon::{ThreadPool, ThreadPoolBuilder};
-
 use std::sync::mpsc::channel;
 
 use super::super::algo::k_way_merge::k_way_merge;
@@ -7,16 +5,18 @@ use super::super::algo::regions_graph::{swap_countries, RegionsGraph};
 use super::super::algo::verge_sort_heuristic::verge_sort_preprocessing;
 use super::super::{RadixKey, RadixSort, Radixable};
 use super::rollercoaster_sort::fallback;
-use super::ska_sort::ska_swap;
+use super::ska_sort::ska_swap_params;
 use super::utils::{get_histogram, prefix_sums, Params};
+use super::utils_mt::{compute_offset_reduced_mt, run_in_pool};
 
 const FALLBACK_THRESHOLD: usize = 128_000;
 
-fn local_sorting<T, K>(
+// Local Sorting Phase: each block of `block_size` elements is sorted on the
+// current digit, in parallel. Returns the histograms of the blocks.
+pub fn local_sorting<T, K>(
     arr: &mut [T],
     p: &Params,
     block_size: usize,
-    pool: &ThreadPool,
 ) -> Vec<Vec<usize>>
 where
     T: Radixable<K>,
@@ -24,13 +24,36 @@ where
 {
     let dummy = arr[0];
     let (mask, shift) = dummy.get_mask_and_shift_from_left(&p);
+
+    local_sorting_with(arr, block_size, |block| {
+        let h = get_histogram(block, p, mask, shift);
+        let (_, mut heads, tails) = prefix_sums(&h);
+
+        ska_swap_params(block, p, &mut heads, &tails, mask, shift);
+
+        h
+    })
+}
+
+// Same as `local_sorting`, each block is sorted by `sort_block`, which returns
+// its histogram.
+pub fn local_sorting_with<T, F>(
+    arr: &mut [T],
+    block_size: usize,
+    sort_block: F,
+) -> Vec<Vec<usize>>
+where
+    T: Send,
+    F: Fn(&mut [T]) -> Vec<usize> + Sync,
+{
+    let sort_block = &sort_block;
     let mut histograms: Vec<Vec<usize>> = Vec::new();
     let mut receivers = Vec::new();
 
-    pool.scope(|s| {
+    rayon::scope(|s| {
         let mut rest = arr;
         while !rest.is_empty() {
-            let (mut fst, snd) = if block_size < rest.len() {
+            let (fst, snd) = if block_size < rest.len() {
                 rest.split_at_mut(block_size)
             } else {
                 (rest, &mut [] as &mut [T])
@@ -40,12 +63,7 @@ where
             let (sender, receiver) = channel();
             receivers.push(receiver);
             s.spawn(move |_| {
-                let h = get_histogram(fst, p, mask, shift);
-                let (_, mut heads, tails) = prefix_sums(&h);
-
-                ska_swap(&mut fst, &mut heads, &tails, mask, shift);
-
-                sender.send(h).unwrap();
+                sender.send(sort_block(fst)).unwrap();
             });
         }
     });
@@ -60,7 +78,6 @@ where
 fn peeka_sort_rec<T: Radixable<K>, K: RadixKey>(
     arr: &mut [T],
     p: Params,
-    pool: &ThreadPool,
     previous_block_count: usize,
     init_size: usize,
 ) {
@@ -92,21 +109,60 @@ fn peeka_sort_rec<T: Radixable<K>, K: RadixKey>(
         let histogram = get_histogram(arr, &p, mask, shift);
         let (_, mut heads, tails) = prefix_sums(&histogram);
 
-        ska_swap(arr, &mut heads, &tails, mask, shift);
+        ska_swap_params(arr, &p, &mut heads, &tails, mask, shift);
 
         vec![histogram]
     } else {
-        local_sorting(arr, &p, block_size, pool)
+        local_sorting(arr, &p, block_size)
     };
 
-    // Graph Construction Phase
+    // Global Sorting Phase and early recursion
+    rayon::scope(|s| {
+        let mut smalls = Vec::new();
+        global_sorting(arr, &p, &histograms, |mut broker| {
+            if p.level < p.max_level - 1 {
+                if broker.len() > 3000 {
+                    s.spawn(move |_| {
+                        let new_params = p.new_level(p.level + 1);
+                        peeka_sort_rec(
+                            &mut broker,
+                            new_params,
+                            block_count,
+                            init_size,
+                        );
+                    });
+                } else {
+                    smalls.push(broker);
+                }
+            }
+        });
+
+        for mut small_array in smalls.into_iter() {
+            fallback(&mut small_array, p.new_level(p.level + 1));
+        }
+    });
+}
+
+// Graph Construction Phase and Global Sorting Phase: moves each element to
+// its bucket (country), from the histograms of the locally sorted blocks.
+// Each bucket is given to `on_country` as soon as all its elements are in
+// place. Returns the global histogram.
+pub fn global_sorting<'a, T, F>(
+    arr: &'a mut [T],
+    p: &Params,
+    histograms: &[Vec<usize>],
+    mut on_country: F,
+) -> Vec<usize>
+where
+    T: Copy,
+    F: FnMut(&'a mut [T]),
+{
     let mut regions_graph = RegionsGraph::new(p.radix_range);
-    let global_histogram = regions_graph.build_regions_graph(&histograms);
+    let global_histogram = regions_graph.build_regions_graph(histograms);
 
     // let sorted_countries = sort_countries(&global_histogram);
     let (p_sums, _, _) = prefix_sums(&global_histogram);
 
-    // Global Sorting Phase and early recursion
     let mut countries = Vec::new();
     let mut rest = arr;
     let mut country_map = vec![0; p.radix_range];
@@ -126,53 +182,33 @@ fn peeka_sort_rec<T: Radixable<K>, K: RadixKey>(
         country_map[country_id] = i;
     });
 
-    pool.scope(|s| {
-        let mut smalls = Vec::new();
-        for _ in 0..p.radix_range {
-            let (bro_id, mut broker, bro_offset) = countries
-                .pop()
-                .expect("[Regions sort -> swapping] Bad implementation.");
-
-            let swaps = regions_graph.two_cycle(bro_id);
-            swap_countries(
-                swaps,
-                &mut broker,
-                &mut countries,
-                &country_map,
-                bro_offset,
-            );
-
-            let swaps = regions_graph.two_path(bro_id);
-            swap_countries(
-                swaps,
-                &mut broker,
-                &mut countries,
-                &country_map,
-                bro_offset,
-            );
+    for _ in 0..p.radix_range {
+        let (bro_id, mut broker, bro_offset) = countries
+            .pop()
+            .expect("[Regions sort -> swapping] Bad implementation.");
 
-            if p.level < p.max_level - 1 {
-                if broker.len() > 3000 {
-                    s.spawn(move |_| {
-                        let new_params = p.new_level(p.level + 1);
-                        peeka_sort_rec(
-                            &mut broker,
-                            new_params,
-                            pool,
-                            block_count,
-                            init_size,
-                        );
-                    });
-                } else {
-                    smalls.push(broker);
-                }
-            }
-        }
+        let swaps = regions_graph.two_cycle(bro_id);
+        swap_countries(
+            swaps,
+            &mut broker,
+            &mut countries,
+            &country_map,
+            bro_offset,
+        );
 
-        for mut small_array in smalls.into_iter() {
-            fallback(&mut small_array, p.new_level(p.level + 1));
-        }
-    });
+        let swaps = regions_graph.two_path(bro_id);
+        swap_countries(
+            swaps,
+            &mut broker,
+            &mut countries,
+            &country_map,
+            bro_offset,
+        );
+
+        on_country(broker);
+    }
+
+    global_histogram
 }
 
 /// # Peek Regions sort (Peekasort)
@@ -185,6 +221,9 @@ fn peeka_sort_rec<T: Radixable<K>, K: RadixKey>(
 ///
 /// This sort is an inplace unstable radix sort.
 ///
+/// The sort runs on a new pool of `thread_n` threads, or on the current rayon
+/// pool if `thread_n` is 0.
+///
 /// For "small" arrays, this sort fallbacks on the single thread Voracious sort.
 /// In the trait implementation, there is a first fallback on the Rayon
 /// parallel quicksort.
@@ -203,19 +242,22 @@ pub fn peeka_sort<T, K>(
         return;
     }
 
-    let pool = ThreadPoolBuilder::new().num_threads(thread_n).build().unwrap();
+    run_in_pool(thread_n, || {
+        let dummy = arr[0];
+        let mut separators =
+            verge_sort_preprocessing(arr, radix, &|array, rdx| {
+                let (_offset, raw_offset, key_min) =
+                    compute_offset_reduced_mt(array, rdx);
+                let max_level = dummy.compute_max_level(raw_offset, rdx);
 
-    let dummy = arr[0];
-    let mut separators = verge_sort_preprocessing(arr, radix, &|array, rdx| {
-        let (_offset, raw_offset) = dummy.compute_offset_mt(array, rdx);
-        let max_level = dummy.compute_max_level(raw_offset, rdx);
+                if max_level > 0 {
+                    let params = Params::new(0, rdx, raw_offset, max_level)
+                        .with_key_min(key_min);
 
-        if max_level > 0 {
-            let params = Params::new(0, rdx, raw_offset, max_level);
+                    peeka_sort_rec(array, params, blocks_info, size);
+                }
+            });
 
-            peeka_sort_rec(array, params, &pool, blocks_info, size);
-        }
+        k_way_merge(arr, &mut separators);
     });
-
-    k_way_merge(arr, &mut separators);
 }
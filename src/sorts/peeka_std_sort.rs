@@ -0,0 +1,202 @@
+use std::sync::mpsc::channel;
+
+use super::super::algo::k_way_merge_std_mt::k_way_merge_std_mt;
+use super::super::algo::regions_graph::{swap_countries, RegionsGraph};
+use super::super::algo::verge_sort_heuristic::verge_sort_preprocessing;
+use super::super::{RadixKey, RadixSort, Radixable};
+use super::rollercoaster_sort::fallback;
+use super::ska_sort::ska_swap;
+use super::utils::{get_histogram, prefix_sums, Params};
+use super::work_queue::{part_mut, with_work_queue, Job, WorkQueue};
+
+const FALLBACK_THRESHOLD: usize = 128_000;
+
+fn local_sorting<'a, T, K>(
+    queue: &WorkQueue<'a>,
+    arr: &mut [T],
+    p: &Params,
+    block_size: usize,
+) -> Vec<Vec<usize>>
+where
+    T: Radixable<K> + 'a,
+    K: RadixKey + 'a,
+{
+    let dummy = arr[0];
+    let (mask, shift) = dummy.get_mask_and_shift_from_left(p);
+    let mut histograms: Vec<Vec<usize>> = Vec::new();
+    let mut receivers = Vec::new();
+    let mut jobs: Vec<Job<'a>> = Vec::new();
+
+    for fst in arr.chunks_mut(block_size) {
+        let (ptr, len) = (fst.as_mut_ptr() as usize, fst.len());
+        let p = *p;
+
+        let (sender, receiver) = channel();
+        receivers.push(receiver);
+        jobs.push(Box::new(move |_| {
+            let fst = unsafe { part_mut::<T>(ptr, len) };
+            let h = get_histogram(fst, &p, mask, shift);
+            let (_, mut heads, tails) = prefix_sums(&h);
+
+            ska_swap(fst, &mut heads, &tails, mask, shift);
+
+            sender.send(h).unwrap();
+        }));
+    }
+
+    queue.join_all(jobs);
+
+    for receiver in receivers.iter() {
+        histograms.push(receiver.recv().unwrap());
+    }
+
+    histograms
+}
+
+fn peeka_std_sort_rec<'a, T, K>(
+    queue: &WorkQueue<'a>,
+    arr: &mut [T],
+    p: Params,
+    block_size: usize,
+) where
+    T: Radixable<K> + 'a,
+    K: RadixKey + 'a,
+{
+    if arr.len() <= FALLBACK_THRESHOLD {
+        fallback(arr, p);
+        return;
+    }
+
+    let dummy = arr[0];
+
+    // Local Sorting Phase for each block
+    let histograms = if arr.len() <= block_size {
+        let (mask, shift) = dummy.get_mask_and_shift_from_left(&p);
+        let histogram = get_histogram(arr, &p, mask, shift);
+        let (_, mut heads, tails) = prefix_sums(&histogram);
+
+        ska_swap(arr, &mut heads, &tails, mask, shift);
+
+        vec![histogram]
+    } else {
+        local_sorting(queue, arr, &p, block_size)
+    };
+
+    // Graph Construction Phase
+    let mut regions_graph = RegionsGraph::new(p.radix_range);
+    let global_histogram = regions_graph.build_regions_graph(&histograms);
+
+    let (p_sums, _, _) = prefix_sums(&global_histogram);
+
+    // Global Sorting Phase and early recursion
+    let mut countries = Vec::new();
+    let mut rest = arr;
+    let mut country_map = vec![0; p.radix_range];
+
+    for country_id in 0..p.radix_range {
+        let end = p_sums[country_id + 1] - p_sums[country_id];
+        let (country, snd) = rest.split_at_mut(end);
+        countries.push((country_id, country, p_sums[country_id]));
+        rest = snd;
+    }
+    countries.sort_unstable_by(|(_, a, _), (_, b, _)| {
+        a.len()
+            .partial_cmp(&b.len())
+            .expect("[Regions sort -> countries sorting] Bad implementation.")
+    });
+    countries.iter().enumerate().for_each(|(i, &(country_id, _, _))| {
+        country_map[country_id] = i;
+    });
+
+    let mut smalls = Vec::new();
+    for _ in 0..p.radix_range {
+        let (bro_id, broker, bro_offset) = countries
+            .pop()
+            .expect("[Regions sort -> swapping] Bad implementation.");
+
+        let swaps = regions_graph.two_cycle(bro_id);
+        swap_countries(
+            swaps,
+            broker,
+            &mut countries,
+            &country_map,
+            bro_offset,
+        );
+
+        let swaps = regions_graph.two_path(bro_id);
+        swap_countries(
+            swaps,
+            broker,
+            &mut countries,
+            &country_map,
+            bro_offset,
+        );
+
+        if p.level < p.max_level - 1 {
+            if broker.len() > 3000 {
+                let (ptr, len) = (broker.as_mut_ptr() as usize, broker.len());
+                queue.push(move |queue| {
+                    let broker = unsafe { part_mut::<T>(ptr, len) };
+                    let new_params = p.new_level(p.level + 1);
+                    peeka_std_sort_rec(queue, broker, new_params, block_size);
+                });
+            } else {
+                smalls.push(broker);
+            }
+        }
+    }
+
+    for small_array in smalls.into_iter() {
+        fallback(small_array, p.new_level(p.level + 1));
+    }
+}
+
+/// # Peek Regions sort (Peekasort) without rayon
+///
+/// Same algorithm as the `peeka_sort`, but the threads are
+/// `std::thread::scope` threads sharing a small work queue, so rayon is not
+/// needed. It is available with the "`voracious_multithread_std`" feature.
+///
+/// This sort is an inplace unstable radix sort.
+///
+/// The sort runs on `thread_n` threads, or on as many threads as the
+/// available parallelism if `thread_n` is 0. The arrays are cut in blocks of
+/// `block_size` elements for the local sorting phase.
+///
+/// For "small" arrays, this sort fallbacks on the single thread Voracious sort.
+pub fn peeka_std_sort<T, K>(
+    arr: &mut [T],
+    radix: usize,
+    block_size: usize,
+    thread_n: usize,
+) where
+    T: Radixable<K>,
+    K: RadixKey,
+{
+    let size = arr.len();
+    if size <= FALLBACK_THRESHOLD {
+        arr.voracious_sort();
+        return;
+    }
+
+    let block_size = block_size.max(1);
+    let dummy = arr[0];
+
+    // One scope for the whole sort: the same threads sort the parts found by
+    // the Verge sort pre-processing, then merge them.
+    with_work_queue(thread_n, |queue| {
+        let mut separators =
+            verge_sort_preprocessing(arr, radix, &|array, rdx| {
+                let (_offset, raw_offset) = dummy.compute_offset(array, rdx);
+                let max_level = dummy.compute_max_level(raw_offset, rdx);
+
+                if max_level > 0 {
+                    let params = Params::new(0, rdx, raw_offset, max_level);
+                    peeka_std_sort_rec(queue, array, params, block_size);
+                    queue.wait_all();
+                }
+            });
+
+        k_way_merge_std_mt(queue, arr, &mut separators);
+    });
+}
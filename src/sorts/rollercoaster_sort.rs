@@ -3,6 +3,7 @@ use super::super::algo::verge_sort_heuristic::{
     explore_simple_forward, verge_sort_preprocessing, Orientation,
 };
 use super::super::{RadixKey, RadixSort, Radixable};
+use super::comparative_sort::key_cmp;
 use super::counting_sort::counting_sort;
 use super::dlsd_sort::dlsd_radixsort_body;
 use super::lsd_sort::lsd_radixsort_body;
@@ -15,14 +16,16 @@ pub fn fallback<T: Radixable<K>, K: RadixKey>(arr: &mut [T], p: Params) {
     assert!(size <= 128_000);
 
     if size <= 256 {
-        arr.sort_unstable_by(|a, b| a.partial_cmp(b).unwrap());
+        arr.sort_unstable_by(key_cmp);
         return;
     }
 
     let remaining_level = p.max_level - p.level;
-    if remaining_level < 4 {
+    // The diversion of the DLSD sort does not handle the reduced keys.
+    if remaining_level < 4 || p.key_min.is_some() {
         let new_offset = ((p.offset / p.radix) * p.radix) + (p.level * p.radix);
-        let p = Params::new(0, p.radix, new_offset, remaining_level);
+        let p = Params::new(0, p.radix, new_offset, remaining_level)
+            .with_key_min(p.key_min);
         lsd_radixsort_body(arr, p);
     } else {
         let new_offset = p.level * p.radix + p.offset;
@@ -124,7 +127,7 @@ fn rollercoaster_sort_aux<T: Radixable<K>, K: RadixKey>(
 ) {
     let size = arr.len();
     if size <= 128 {
-        arr.sort_unstable_by(|a, b| a.partial_cmp(b).unwrap());
+        arr.sort_unstable_by(key_cmp);
         return;
     }
 
@@ -171,7 +174,7 @@ where
     K: RadixKey,
 {
     if arr.len() <= 128 {
-        arr.sort_unstable_by(|a, b| a.partial_cmp(b).unwrap());
+        arr.sort_unstable_by(key_cmp);
         return;
     }
 
@@ -187,7 +190,7 @@ where
     K: RadixKey,
 {
     if arr.len() <= 128 {
-        arr.sort_unstable_by(|a, b| a.partial_cmp(b).unwrap());
+        arr.sort_unstable_by(key_cmp);
         return;
     }
 
@@ -0,0 +1,121 @@
+use core::cmp::{max, min};
+use core::mem::size_of;
+
+use super::super::{RadixKey, Radixable};
+
+// Upper bounds of the scratch memory (in bytes) of the sorts, for the array
+// `arr`. The memory limited sort uses them to know whether the sort chosen by
+// the dispatcher fits in the limit.
+//
+// The bounds are worst cases: they count every buffer a sort may keep alive
+// at the same time, the buffers of its fallbacks included.
+
+fn bits<T: Radixable<K>, K: RadixKey>(arr: &[T]) -> usize {
+    arr.first().map_or(0, |item| item.type_size())
+}
+
+fn elements_bytes<T>(n: usize) -> usize { n.saturating_mul(size_of::<T>()) }
+
+fn levels(bits: usize, radix: usize) -> usize { (bits + radix - 1) / radix }
+
+fn words_bytes(n: usize) -> usize { n.saturating_mul(size_of::<usize>()) }
+
+// The histograms of all the levels, plus the heads of one level.
+fn histograms_bytes(levels: usize, radix: usize) -> usize {
+    words_bytes((levels + 1) << radix)
+}
+
+// Once the sort is done, the Verge sort pre-processing merges the sorted runs
+// with a buffer of half the array (`k_way_merge`).
+fn merge_bytes<T>(len: usize) -> usize { elements_bytes::<T>(len / 2 + 2) }
+
+// An in place MSD pass keeps its histogram, prefix sums, heads and tails
+// during the recursion, and the Ska swap sorts the buckets by size.
+fn in_place_bytes(bits: usize, radix: usize) -> usize {
+    words_bytes(levels(bits, radix) * (8 << radix))
+}
+
+// Each level of the out of place MSD recursion keeps a copy of its bucket.
+fn msd_rec_bytes<T>(len: usize, bits: usize, radix: usize) -> usize {
+    elements_bytes::<T>(len.saturating_mul(levels(bits, radix)))
+        + in_place_bytes(bits, radix)
+}
+
+// The DLSD radix goes up to 9 bits, and the diversion sorts the unsorted
+// parts while the DLSD buffer is still alive.
+fn dlsd_body_bytes<T>(len: usize, bits: usize) -> usize {
+    elements_bytes::<T>(len.saturating_mul(2))
+        + histograms_bytes(levels(bits, 7), 9)
+        + msd_rec_bytes::<T>(0, bits, 8)
+}
+
+/// Scratch memory of the Ska sort and of the American flag sort: only the
+/// histograms of the recursion.
+pub fn in_place_scratch<T, K>(arr: &[T], radix: usize) -> usize
+where
+    T: Radixable<K>,
+    K: RadixKey,
+{
+    in_place_bytes(bits(arr), radix)
+}
+
+/// Scratch memory of `lsd_radixsort`.
+pub fn lsd_scratch<T, K>(arr: &[T], radix: usize) -> usize
+where
+    T: Radixable<K>,
+    K: RadixKey,
+{
+    let levels = levels(bits(arr), radix);
+    let sort = elements_bytes::<T>(arr.len()) + histograms_bytes(levels, radix);
+    max(merge_bytes::<T>(arr.len()), sort)
+}
+
+/// Scratch memory of `dlsd_radixsort`.
+pub fn dlsd_scratch<T, K>(arr: &[T]) -> usize
+where
+    T: Radixable<K>,
+    K: RadixKey,
+{
+    let sort = dlsd_body_bytes::<T>(arr.len(), bits(arr));
+    max(merge_bytes::<T>(arr.len()), sort)
+}
+
+/// Scratch memory of `msd_radixsort`.
+pub fn msd_scratch<T, K>(arr: &[T], radix: usize) -> usize
+where
+    T: Radixable<K>,
+    K: RadixKey,
+{
+    let sort = msd_rec_bytes::<T>(arr.len(), bits(arr), radix);
+    max(merge_bytes::<T>(arr.len()), sort)
+}
+
+/// Scratch memory of `voracious_sort`: the in place passes fall back on the
+/// out of place MSD sort below 30_000 elements.
+pub fn voracious_scratch<T, K>(arr: &[T], radix: usize) -> usize
+where
+    T: Radixable<K>,
+    K: RadixKey,
+{
+    let bits = bits(arr);
+    let fallback = msd_rec_bytes::<T>(min(arr.len(), 30_000), bits, radix);
+    let sort = in_place_bytes(bits, radix) + fallback;
+    max(merge_bytes::<T>(arr.len()), sort)
+}
+
+/// Scratch memory of `rollercoaster_sort`: the in place passes fall back on
+/// the LSD or DLSD sort below 128_000 elements. The non negative signed
+/// integers are sorted at once by the unsigned sort.
+pub fn rollercoaster_scratch<T, K>(arr: &[T], radix: usize) -> usize
+where
+    T: Radixable<K>,
+    K: RadixKey,
+{
+    let bits = bits(arr);
+    let signed = arr
+        .first()
+        .map_or(false, |item| item.is_i32() || item.is_i64() || item.is_i128());
+    let chunk = if signed { arr.len() } else { min(arr.len(), 128_000) };
+    let sort = in_place_bytes(bits, radix) + dlsd_body_bytes::<T>(chunk, bits);
+    max(merge_bytes::<T>(arr.len()), sort)
+}
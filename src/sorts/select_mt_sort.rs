@@ -0,0 +1,146 @@
+use super::super::{RadixKey, RadixSort, Radixable};
+use super::peeka_sort::{global_sorting, local_sorting};
+use super::select_sort::select_nth_rec;
+use super::utils::{prefix_sums, Params};
+use super::utils_mt::{compute_offset_reduced_mt, run_in_pool};
+
+const FALLBACK_THRESHOLD: usize = 128_000;
+
+fn select_nth_mt_rec<T: Radixable<K>, K: RadixKey>(
+    arr: &mut [T],
+    p: Params,
+    k: usize,
+    block_size: usize,
+) {
+    if arr.len() <= block_size {
+        select_nth_rec(arr, p, k);
+        return;
+    }
+
+    // Local Sorting Phase for each block, then Global Sorting Phase, as in the
+    // Peeka sort.
+    let histograms = local_sorting(arr, &p, block_size);
+    let global_histogram = global_sorting(arr, &p, &histograms, |_| {});
+    let (p_sums, _, _) = prefix_sums(&global_histogram);
+
+    // Only the bucket which contains the rank k is sorted further.
+    if p.level < p.max_level - 1 {
+        let bucket = (0..p.radix_range)
+            .find(|&i| p_sums[i] <= k && k < p_sums[i + 1])
+            .unwrap();
+        if global_histogram[bucket] > 1 {
+            select_nth_mt_rec(
+                &mut arr[p_sums[bucket]..p_sums[bucket + 1]],
+                p.new_level(p.level + 1),
+                k - p_sums[bucket],
+                block_size,
+            );
+        }
+    }
+}
+
+/// # Voracious multithread select nth unstable
+///
+/// Same as `voracious_select_nth_unstable`, but the elements are partitioned
+/// with the block histograms of the Peeka sort, on `thread_n` threads (or on
+/// the current rayon pool if `thread_n` is 0).
+///
+/// # Panics
+///
+/// Panics when `k >= arr.len()`.
+pub fn voracious_mt_select_nth_unstable<T, K>(
+    arr: &mut [T],
+    k: usize,
+    thread_n: usize,
+) -> (&mut [T], &mut T, &mut [T])
+where
+    T: Radixable<K>,
+    K: RadixKey,
+{
+    assert!(
+        k < arr.len(),
+        "partition at index {} is out of bounds for length {}",
+        k,
+        arr.len()
+    );
+
+    run_in_pool(thread_n, || {
+        let radix = 8;
+        let dummy = arr[0];
+        let (_, raw_offset, key_min) = compute_offset_reduced_mt(arr, radix);
+        let max_level = dummy.compute_max_level(raw_offset, radix);
+
+        if max_level > 0 {
+            let block_size = (arr.len() / rayon::current_num_threads())
+                .max(FALLBACK_THRESHOLD);
+            let params = Params::new(0, radix, raw_offset, max_level)
+                .with_key_min(key_min);
+            select_nth_mt_rec(arr, params, k, block_size);
+        }
+    });
+
+    let (left, rest) = arr.split_at_mut(k);
+    let (nth, right) = rest.split_first_mut().unwrap();
+    (left, nth, right)
+}
+
+/// # Voracious multithread partial sort
+///
+/// Same as `voracious_partial_sort`, with
+/// `voracious_mt_select_nth_unstable` and `voracious_mt_sort`.
+///
+/// # Panics
+///
+/// Panics when `k > arr.len()`.
+pub fn voracious_mt_partial_sort<T, K>(
+    arr: &mut [T],
+    k: usize,
+    thread_n: usize,
+) where
+    T: Radixable<K>,
+    K: RadixKey,
+{
+    assert!(k <= arr.len(), "k ({}) is greater than the length", k);
+
+    if k == 0 {
+        return;
+    }
+    if k == arr.len() {
+        arr.voracious_mt_sort(thread_n);
+        return;
+    }
+
+    voracious_mt_select_nth_unstable(arr, k - 1, thread_n);
+    arr[..k - 1].voracious_mt_sort(thread_n);
+}
+
+/// # Voracious multithread top k
+///
+/// Same as `voracious_top_k`, with `voracious_mt_select_nth_unstable` and
+/// `voracious_mt_sort_desc`.
+///
+/// # Panics
+///
+/// Panics when `k > arr.len()`.
+pub fn voracious_mt_top_k<T, K>(
+    arr: &mut [T],
+    k: usize,
+    thread_n: usize,
+) -> &mut [T]
+where
+    T: Radixable<K>,
+    K: RadixKey,
+{
+    assert!(k <= arr.len(), "k ({}) is greater than the length", k);
+
+    let size = arr.len();
+    if k == 0 {
+        return &mut arr[size..];
+    }
+    if k < size {
+        voracious_mt_select_nth_unstable(arr, size - k, thread_n);
+    }
+    let top = &mut arr[size - k..];
+    top.voracious_mt_sort_desc(thread_n);
+    top
+}
@@ -0,0 +1,147 @@
+use super::super::{RadixKey, RadixSort, Radixable};
+use super::comparative_sort::key_cmp;
+use super::key_range::compute_offset_reduced;
+use super::ska_sort::ska_swap_params;
+use super::utils::{get_histogram, prefix_sums, Params};
+
+const SELECT_THRESHOLD: usize = 128;
+
+pub fn select_nth_rec<T: Radixable<K>, K: RadixKey>(
+    arr: &mut [T],
+    p: Params,
+    k: usize,
+) {
+    if arr.len() <= SELECT_THRESHOLD {
+        arr.select_nth_unstable_by(k, key_cmp);
+        return;
+    }
+
+    let dummy = arr[0];
+    let (mask, shift) = dummy.get_mask_and_shift_from_left(&p);
+    let histogram = get_histogram(arr, &p, mask, shift);
+    let (p_sums, mut heads, tails) = prefix_sums(&histogram);
+
+    ska_swap_params(arr, &p, &mut heads, &tails, mask, shift);
+
+    // Only the bucket which contains the rank k is sorted further.
+    if p.level < p.max_level - 1 {
+        let bucket = (0..p.radix_range)
+            .find(|&i| p_sums[i] <= k && k < p_sums[i + 1])
+            .unwrap();
+        if histogram[bucket] > 1 {
+            select_nth_rec(
+                &mut arr[p_sums[bucket]..p_sums[bucket + 1]],
+                p.new_level(p.level + 1),
+                k - p_sums[bucket],
+            );
+        }
+    }
+}
+
+/// # Voracious select nth unstable
+///
+/// Reorders the slice such that the element at `k` is at its final sorted
+/// position, like `slice::select_nth_unstable`. The elements before `k` are
+/// lower than or equal to it, and the elements after `k` are greater than or
+/// equal to it.
+///
+/// It is a MSD radix sort which only recurses into the bucket that contains
+/// the rank `k`, so it runs in linear time.
+///
+/// Returns the elements before `k`, the element at `k` and the elements after
+/// `k`.
+///
+/// # Panics
+///
+/// Panics when `k >= arr.len()`.
+pub fn voracious_select_nth_unstable<T, K>(
+    arr: &mut [T],
+    k: usize,
+) -> (&mut [T], &mut T, &mut [T])
+where
+    T: Radixable<K>,
+    K: RadixKey,
+{
+    assert!(
+        k < arr.len(),
+        "partition at index {} is out of bounds for length {}",
+        k,
+        arr.len()
+    );
+
+    if arr.len() <= SELECT_THRESHOLD {
+        arr.select_nth_unstable_by(k, key_cmp);
+    } else {
+        let radix = 8;
+        let dummy = arr[0];
+        let (_, raw_offset, key_min) = compute_offset_reduced(arr, radix);
+        let max_level = dummy.compute_max_level(raw_offset, radix);
+
+        if max_level > 0 {
+            let params = Params::new(0, radix, raw_offset, max_level)
+                .with_key_min(key_min);
+            select_nth_rec(arr, params, k);
+        }
+    }
+
+    let (left, rest) = arr.split_at_mut(k);
+    let (nth, right) = rest.split_first_mut().unwrap();
+    (left, nth, right)
+}
+
+/// # Voracious partial sort
+///
+/// Sorts the `k` smallest elements of the slice into `arr[..k]`. The order of
+/// the other elements is unspecified.
+///
+/// The `k` smallest elements are selected with
+/// `voracious_select_nth_unstable`, and then sorted with `voracious_sort`.
+///
+/// # Panics
+///
+/// Panics when `k > arr.len()`.
+pub fn voracious_partial_sort<T, K>(arr: &mut [T], k: usize)
+where
+    T: Radixable<K>,
+    K: RadixKey,
+{
+    assert!(k <= arr.len(), "k ({}) is greater than the length", k);
+
+    if k == 0 {
+        return;
+    }
+    if k == arr.len() {
+        arr.voracious_sort();
+        return;
+    }
+
+    voracious_select_nth_unstable(arr, k - 1);
+    arr[..k - 1].voracious_sort();
+}
+
+/// # Voracious top k
+///
+/// Moves the `k` largest elements of the slice to its end and returns them, in
+/// descending order. The order of the other elements is unspecified.
+///
+/// # Panics
+///
+/// Panics when `k > arr.len()`.
+pub fn voracious_top_k<T, K>(arr: &mut [T], k: usize) -> &mut [T]
+where
+    T: Radixable<K>,
+    K: RadixKey,
+{
+    assert!(k <= arr.len(), "k ({}) is greater than the length", k);
+
+    let size = arr.len();
+    if k == 0 {
+        return &mut arr[size..];
+    }
+    if k < size {
+        voracious_select_nth_unstable(arr, size - k);
+    }
+    let top = &mut arr[size - k..];
+    top.voracious_sort_desc();
+    top
+}
@@ -1,6 +1,9 @@
+use alloc::vec::Vec;
+
 use super::super::{RadixKey, Radixable};
 use super::american_flag_sort::serial_radixsort_rec;
 use super::comparative_sort::insertion_sort;
+use super::key_range::reduced_digit;
 use super::utils::{get_histogram, prefix_sums, Params};
 
 const UNROLL_SIZE: usize = 4;
@@ -12,6 +15,34 @@ pub fn ska_swap<T: Radixable<K>, K: RadixKey>(
     mask: <<T as Radixable<K>>::Key as RadixKey>::Key,
     shift: usize,
 ) {
+    ska_swap_by(arr, heads, tails, |item: &T| item.extract(mask, shift));
+}
+
+// Same as `ska_swap`, the bucket of an element is given by `digit`.
+pub fn ska_swap_by<T, F>(
+    arr: &mut [T],
+    heads: &mut Vec<usize>,
+    tails: &[usize],
+    digit: F,
+) where
+    F: Fn(&T) -> usize,
+{
+    ska_swap_with(arr, heads, tails, digit, |_, _| {});
+}
+
+// Same as `ska_swap_by`, and `swapped(i, j)` is called once the elements `i`
+// and `j` are swapped, so the data kept out of the elements (the values of
+// the pairs sorts) can follow.
+pub fn ska_swap_with<T, F, S>(
+    arr: &mut [T],
+    heads: &mut Vec<usize>,
+    tails: &[usize],
+    digit: F,
+    mut swapped: S,
+) where
+    F: Fn(&T) -> usize,
+    S: FnMut(usize, usize),
+{
     let mut buckets_size = Vec::new();
     for i in 0..heads.len() {
         buckets_size.push((i, tails[i] - heads[i]))
@@ -33,10 +64,10 @@ pub fn ska_swap<T: Radixable<K>, K: RadixKey>(
                     let o = offset + q * UNROLL_SIZE;
 
                     unsafe {
-                        let tb0 = arr.get_unchecked(o).extract(mask, shift);
-                        let tb1 = arr.get_unchecked(o + 1).extract(mask, shift);
-                        let tb2 = arr.get_unchecked(o + 2).extract(mask, shift);
-                        let tb3 = arr.get_unchecked(o + 3).extract(mask, shift);
+                        let tb0 = digit(arr.get_unchecked(o));
+                        let tb1 = digit(arr.get_unchecked(o + 1));
+                        let tb2 = digit(arr.get_unchecked(o + 2));
+                        let tb3 = digit(arr.get_unchecked(o + 3));
 
                         let dest_index_0 = *heads.get_unchecked(tb0);
                         heads[tb0] += 1;
@@ -48,9 +79,13 @@ pub fn ska_swap<T: Radixable<K>, K: RadixKey>(
                         heads[tb3] += 1;
 
                         arr.swap(o, dest_index_0);
+                        swapped(o, dest_index_0);
                         arr.swap(o + 1, dest_index_1);
+                        swapped(o + 1, dest_index_1);
                         arr.swap(o + 2, dest_index_2);
+                        swapped(o + 2, dest_index_2);
                         arr.swap(o + 3, dest_index_3);
+                        swapped(o + 3, dest_index_3);
                     }
                 }
 
@@ -58,8 +93,9 @@ pub fn ska_swap<T: Radixable<K>, K: RadixKey>(
 
                 for i in 0..remainder {
                     unsafe {
-                        let b = arr.get_unchecked(n_o + i).extract(mask, shift);
+                        let b = digit(arr.get_unchecked(n_o + i));
                         arr.swap(n_o + i, heads[b]);
+                        swapped(n_o + i, heads[b]);
                         heads[b] += 1;
                     }
                 }
@@ -74,6 +110,26 @@ pub fn ska_swap<T: Radixable<K>, K: RadixKey>(
     }
 }
 
+// `ska_swap` on the reduced keys when `p.key_min` is set (see `key_range`).
+pub fn ska_swap_params<T: Radixable<K>, K: RadixKey>(
+    arr: &mut [T],
+    p: &Params,
+    heads: &mut Vec<usize>,
+    tails: &[usize],
+    mask: <<T as Radixable<K>>::Key as RadixKey>::Key,
+    shift: usize,
+) {
+    match p.key_min {
+        Some(min) => {
+            let mask = mask.into();
+            ska_swap_by(arr, heads, tails, |item| {
+                reduced_digit(item, min, mask, shift)
+            });
+        },
+        None => ska_swap(arr, heads, tails, mask, shift),
+    }
+}
+
 pub fn ska_sort_rec<T: Radixable<K>, K: RadixKey>(arr: &mut [T], p: Params) {
     if arr.len() <= 64 {
         insertion_sort(arr);
@@ -0,0 +1,212 @@
+use alloc::collections::TryReserveError;
+use alloc::vec;
+use alloc::vec::Vec;
+
+use super::super::{RadixKey, Radixable};
+use super::comparative_sort::insertion_sort;
+use super::lsd_sort::lsd_radixsort_body_with_buffer;
+use super::utils::{fill_full_histograms, Params};
+use super::utils_simd::simd_copies_len;
+
+// The LSD sort is the only radix sort whose memory need is known before the
+// sort starts (one buffer as long as the array, plus the histograms), so it is
+// the one used when the memory is provided by the caller. The core algorithm
+// is stable, and the fallback is a stable insertion sort (`sort_by` would
+// allocate a buffer).
+
+// Returns `None` when there is nothing to sort.
+fn compute_params<T, K>(arr: &mut [T]) -> Option<Params>
+where
+    T: Radixable<K>,
+    K: RadixKey,
+{
+    let dummy = arr[0];
+    let (offset, _) = dummy.compute_offset(arr, 8);
+    let max_level = dummy.compute_max_level(offset, 8);
+
+    if max_level == 0 {
+        None
+    } else {
+        Some(Params::new(0, 8, offset, max_level))
+    }
+}
+
+/// # Voracious sort with a caller-provided buffer
+///
+/// Sort `arr` using `buffer` as the scratch memory of the LSD radix sort. The
+/// buffer content is overwritten, and its length must be at least the length
+/// of `arr`, otherwise the sort panics.
+///
+/// Only the histograms (a few KB) are allocated. Use a `SortContext` to
+/// reuse them too.
+///
+/// This sort is a stable radix sort.
+pub fn voracious_sort_with_buffer<T, K>(arr: &mut [T], buffer: &mut [T])
+where
+    T: Radixable<K>,
+    K: RadixKey,
+{
+    assert!(buffer.len() >= arr.len());
+
+    if arr.len() <= 128 {
+        insertion_sort(arr);
+        return;
+    }
+
+    if let Some(p) = compute_params(arr) {
+        let dummy = arr[0];
+        let histograms = dummy.get_full_histograms(arr, &p);
+        let mut heads = vec![0; p.radix_range];
+
+        lsd_radixsort_body_with_buffer(
+            arr,
+            buffer,
+            &histograms,
+            &mut heads,
+            p,
+        );
+    }
+}
+
+/// # Sort context
+///
+/// A reusable context which keeps the scratch buffer and the histograms of
+/// the LSD radix sort between two sorts.
+///
+/// The memory grows with the largest array sorted so far and is never
+/// shrunk, so once the context is warm (or created with `with_capacity`),
+/// sorting does not allocate at all.
+///
+/// ```
+/// use voracious_radix_sort::SortContext;
+///
+/// let mut context = SortContext::with_capacity(1000);
+///
+/// for batch in 0..10 {
+///     let mut arr: Vec<u64> =
+///         (0..1000).map(|i| (i * 7919 + batch) % 1000).collect();
+///     context.voracious_sort(&mut arr);
+///     assert!(arr.windows(2).all(|w| w[0] <= w[1]));
+/// }
+/// ```
+pub struct SortContext<T> {
+    buffer: Vec<T>,
+    histograms: Vec<Vec<usize>>,
+    heads: Vec<usize>,
+    copies: Vec<usize>,
+}
+
+impl<T> Default for SortContext<T> {
+    fn default() -> Self { SortContext::new() }
+}
+
+impl<T> SortContext<T> {
+    /// Create an empty context. The memory is allocated by the first sorts.
+    pub fn new() -> Self {
+        SortContext {
+            buffer: Vec::new(),
+            histograms: Vec::new(),
+            heads: Vec::new(),
+            copies: Vec::new(),
+        }
+    }
+
+    /// Create a context which can sort arrays of up to `capacity` elements of
+    /// any `Radixable` type without allocating.
+    pub fn with_capacity(capacity: usize) -> Self {
+        // A `u128` key needs at most 16 levels of 8 bits. The SIMD histograms
+        // only handle keys of at most 64 bits.
+        SortContext {
+            buffer: Vec::with_capacity(capacity),
+            histograms: vec![vec![0; 256]; 16],
+            heads: vec![0; 256],
+            copies: Vec::with_capacity(simd_copies_len(8, 8)),
+        }
+    }
+
+    /// Same as `with_capacity`, but the memory is allocated with
+    /// `try_reserve`: an allocation failure is returned instead of aborting.
+    pub fn try_with_capacity(capacity: usize) -> Result<Self, TryReserveError> {
+        let mut context = SortContext::new();
+        context.buffer.try_reserve_exact(capacity)?;
+        context.histograms.try_reserve_exact(16)?;
+        for _ in 0..16 {
+            context.histograms.push(try_zeroed(256)?);
+        }
+        context.heads = try_zeroed(256)?;
+        context.copies.try_reserve_exact(simd_copies_len(8, 8))?;
+
+        Ok(context)
+    }
+}
+
+fn try_zeroed(size: usize) -> Result<Vec<usize>, TryReserveError> {
+    let mut counts = Vec::new();
+    counts.try_reserve_exact(size)?;
+    counts.resize(size, 0);
+    Ok(counts)
+}
+
+impl<T: Copy> SortContext<T> {
+    fn prepare(&mut self, arr: &[T], p: &Params) {
+        if self.buffer.len() < arr.len() {
+            self.buffer.resize(arr.len(), arr[0]);
+        }
+        if self.heads.len() < p.radix_range {
+            self.heads.resize(p.radix_range, 0);
+        }
+        if self.histograms.len() < p.max_level {
+            self.histograms.resize(p.max_level, Vec::new());
+        }
+        for histogram in self.histograms.iter_mut().take(p.max_level) {
+            if histogram.len() < p.radix_range {
+                histogram.resize(p.radix_range, 0);
+            }
+            histogram.iter_mut().for_each(|count| *count = 0);
+        }
+    }
+
+    /// Sort `arr` with the memory of the context.
+    ///
+    /// This sort is a stable radix sort.
+    pub fn voracious_sort<K>(&mut self, arr: &mut [T])
+    where
+        T: Radixable<K>,
+        K: RadixKey,
+    {
+        if arr.len() <= 128 {
+            insertion_sort(arr);
+            return;
+        }
+
+        if let Some(p) = compute_params(arr) {
+            self.prepare(arr, &p);
+            fill_full_histograms(
+                arr,
+                &p,
+                &mut self.histograms,
+                &mut self.copies,
+            );
+
+            lsd_radixsort_body_with_buffer(
+                arr,
+                &mut self.buffer,
+                &self.histograms,
+                &mut self.heads,
+                p,
+            );
+        }
+    }
+
+    /// Sort `arr` with the memory of the context. The relative order of equal
+    /// elements is kept.
+    ///
+    /// This sort is a stable radix sort.
+    pub fn voracious_stable_sort<K>(&mut self, arr: &mut [T])
+    where
+        T: Radixable<K>,
+        K: RadixKey,
+    {
+        self.voracious_sort(arr);
+    }
+}
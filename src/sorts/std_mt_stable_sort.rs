@@ -0,0 +1,45 @@
+use super::super::algo::k_way_merge_std_mt::k_way_merge_std_mt;
+use super::super::{RadixKey, Radixable};
+use super::work_queue::{part_mut, thread_count, with_work_queue, Job};
+
+/// # Multithread stable sort without rayon
+///
+/// The array is cut in one chunk per thread, the chunks are sorted at the
+/// same time with the single thread stable sort, and then they are merged in
+/// parallel. The threads are `std::thread::scope` threads sharing a small
+/// work queue, so rayon is not needed.
+///
+/// This sort is a stable sort.
+///
+/// The sort runs on `thread_n` threads, or on as many threads as the
+/// available parallelism if `thread_n` is 0.
+pub fn std_mt_stable_sort<T, K>(arr: &mut [T], thread_n: usize)
+where
+    T: Radixable<K>,
+    K: RadixKey,
+{
+    if arr.is_empty() {
+        return;
+    }
+
+    let threads = thread_count(thread_n);
+    let chunk_size = (arr.len() + threads - 1) / threads;
+
+    with_work_queue(thread_n, |queue| {
+        let mut separators = vec![0];
+        let mut jobs: Vec<Job> = Vec::new();
+        for chunk in arr.chunks_mut(chunk_size) {
+            let (ptr, len) = (chunk.as_mut_ptr() as usize, chunk.len());
+            separators.push(separators[separators.len() - 1] + len);
+
+            jobs.push(Box::new(move |_| {
+                let chunk = unsafe { part_mut::<T>(ptr, len) };
+                let dummy = chunk[0];
+                dummy.voracious_stable_sort(chunk);
+            }));
+        }
+        queue.join_all(jobs);
+
+        k_way_merge_std_mt(queue, arr, &mut separators);
+    });
+}
@@ -0,0 +1,250 @@
+use alloc::vec;
+use alloc::vec::Vec;
+use core::mem::MaybeUninit;
+use core::ptr;
+
+#[cfg(feature = "voracious_multithread")]
+use super::utils_mt::run_in_pool;
+
+use super::utils::prefix_sums;
+
+// One bucket per byte value, plus the bucket 0 for the strings which are
+// shorter than the current depth. Shorter strings come first.
+const STRING_RADIX_RANGE: usize = 257;
+const FALLBACK_THRESHOLD: usize = 64;
+
+#[inline]
+fn byte_at<T: AsRef<[u8]>>(item: &T, depth: usize) -> usize {
+    let bytes = item.as_ref();
+    if depth < bytes.len() {
+        bytes[depth] as usize + 1
+    } else {
+        0
+    }
+}
+
+// All the strings of a bucket share the same first `depth` bytes, so they are
+// compared from `depth` only.
+fn fallback_sort<T: AsRef<[u8]>>(arr: &mut [T], depth: usize) {
+    arr.sort_unstable_by(|a, b| {
+        a.as_ref()[depth..].cmp(&b.as_ref()[depth..])
+    });
+}
+
+fn fallback_stable_sort<T: AsRef<[u8]>>(arr: &mut [T], depth: usize) {
+    arr.sort_by(|a, b| a.as_ref()[depth..].cmp(&b.as_ref()[depth..]));
+}
+
+fn get_string_histogram<T: AsRef<[u8]>>(
+    arr: &[T],
+    depth: usize,
+) -> Vec<usize> {
+    let mut histogram = vec![0; STRING_RADIX_RANGE];
+
+    arr.iter().for_each(|item| histogram[byte_at(item, depth)] += 1);
+
+    histogram
+}
+
+// Skip the common prefix: as long as all the strings fall into the same
+// bucket, there is nothing to move. Returns `None` when all the strings are
+// equal.
+fn skip_common_prefix<T: AsRef<[u8]>>(
+    arr: &[T],
+    depth: &mut usize,
+) -> Option<Vec<usize>> {
+    loop {
+        let histogram = get_string_histogram(arr, *depth);
+
+        if histogram[0] == arr.len() {
+            return None;
+        }
+        if histogram.contains(&arr.len()) {
+            *depth += 1;
+        } else {
+            return Some(histogram);
+        }
+    }
+}
+
+fn string_serial_swap<T: AsRef<[u8]>>(
+    arr: &mut [T],
+    heads: &mut [usize],
+    tails: &[usize],
+    depth: usize,
+) {
+    for i in 0..STRING_RADIX_RANGE - 1 {
+        while heads[i] < tails[i] {
+            let mut bucket = byte_at(&arr[heads[i]], depth);
+            while bucket != i {
+                arr.swap(heads[i], heads[bucket]);
+                heads[bucket] += 1;
+                bucket = byte_at(&arr[heads[i]], depth);
+            }
+            heads[i] += 1;
+        }
+    }
+}
+
+// Returns the buckets which still have to be sorted, with their depth.
+fn string_radixsort_level<T: AsRef<[u8]>>(
+    arr: &mut [T],
+    mut depth: usize,
+) -> Vec<(&mut [T], usize)> {
+    let histogram = match skip_common_prefix(arr, &mut depth) {
+        Some(histogram) => histogram,
+        None => return Vec::new(),
+    };
+    let (p_sums, mut heads, tails) = prefix_sums(&histogram);
+
+    string_serial_swap(arr, &mut heads, &tails, depth);
+
+    let mut buckets = Vec::new();
+    let mut rest = arr;
+    for i in 0..STRING_RADIX_RANGE {
+        let bucket_end = p_sums[i + 1] - p_sums[i];
+        let (first_part, second_part) = rest.split_at_mut(bucket_end);
+        rest = second_part;
+        // Bucket 0 only contains equal strings.
+        if i > 0 && histogram[i] > 1 {
+            buckets.push((first_part, depth + 1));
+        }
+    }
+
+    buckets
+}
+
+fn string_radixsort_rec<T: AsRef<[u8]>>(arr: &mut [T], depth: usize) {
+    if arr.len() <= FALLBACK_THRESHOLD {
+        fallback_sort(arr, depth);
+        return;
+    }
+
+    for (bucket, depth) in string_radixsort_level(arr, depth) {
+        string_radixsort_rec(bucket, depth);
+    }
+}
+
+/// # String sort
+///
+/// A byte-wise [American flag sort](https://en.wikipedia.org/wiki/American_flag_sort)
+/// for variable length byte strings (`String`, `&str`, `Vec<u8>`, ...).
+///
+/// Strings are sorted in lexicographic order of their bytes, which is also
+/// the order of `Ord` for `String` and `&str`. Each level of the sort handles
+/// one byte and the common prefix of a bucket is skipped without moving any
+/// element. Small buckets fallback on the Rust unstable sort.
+///
+/// The String sort is an in place unstable radix sort.
+pub fn string_sort<T: AsRef<[u8]>>(arr: &mut [T]) {
+    string_radixsort_rec(arr, 0);
+}
+
+// Out of place stable counting sort on one byte. Bucket ids are computed
+// before moving anything, so a panic in `as_ref` cannot leave `arr` with
+// duplicated elements.
+fn string_stable_radixsort_rec<T: AsRef<[u8]>>(
+    arr: &mut [T],
+    buffer: &mut [MaybeUninit<T>],
+    bucket_ids: &mut [u16],
+    mut depth: usize,
+) {
+    if arr.len() <= FALLBACK_THRESHOLD {
+        fallback_stable_sort(arr, depth);
+        return;
+    }
+
+    let histogram = match skip_common_prefix(arr, &mut depth) {
+        Some(histogram) => histogram,
+        None => return,
+    };
+    let (p_sums, mut heads, _) = prefix_sums(&histogram);
+
+    arr.iter()
+        .zip(bucket_ids.iter_mut())
+        .for_each(|(item, id)| *id = byte_at(item, depth) as u16);
+
+    unsafe {
+        let src = arr.as_mut_ptr();
+        let dst = buffer.as_mut_ptr() as *mut T;
+        for (i, id) in bucket_ids.iter().enumerate() {
+            let id = *id as usize;
+            ptr::copy_nonoverlapping(src.add(i), dst.add(heads[id]), 1);
+            heads[id] += 1;
+        }
+        ptr::copy_nonoverlapping(dst, src, arr.len());
+    }
+
+    let mut rest = arr;
+    let mut rest_buffer = buffer;
+    let mut rest_ids = bucket_ids;
+    for i in 0..STRING_RADIX_RANGE {
+        let bucket_end = p_sums[i + 1] - p_sums[i];
+        let (first_part, second_part) = rest.split_at_mut(bucket_end);
+        let (first_buffer, second_buffer) =
+            rest_buffer.split_at_mut(bucket_end);
+        let (first_ids, second_ids) = rest_ids.split_at_mut(bucket_end);
+        rest = second_part;
+        rest_buffer = second_buffer;
+        rest_ids = second_ids;
+        if i > 0 && histogram[i] > 1 {
+            string_stable_radixsort_rec(
+                first_part,
+                first_buffer,
+                first_ids,
+                depth + 1,
+            );
+        }
+    }
+}
+
+/// # String stable sort
+///
+/// A byte-wise out of place MSD radix sort for variable length byte strings
+/// (`String`, `&str`, `Vec<u8>`, ...).
+///
+/// Strings are sorted in lexicographic order of their bytes. Elements are
+/// moved with a counting sort into a buffer, so the sort keeps the relative
+/// order of equal strings. Small buckets fallback on the Rust stable sort.
+///
+/// The String stable sort is an out of place stable radix sort.
+pub fn string_stable_sort<T: AsRef<[u8]>>(arr: &mut [T]) {
+    if arr.len() <= FALLBACK_THRESHOLD {
+        fallback_stable_sort(arr, 0);
+        return;
+    }
+
+    let mut buffer: Vec<MaybeUninit<T>> = Vec::with_capacity(arr.len());
+    let mut bucket_ids: Vec<u16> = vec![0; arr.len()];
+    unsafe {
+        buffer.set_len(arr.len());
+    }
+
+    string_stable_radixsort_rec(arr, &mut buffer, &mut bucket_ids, 0);
+}
+
+/// # String multithread sort
+///
+/// The multithread version of the String sort. The first levels are sorted
+/// by the current thread, then the buckets are sorted in parallel on a
+/// thread pool of `thread_n` threads, or on the current rayon pool if
+/// `thread_n` is 0.
+///
+/// The String multithread sort is an in place unstable radix sort.
+#[cfg(feature = "voracious_multithread")]
+pub fn string_mt_sort<T: AsRef<[u8]> + Send>(arr: &mut [T], thread_n: usize) {
+    if arr.len() <= 10_000 || thread_n == 1 {
+        string_sort(arr);
+        return;
+    }
+
+    run_in_pool(thread_n, || {
+        let buckets = string_radixsort_level(arr, 0);
+
+        rayon::scope(|s| {
+            for (bucket, depth) in buckets {
+                s.spawn(move |_| string_radixsort_rec(bucket, depth));
+            }
+        });
+    });
+}
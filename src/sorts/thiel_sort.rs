@@ -1,6 +1,10 @@
+use alloc::vec;
+use alloc::vec::Vec;
+
 use super::super::algo::k_way_merge::k_way_merge;
 use super::super::algo::verge_sort_heuristic::verge_sort_preprocessing;
 use super::super::{RadixKey, Radixable};
+use super::comparative_sort::key_cmp;
 use super::lsd_sort::lsd_radixsort_body;
 use super::msd_sort::copy_by_histogram;
 use super::utils::{
@@ -385,7 +389,7 @@ where
     K: RadixKey,
 {
     if arr.len() <= 128 {
-        arr.sort_unstable_by(|a, b| a.partial_cmp(b).unwrap());
+        arr.sort_unstable_by(key_cmp);
         return;
     }
 
@@ -519,7 +523,7 @@ where
     K: RadixKey,
 {
     if arr.len() <= 128 {
-        arr.sort_unstable_by(|a, b| a.partial_cmp(b).unwrap());
+        arr.sort_unstable_by(key_cmp);
         return;
     }
 
@@ -564,7 +568,7 @@ where
     K: RadixKey,
 {
     if arr.len() <= 128 {
-        arr.sort_unstable_by(|a, b| a.partial_cmp(b).unwrap());
+        arr.sort_unstable_by(key_cmp);
         return;
     }
 
@@ -0,0 +1,120 @@
+use alloc::collections::TryReserveError;
+use core::fmt;
+
+use super::super::{Dispatcher, RadixKey, Radixable};
+use super::american_flag_sort::try_american_flag_sort;
+use super::comparative_sort::{in_place_merge_sort, insertion_sort};
+use super::sort_context::SortContext;
+
+/// # Sort error
+///
+/// Returned by the `try_*` sorts when the sort cannot be done.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SortError {
+    /// The scratch memory of the sort (`bytes` bytes) could not be
+    /// allocated.
+    AllocationFailed { bytes: usize },
+    /// A key is NaN, and the NaN policy of the sort is `NanPolicy::Error`.
+    NanKey,
+}
+
+impl fmt::Display for SortError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SortError::AllocationFailed { bytes } => {
+                write!(f, "cannot allocate {} bytes of scratch memory", bytes)
+            },
+            SortError::NanKey => write!(f, "a key is NaN"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for SortError {}
+
+fn allocation_failed<T>(size: usize) -> SortError {
+    SortError::AllocationFailed {
+        bytes: size.saturating_mul(core::mem::size_of::<T>()),
+    }
+}
+
+/// # Try voracious sort
+///
+/// Same as the `voracious_sort` method, but the scratch memory is allocated
+/// with `try_reserve`. The array is sorted by the LSD radix sort of a
+/// `SortContext` allocated for it. If this allocation fails, the array is
+/// sorted by an in place radix sort (the American flag sort) whose histograms
+/// are allocated with `try_reserve` too. If both allocations fail, the array
+/// is left untouched and `SortError::AllocationFailed` is returned. Small
+/// arrays are sorted by an insertion sort, which allocates nothing.
+///
+/// This sort is an unstable radix sort.
+pub fn try_voracious_sort<T, K>(arr: &mut [T]) -> Result<(), SortError>
+where
+    T: Radixable<K>,
+    K: RadixKey,
+{
+    if arr.len() <= 128 {
+        insertion_sort(arr);
+        return Ok(());
+    }
+
+    if let Ok(mut context) = SortContext::try_with_capacity(arr.len()) {
+        context.voracious_sort(arr);
+        return Ok(());
+    }
+
+    // Two counts (histogram and heads) per bucket and per level.
+    try_american_flag_sort(arr, 8).map_err(|_: TryReserveError| {
+        let levels = (arr[0].type_size() + 7) / 8;
+        allocation_failed::<usize>(2 * levels * 256)
+    })
+}
+
+/// # Try voracious stable sort
+///
+/// Same as the `voracious_stable_sort` method, but the scratch memory is
+/// allocated with `try_reserve`: the array is sorted by the LSD radix sort of
+/// a `SortContext` allocated for it. There is no in place stable radix sort,
+/// so if the allocation fails, the array is sorted by an in place merge sort
+/// (`O(n log² n)`), which allocates nothing. This sort never fails.
+///
+/// This sort is a stable radix sort.
+pub fn try_voracious_stable_sort<T, K>(arr: &mut [T]) -> Result<(), SortError>
+where
+    T: Radixable<K>,
+    K: RadixKey,
+{
+    if arr.len() <= 128 {
+        insertion_sort(arr);
+        return Ok(());
+    }
+
+    match SortContext::try_with_capacity(arr.len()) {
+        Ok(mut context) => context.voracious_stable_sort(arr),
+        Err(_) => in_place_merge_sort(arr),
+    }
+
+    Ok(())
+}
+
+/// # Voracious sort with a memory limit
+///
+/// Sort `arr` with the same algorithms as the `voracious_sort` method, as
+/// long as their scratch memory (the buffers of the sort, of its fallbacks
+/// and of the Verge sort pre-processing, and the histograms) fits in `bytes`.
+/// Otherwise the in place radix sort of the key type is used (the American
+/// flag sort or the Ska sort, which only allocate histograms), or a
+/// comparison sort if even the histograms do not fit.
+///
+/// This sort is an unstable radix sort.
+pub fn voracious_sort_with_memory_limit<T, K>(arr: &mut [T], bytes: usize)
+where
+    T: Radixable<K>,
+    K: RadixKey,
+{
+    if arr.len() > 1 {
+        let dummy_key = arr[0].key();
+        Dispatcher::voracious_sort_with_memory_limit(&dummy_key, arr, bytes);
+    }
+}
@@ -1,4 +1,9 @@
+use alloc::vec;
+use alloc::vec::Vec;
+
 use super::super::{RadixKey, Radixable};
+use super::key_range::reduced_digit;
+use super::utils_simd::fill_full_histograms_simd;
 
 #[derive(Copy, Clone, Debug, Default)]
 pub struct Params {
@@ -7,6 +12,8 @@ pub struct Params {
     pub offset: usize,
     pub max_level: usize,
     pub radix_range: usize,
+    pub write_combining: bool,
+    pub key_min: Option<u128>,
 }
 
 impl Params {
@@ -22,11 +29,58 @@ impl Params {
             offset,
             max_level,
             radix_range: (2 as usize).pow(radix as u32),
+            write_combining: false,
+            key_min: None,
         }
     }
     pub fn new_level(&self, level: usize) -> Params {
         Params { level, ..(*self) }
     }
+    // When set, the scatter steps go through `copy_by_histogram_wc`.
+    pub fn with_write_combining(&self, write_combining: bool) -> Params {
+        Params { write_combining, ..(*self) }
+    }
+    // When set, this key is subtracted from every key before the digits are
+    // extracted (see `key_range`).
+    pub fn with_key_min(&self, key_min: Option<u128>) -> Params {
+        Params { key_min, ..(*self) }
+    }
+}
+
+// `f64::log2` and `f64::ceil` need std. Without std, the heuristics use these
+// approximations (log2 is linear between two powers of two), which are close
+// enough to pick a radix or a run size.
+#[inline]
+pub fn log2(x: f64) -> f64 {
+    #[cfg(feature = "std")]
+    {
+        x.log2()
+    }
+    #[cfg(not(feature = "std"))]
+    {
+        let bits = x.to_bits();
+        let exponent = ((bits >> 52) & 0x7ff) as i64 - 1023;
+        let mantissa =
+            f64::from_bits((bits & 0x000f_ffff_ffff_ffff) | (1023 << 52));
+        exponent as f64 + (mantissa - 1.0)
+    }
+}
+
+#[inline]
+pub fn ceil(x: f64) -> f64 {
+    #[cfg(feature = "std")]
+    {
+        x.ceil()
+    }
+    #[cfg(not(feature = "std"))]
+    {
+        let truncated = x as i64 as f64;
+        if truncated < x {
+            truncated + 1.0
+        } else {
+            truncated
+        }
+    }
 }
 
 #[inline]
@@ -36,7 +90,7 @@ pub fn copy_nonoverlapping<T>(
     length: usize,
 ) {
     unsafe {
-        std::ptr::copy_nonoverlapping(
+        core::ptr::copy_nonoverlapping(
             source.as_ptr(),
             destination.get_unchecked_mut(0),
             length,
@@ -59,6 +113,31 @@ pub fn prefix_sums(
     (p_sums, heads, tails)
 }
 
+// Exclusive prefix sums of `histogram`, written in `heads` (no allocation).
+pub fn prefix_sums_into(histogram: &[usize], heads: &mut [usize]) {
+    let mut sum = 0;
+    for (head, count) in heads.iter_mut().zip(histogram.iter()) {
+        *head = sum;
+        sum += *count;
+    }
+}
+
+#[cfg(any(
+    feature = "voracious_multithread",
+    feature = "voracious_multithread_std"
+))]
+pub fn aggregate_histograms(histograms: &[Vec<usize>]) -> Vec<usize> {
+    let mut global_histogram = vec![0; histograms[0].len()];
+
+    histograms.iter().for_each(|histogram| {
+        histogram.iter().enumerate().for_each(|(i, v)| {
+            global_histogram[i] += v;
+        });
+    });
+
+    global_histogram
+}
+
 pub fn only_one_bucket_filled(histogram: &[usize]) -> bool {
     let mut count = 0;
 
@@ -76,7 +155,7 @@ pub fn only_one_bucket_filled(histogram: &[usize]) -> bool {
 }
 
 pub fn offset_from_bits<T, K>(
-    _arr: &mut [T],
+    _arr: &[T],
     biggest: <<T as Radixable<K>>::Key as RadixKey>::Key,
     radix: usize,
     bits: usize,
@@ -112,7 +191,7 @@ where
 }
 
 pub fn compute_offset<T: Radixable<K> + Copy, K: RadixKey>(
-    arr: &mut [T],
+    arr: &[T],
     radix: usize,
 ) -> (usize, usize) {
     let dummy = arr[0];
@@ -158,15 +237,27 @@ pub fn get_histogram<T: Radixable<K>, K: RadixKey>(
     mask: <<T as Radixable<K>>::Key as RadixKey>::Key,
     shift: usize,
 ) -> Vec<usize> {
+    if let Some(min) = p.key_min {
+        let mask = mask.into();
+        get_histogram_by(arr, p, |item| reduced_digit(item, min, mask, shift))
+    } else {
+        get_histogram_by(arr, p, |item| item.extract(mask, shift))
+    }
+}
+
+pub fn get_histogram_by<T, F>(arr: &[T], p: &Params, digit: F) -> Vec<usize>
+where
+    F: Fn(&T) -> usize,
+{
     let mut histogram = vec![0; p.radix_range];
     let remainder = arr.len() % 4;
     let (arr_fst, arr_remainder) = arr.split_at(arr.len() - remainder);
 
     arr_fst.chunks_exact(4).for_each(|chunk| {
-        let bucket0 = chunk[0].extract(mask, shift);
-        let bucket1 = chunk[1].extract(mask, shift);
-        let bucket2 = chunk[2].extract(mask, shift);
-        let bucket3 = chunk[3].extract(mask, shift);
+        let bucket0 = digit(&chunk[0]);
+        let bucket1 = digit(&chunk[1]);
+        let bucket2 = digit(&chunk[2]);
+        let bucket3 = digit(&chunk[3]);
         histogram[bucket0] += 1;
         histogram[bucket1] += 1;
         histogram[bucket2] += 1;
@@ -174,7 +265,7 @@ pub fn get_histogram<T: Radixable<K>, K: RadixKey>(
     });
 
     arr_remainder.iter().for_each(|item| {
-        let bucket = item.extract(mask, shift);
+        let bucket = digit(item);
         histogram[bucket] += 1;
     });
 
@@ -385,13 +476,36 @@ pub fn get_partial_histograms<T: Radixable<K>, K: RadixKey>(
     histograms
 }
 
-pub fn get_full_histograms<T, K>(arr: &mut [T], p: &Params) -> Vec<Vec<usize>>
+pub fn get_full_histograms<T, K>(arr: &[T], p: &Params) -> Vec<Vec<usize>>
 where
     T: Radixable<K>,
     K: RadixKey,
 {
-    let dummy = arr[0];
     let mut histograms = get_empty_histograms(p.max_level, p.radix_range);
+
+    fill_full_histograms(arr, p, &mut histograms, &mut Vec::new());
+
+    histograms
+}
+
+// Same as `get_full_histograms`, but the histograms are provided by the
+// caller, so they can be reused between two sorts. They must be zeroed, with
+// at least `p.max_level` histograms of at least `p.radix_range` buckets.
+// `copies` is the scratch memory of the SIMD histograms, reused as well.
+pub fn fill_full_histograms<T, K>(
+    arr: &[T],
+    p: &Params,
+    histograms: &mut [Vec<usize>],
+    copies: &mut Vec<usize>,
+) where
+    T: Radixable<K>,
+    K: RadixKey,
+{
+    if fill_full_histograms_simd(arr, p, histograms, copies) {
+        return;
+    }
+
+    let dummy = arr[0];
     let default_mask = dummy.default_mask(p.radix);
     let shift = dummy.usize_to_keytype(p.radix);
 
@@ -1839,6 +1953,4 @@ where
     } else {
         panic!("[Get full histogram] Too small radix.");
     }
-
-    histograms
 }
@@ -1,7 +1,36 @@
-use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
+use rayon::iter::{
+    IndexedParallelIterator, IntoParallelRefIterator,
+    IntoParallelRefMutIterator, ParallelIterator,
+};
+use rayon::slice::{ParallelSlice, ParallelSliceMut};
+use rayon::ThreadPoolBuilder;
 
 use super::super::{RadixKey, Radixable};
-use super::utils::offset_from_bits;
+use super::key_range::{reduce_key_range, reduced_digit};
+use super::utils::{
+    get_histogram, offset_from_bits, only_one_bucket_filled, prefix_sums,
+    Params,
+};
+
+// Also used by the `std` multithread sorts, which do not build this module.
+pub use super::utils::aggregate_histograms;
+
+// Runs `f` on a new pool of `thread_n` threads. When `thread_n` is 0, `f`
+// runs on the current rayon pool (the global pool, or the pool of the calling
+// rayon task) with all its threads, and no thread is spawned.
+pub fn run_in_pool<R, F>(thread_n: usize, f: F) -> R
+where
+    R: Send,
+    F: FnOnce() -> R + Send,
+{
+    if thread_n == 0 {
+        f()
+    } else {
+        let pool =
+            ThreadPoolBuilder::new().num_threads(thread_n).build().unwrap();
+        pool.install(f)
+    }
+}
 
 pub fn compute_offset_mt<T: Radixable<K>, K: RadixKey>(
     arr: &mut [T],
@@ -20,14 +49,167 @@ pub fn compute_offset_mt<T: Radixable<K>, K: RadixKey>(
     )
 }
 
-pub fn aggregate_histograms(histograms: &[Vec<usize>]) -> Vec<usize> {
-    let mut global_histogram = vec![0; histograms[0].len()];
+// Same as `compute_offset_reduced`, the minimum and the maximum keys are
+// computed on the current rayon pool.
+pub fn compute_offset_reduced_mt<T: Radixable<K>, K: RadixKey>(
+    arr: &mut [T],
+    radix: usize,
+) -> (usize, usize, Option<u128>) {
+    let dummy = arr[0];
+    let first = dummy.into_key_type();
+    let (min, max) = arr
+        .par_iter()
+        .map(|item| {
+            let key = item.into_key_type();
+            (key, key)
+        })
+        .reduce(
+            || (first, first),
+            |(min_a, max_a), (min_b, max_b)| {
+                (
+                    if min_b < min_a { min_b } else { min_a },
+                    if max_b > max_a { max_b } else { max_a },
+                )
+            },
+        );
+
+    match reduce_key_range(&dummy, min, max, radix) {
+        Some((offset, raw_offset, key_min)) => {
+            (offset, raw_offset, Some(key_min))
+        },
+        None => {
+            let (offset, raw_offset) = offset_from_bits(
+                arr,
+                max,
+                radix,
+                dummy.type_size(),
+                dummy.default_key(),
+                dummy.one(),
+            );
+            (offset, raw_offset, None)
+        },
+    }
+}
 
-    histograms.iter().for_each(|histogram| {
-        histogram.iter().enumerate().for_each(|(i, v)| {
-            global_histogram[i] += v;
+// Scatters each chunk of `source` (of `chunk_size` elements) into
+// `destination`, from the heads given by the histograms of the chunks, on the
+// current rayon pool. Equal digits keep their order.
+fn scatter_by_histograms_by_mt<T, K, F>(
+    source: &[T],
+    destination: &mut [T],
+    chunk_size: usize,
+    histograms: &[Vec<usize>],
+    global_histogram: &[usize],
+    digit: F,
+) where
+    T: Radixable<K>,
+    K: RadixKey,
+    F: Fn(&T) -> usize + Sync,
+{
+    let (_, mut heads, _) = prefix_sums(global_histogram);
+    let mut chunks_heads = Vec::with_capacity(histograms.len());
+    for histogram in histograms.iter() {
+        chunks_heads.push(heads.to_vec());
+        heads.iter_mut().zip(histogram.iter()).for_each(|(head, count)| {
+            *head += count;
         });
-    });
+    }
+
+    // Each chunk writes in its own positions of the destination.
+    let destination_ptr = destination.as_mut_ptr() as usize;
+    source
+        .par_chunks(chunk_size)
+        .zip(chunks_heads.par_iter_mut())
+        .for_each(|(chunk, heads)| {
+            let destination = destination_ptr as *mut T;
+            chunk.iter().for_each(|item| {
+                let bucket = digit(item);
+                unsafe {
+                    *destination.add(heads[bucket]) = *item;
+                }
+                heads[bucket] += 1;
+            });
+        });
+}
+
+// `scatter_by_histograms_by_mt` on the digits of `get_histogram`: the reduced
+// keys when `p.key_min` is set (see `key_range`).
+pub fn scatter_by_histograms_mt<T, K>(
+    source: &[T],
+    destination: &mut [T],
+    chunk_size: usize,
+    histograms: &[Vec<usize>],
+    global_histogram: &[usize],
+    p: &Params,
+    mask: <<T as Radixable<K>>::Key as RadixKey>::Key,
+    shift: usize,
+) where
+    T: Radixable<K>,
+    K: RadixKey,
+{
+    match p.key_min {
+        Some(min) => {
+            let mask = mask.into();
+            scatter_by_histograms_by_mt(
+                source,
+                destination,
+                chunk_size,
+                histograms,
+                global_histogram,
+                |item: &T| reduced_digit(item, min, mask, shift),
+            );
+        },
+        None => scatter_by_histograms_by_mt(
+            source,
+            destination,
+            chunk_size,
+            histograms,
+            global_histogram,
+            |item: &T| item.extract(mask, shift),
+        ),
+    }
+}
+
+// One stable distribution pass of `source` into `destination`, on the
+// current rayon pool.
+// The source is cut into one chunk per thread, each chunk computes its own
+// histogram, then the histograms are aggregated and each chunk scatters its
+// elements from its own heads.
+// Returns `None` (and nothing is copied) when only one bucket is filled.
+pub fn stable_pass_mt<T, K>(
+    source: &mut [T],
+    destination: &mut [T],
+    p: &Params,
+    mask: <<T as Radixable<K>>::Key as RadixKey>::Key,
+    shift: usize,
+) -> Option<Vec<usize>>
+where
+    T: Radixable<K>,
+    K: RadixKey,
+{
+    let thread_n = rayon::current_num_threads();
+    let chunk_size = (source.len() + thread_n - 1) / thread_n;
+
+    let histograms: Vec<Vec<usize>> = source
+        .par_chunks_mut(chunk_size)
+        .map(|chunk| get_histogram(chunk, p, mask, shift))
+        .collect();
+    let global_histogram = aggregate_histograms(&histograms);
+
+    if only_one_bucket_filled(&global_histogram) {
+        return None;
+    }
+
+    scatter_by_histograms_mt(
+        source,
+        destination,
+        chunk_size,
+        &histograms,
+        &global_histogram,
+        p,
+        mask,
+        shift,
+    );
 
-    global_histogram
+    Some(global_histogram)
 }
@@ -0,0 +1,605 @@
+use alloc::vec;
+use alloc::vec::Vec;
+
+#[cfg(target_arch = "x86_64")]
+use core::arch::x86_64::*;
+
+use super::super::{RadixKey, Radixable};
+use super::utils::Params;
+
+// Number of histogram copies updated by the SIMD kernels. Two consecutive keys
+// falling into the same bucket are counted in two different copies, so the
+// second increment does not wait for the first one to be stored.
+const COPIES: usize = 4;
+
+// Below this size, merging the copies costs more than it saves.
+const SIMD_THRESHOLD: usize = 4096;
+
+// The copies hold `1 << radix` buckets per level, so the radix is bounded.
+const SIMD_MAX_RADIX: usize = 12;
+
+// Number of keys gathered at once by the generic path.
+const BLOCK_SIZE: usize = 256;
+
+/// How the raw bits of a slice are turned into an unsigned key before the
+/// digits are extracted. It matches the `into_key_type` of the primitive
+/// types.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum KeyTransform {
+    /// Unsigned integers, the bits are the key.
+    Identity,
+    /// Signed integers, the sign bit is flipped.
+    FlipSign,
+    /// Floats, the negative numbers have all their bits flipped, the positive
+    /// ones only their sign bit.
+    FlipFloat,
+}
+
+impl KeyTransform {
+    #[inline]
+    fn apply_u32(self, v: u32) -> u32 {
+        match self {
+            KeyTransform::Identity => v,
+            KeyTransform::FlipSign => v ^ 0x8000_0000,
+            KeyTransform::FlipFloat => {
+                v ^ (((v as i32) >> 31) as u32 | 0x8000_0000)
+            },
+        }
+    }
+
+    #[inline]
+    fn apply_u64(self, v: u64) -> u64 {
+        match self {
+            KeyTransform::Identity => v,
+            KeyTransform::FlipSign => v ^ 0x8000_0000_0000_0000,
+            KeyTransform::FlipFloat => {
+                v ^ (((v as i64) >> 63) as u64 | 0x8000_0000_0000_0000)
+            },
+        }
+    }
+}
+
+// Views of the raw bits of the primitive slices, to be used with a
+// `KeyTransform`.
+#[inline]
+pub fn bits_of_i32(arr: &[i32]) -> &[u32] {
+    unsafe {
+        core::slice::from_raw_parts(arr.as_ptr() as *const u32, arr.len())
+    }
+}
+
+#[inline]
+pub fn bits_of_f32(arr: &[f32]) -> &[u32] {
+    unsafe {
+        core::slice::from_raw_parts(arr.as_ptr() as *const u32, arr.len())
+    }
+}
+
+#[inline]
+pub fn bits_of_i64(arr: &[i64]) -> &[u64] {
+    unsafe {
+        core::slice::from_raw_parts(arr.as_ptr() as *const u64, arr.len())
+    }
+}
+
+#[inline]
+pub fn bits_of_f64(arr: &[f64]) -> &[u64] {
+    unsafe {
+        core::slice::from_raw_parts(arr.as_ptr() as *const u64, arr.len())
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum Isa {
+    Scalar,
+    #[cfg(target_arch = "x86_64")]
+    Sse42,
+    #[cfg(target_arch = "x86_64")]
+    Avx2,
+}
+
+#[cfg(all(target_arch = "x86_64", feature = "std"))]
+fn detect_isa() -> Isa {
+    if is_x86_feature_detected!("avx2") {
+        Isa::Avx2
+    } else if is_x86_feature_detected!("sse4.2") {
+        Isa::Sse42
+    } else {
+        Isa::Scalar
+    }
+}
+
+// Without std, there is no runtime detection: only the features enabled at
+// compile time are used.
+#[cfg(all(target_arch = "x86_64", not(feature = "std")))]
+fn detect_isa() -> Isa {
+    if cfg!(target_feature = "avx2") {
+        Isa::Avx2
+    } else if cfg!(target_feature = "sse4.2") {
+        Isa::Sse42
+    } else {
+        Isa::Scalar
+    }
+}
+
+#[cfg(not(target_arch = "x86_64"))]
+fn detect_isa() -> Isa { Isa::Scalar }
+
+/// Tells if the histograms of an array of `size` elements would be computed
+/// by a SIMD kernel.
+#[inline]
+pub fn simd_histograms_available(size: usize, radix: usize) -> bool {
+    size >= SIMD_THRESHOLD
+        && radix <= SIMD_MAX_RADIX
+        && detect_isa() != Isa::Scalar
+}
+
+// The histograms are ordered from the most significant digit to the least
+// significant one, so they are filled from the last one.
+pub fn fill_histograms_u32_scalar(
+    arr: &[u32],
+    radix: usize,
+    max_level: usize,
+    transform: KeyTransform,
+    histograms: &mut [Vec<usize>],
+) {
+    let mask = ((1u64 << radix) - 1) as u32;
+    for item in arr.iter() {
+        let mut v = transform.apply_u32(*item);
+        for histogram in histograms[..max_level].iter_mut().rev() {
+            histogram[(v & mask) as usize] += 1;
+            v = v.checked_shr(radix as u32).unwrap_or(0);
+        }
+    }
+}
+
+pub fn fill_histograms_u64_scalar(
+    arr: &[u64],
+    radix: usize,
+    max_level: usize,
+    transform: KeyTransform,
+    histograms: &mut [Vec<usize>],
+) {
+    let mask = (1u64 << radix) - 1;
+    for item in arr.iter() {
+        let mut v = transform.apply_u64(*item);
+        for histogram in histograms[..max_level].iter_mut().rev() {
+            histogram[(v & mask) as usize] += 1;
+            v = v.checked_shr(radix as u32).unwrap_or(0);
+        }
+    }
+}
+
+// Copy `c`, histogram `level` lives at `(c * max_level + level) << radix`.
+fn merge_copies(
+    copies: &[usize],
+    radix: usize,
+    max_level: usize,
+    histograms: &mut [Vec<usize>],
+) {
+    let radix_range = 1 << radix;
+    for (index, copy) in copies.chunks_exact(radix_range).enumerate() {
+        let histogram = &mut histograms[index % max_level];
+        for (bucket, count) in copy.iter().enumerate() {
+            histogram[bucket] += *count;
+        }
+    }
+}
+
+// Counts the largest prefix of `arr` the SIMD kernels can handle and returns
+// its length. The remaining elements are left to the scalar path.
+fn count_u32(
+    isa: Isa,
+    arr: &[u32],
+    radix: usize,
+    max_level: usize,
+    transform: KeyTransform,
+    copies: &mut [usize],
+) -> usize {
+    match isa {
+        Isa::Scalar => 0,
+        #[cfg(target_arch = "x86_64")]
+        Isa::Sse42 => unsafe {
+            sse42::count_u32(arr, radix, max_level, transform, copies)
+        },
+        #[cfg(target_arch = "x86_64")]
+        Isa::Avx2 => unsafe {
+            avx2::count_u32(arr, radix, max_level, transform, copies)
+        },
+    }
+}
+
+fn count_u64(
+    isa: Isa,
+    arr: &[u64],
+    radix: usize,
+    max_level: usize,
+    transform: KeyTransform,
+    copies: &mut [usize],
+) -> usize {
+    match isa {
+        Isa::Scalar => 0,
+        #[cfg(target_arch = "x86_64")]
+        Isa::Sse42 => unsafe {
+            sse42::count_u64(arr, radix, max_level, transform, copies)
+        },
+        #[cfg(target_arch = "x86_64")]
+        Isa::Avx2 => unsafe {
+            avx2::count_u64(arr, radix, max_level, transform, copies)
+        },
+    }
+}
+
+/// Adds the digits of `arr` (after `transform`) to the `max_level` first
+/// `histograms`, with the best SIMD kernel available on the running CPU and a
+/// scalar fallback otherwise.
+pub fn fill_histograms_u32(
+    arr: &[u32],
+    radix: usize,
+    max_level: usize,
+    transform: KeyTransform,
+    histograms: &mut [Vec<usize>],
+) {
+    if max_level == 0 {
+        return;
+    }
+    if !simd_histograms_available(arr.len(), radix) {
+        fill_histograms_u32_scalar(
+            arr, radix, max_level, transform, histograms,
+        );
+        return;
+    }
+
+    let mut copies = vec![0; (COPIES * max_level) << radix];
+    let done =
+        count_u32(detect_isa(), arr, radix, max_level, transform, &mut copies);
+    merge_copies(&copies, radix, max_level, histograms);
+    fill_histograms_u32_scalar(
+        &arr[done..],
+        radix,
+        max_level,
+        transform,
+        histograms,
+    );
+}
+
+/// Same as `fill_histograms_u32`, for 64 bits keys.
+pub fn fill_histograms_u64(
+    arr: &[u64],
+    radix: usize,
+    max_level: usize,
+    transform: KeyTransform,
+    histograms: &mut [Vec<usize>],
+) {
+    if max_level == 0 {
+        return;
+    }
+    if !simd_histograms_available(arr.len(), radix) {
+        fill_histograms_u64_scalar(
+            arr, radix, max_level, transform, histograms,
+        );
+        return;
+    }
+
+    let mut copies = vec![0; (COPIES * max_level) << radix];
+    let done =
+        count_u64(detect_isa(), arr, radix, max_level, transform, &mut copies);
+    merge_copies(&copies, radix, max_level, histograms);
+    fill_histograms_u64_scalar(
+        &arr[done..],
+        radix,
+        max_level,
+        transform,
+        histograms,
+    );
+}
+
+/// Number of counters needed by `fill_full_histograms_simd` for `max_level`
+/// levels of `radix` bits.
+pub fn simd_copies_len(max_level: usize, radix: usize) -> usize {
+    (COPIES * max_level) << radix
+}
+
+/// SIMD version of `fill_full_histograms` for any `Radixable` type with a key
+/// of at most 64 bits. The keys are gathered by blocks into a buffer, then
+/// counted by the SIMD kernels. Returns `false`, without touching the
+/// histograms, when no SIMD kernel can be used.
+///
+/// The histogram copies of the kernels are kept in `copies`, which only grows
+/// when it is shorter than `simd_copies_len`: a buffer reused between two
+/// calls is not reallocated.
+pub fn fill_full_histograms_simd<T, K>(
+    arr: &[T],
+    p: &Params,
+    histograms: &mut [Vec<usize>],
+    copies: &mut Vec<usize>,
+) -> bool
+where
+    T: Radixable<K>,
+    K: RadixKey,
+{
+    // `keytype_to_usize` must not truncate the keys.
+    if core::mem::size_of::<usize>() < 8
+        || p.max_level == 0
+        || !simd_histograms_available(arr.len(), p.radix)
+    {
+        return false;
+    }
+
+    let isa = detect_isa();
+    let dummy = arr[0];
+    let bits = dummy.type_size();
+    if bits > 64 {
+        return false;
+    }
+
+    let (max_level, radix) = (p.max_level, p.radix);
+    let len = simd_copies_len(max_level, radix);
+    if copies.len() < len {
+        copies.resize(len, 0);
+    }
+    let copies = &mut copies[..len];
+    copies.iter_mut().for_each(|count| *count = 0);
+
+    if bits <= 32 {
+        let mut block = [0u32; BLOCK_SIZE];
+        for chunk in arr.chunks(BLOCK_SIZE) {
+            for (key, item) in block.iter_mut().zip(chunk.iter()) {
+                *key = dummy.keytype_to_usize(item.into_key_type()) as u32;
+            }
+            let keys = &block[..chunk.len()];
+            let done = count_u32(
+                isa,
+                keys,
+                radix,
+                max_level,
+                KeyTransform::Identity,
+                copies,
+            );
+            fill_histograms_u32_scalar(
+                &keys[done..],
+                radix,
+                max_level,
+                KeyTransform::Identity,
+                histograms,
+            );
+        }
+    } else {
+        let mut block = [0u64; BLOCK_SIZE];
+        for chunk in arr.chunks(BLOCK_SIZE) {
+            for (key, item) in block.iter_mut().zip(chunk.iter()) {
+                *key = dummy.keytype_to_usize(item.into_key_type()) as u64;
+            }
+            let keys = &block[..chunk.len()];
+            let done = count_u64(
+                isa,
+                keys,
+                radix,
+                max_level,
+                KeyTransform::Identity,
+                copies,
+            );
+            fill_histograms_u64_scalar(
+                &keys[done..],
+                radix,
+                max_level,
+                KeyTransform::Identity,
+                histograms,
+            );
+        }
+    }
+
+    merge_copies(copies, radix, max_level, histograms);
+
+    true
+}
+
+#[cfg(target_arch = "x86_64")]
+mod sse42 {
+    use super::*;
+
+    #[inline]
+    #[target_feature(enable = "sse4.2")]
+    unsafe fn transform_u32(v: __m128i, transform: KeyTransform) -> __m128i {
+        let sign = _mm_set1_epi32(i32::MIN);
+        match transform {
+            KeyTransform::Identity => v,
+            KeyTransform::FlipSign => _mm_xor_si128(v, sign),
+            KeyTransform::FlipFloat => {
+                _mm_xor_si128(v, _mm_or_si128(_mm_srai_epi32(v, 31), sign))
+            },
+        }
+    }
+
+    #[inline]
+    #[target_feature(enable = "sse4.2")]
+    unsafe fn transform_u64(v: __m128i, transform: KeyTransform) -> __m128i {
+        let sign = _mm_set1_epi64x(i64::MIN);
+        match transform {
+            KeyTransform::Identity => v,
+            KeyTransform::FlipSign => _mm_xor_si128(v, sign),
+            KeyTransform::FlipFloat => {
+                let negative = _mm_cmpgt_epi64(_mm_setzero_si128(), v);
+                _mm_xor_si128(v, _mm_or_si128(negative, sign))
+            },
+        }
+    }
+
+    #[target_feature(enable = "sse4.2")]
+    pub unsafe fn count_u32(
+        arr: &[u32],
+        radix: usize,
+        max_level: usize,
+        transform: KeyTransform,
+        copies: &mut [usize],
+    ) -> usize {
+        let mask = _mm_set1_epi32(((1u64 << radix) - 1) as i32);
+        let shift = _mm_cvtsi32_si128(radix as i32);
+        let stride = max_level << radix;
+
+        let chunks = arr.chunks_exact(4);
+        let done = arr.len() - chunks.remainder().len();
+
+        for chunk in chunks {
+            let mut v = _mm_loadu_si128(chunk.as_ptr() as *const __m128i);
+            v = transform_u32(v, transform);
+            for level in (0..max_level).rev() {
+                let d = _mm_and_si128(v, mask);
+                let base = level << radix;
+                let b0 = base + _mm_extract_epi32(d, 0) as usize;
+                let b1 = stride + base + _mm_extract_epi32(d, 1) as usize;
+                let b2 = 2 * stride + base + _mm_extract_epi32(d, 2) as usize;
+                let b3 = 3 * stride + base + _mm_extract_epi32(d, 3) as usize;
+                *copies.get_unchecked_mut(b0) += 1;
+                *copies.get_unchecked_mut(b1) += 1;
+                *copies.get_unchecked_mut(b2) += 1;
+                *copies.get_unchecked_mut(b3) += 1;
+                v = _mm_srl_epi32(v, shift);
+            }
+        }
+
+        done
+    }
+
+    #[target_feature(enable = "sse4.2")]
+    pub unsafe fn count_u64(
+        arr: &[u64],
+        radix: usize,
+        max_level: usize,
+        transform: KeyTransform,
+        copies: &mut [usize],
+    ) -> usize {
+        let mask = _mm_set1_epi64x(((1u64 << radix) - 1) as i64);
+        let shift = _mm_cvtsi32_si128(radix as i32);
+        let stride = max_level << radix;
+
+        let chunks = arr.chunks_exact(4);
+        let done = arr.len() - chunks.remainder().len();
+
+        for chunk in chunks {
+            let ptr = chunk.as_ptr() as *const __m128i;
+            let mut v0 = transform_u64(_mm_loadu_si128(ptr), transform);
+            let mut v1 = transform_u64(_mm_loadu_si128(ptr.add(1)), transform);
+            for level in (0..max_level).rev() {
+                let d0 = _mm_and_si128(v0, mask);
+                let d1 = _mm_and_si128(v1, mask);
+                let base = level << radix;
+                let b0 = base + _mm_extract_epi64(d0, 0) as usize;
+                let b1 = stride + base + _mm_extract_epi64(d0, 1) as usize;
+                let b2 = 2 * stride + base + _mm_extract_epi64(d1, 0) as usize;
+                let b3 = 3 * stride + base + _mm_extract_epi64(d1, 1) as usize;
+                *copies.get_unchecked_mut(b0) += 1;
+                *copies.get_unchecked_mut(b1) += 1;
+                *copies.get_unchecked_mut(b2) += 1;
+                *copies.get_unchecked_mut(b3) += 1;
+                v0 = _mm_srl_epi64(v0, shift);
+                v1 = _mm_srl_epi64(v1, shift);
+            }
+        }
+
+        done
+    }
+}
+
+#[cfg(target_arch = "x86_64")]
+mod avx2 {
+    use super::*;
+
+    #[inline]
+    #[target_feature(enable = "avx2")]
+    unsafe fn transform_u32(v: __m256i, transform: KeyTransform) -> __m256i {
+        let sign = _mm256_set1_epi32(i32::MIN);
+        match transform {
+            KeyTransform::Identity => v,
+            KeyTransform::FlipSign => _mm256_xor_si256(v, sign),
+            KeyTransform::FlipFloat => _mm256_xor_si256(
+                v,
+                _mm256_or_si256(_mm256_srai_epi32(v, 31), sign),
+            ),
+        }
+    }
+
+    #[inline]
+    #[target_feature(enable = "avx2")]
+    unsafe fn transform_u64(v: __m256i, transform: KeyTransform) -> __m256i {
+        let sign = _mm256_set1_epi64x(i64::MIN);
+        match transform {
+            KeyTransform::Identity => v,
+            KeyTransform::FlipSign => _mm256_xor_si256(v, sign),
+            KeyTransform::FlipFloat => {
+                let negative = _mm256_cmpgt_epi64(_mm256_setzero_si256(), v);
+                _mm256_xor_si256(v, _mm256_or_si256(negative, sign))
+            },
+        }
+    }
+
+    #[target_feature(enable = "avx2")]
+    pub unsafe fn count_u32(
+        arr: &[u32],
+        radix: usize,
+        max_level: usize,
+        transform: KeyTransform,
+        copies: &mut [usize],
+    ) -> usize {
+        let mask = _mm256_set1_epi32(((1u64 << radix) - 1) as i32);
+        let shift = _mm_cvtsi32_si128(radix as i32);
+        let stride = max_level << radix;
+        let mut digits = [0u32; 8];
+
+        let chunks = arr.chunks_exact(8);
+        let done = arr.len() - chunks.remainder().len();
+
+        for chunk in chunks {
+            let mut v = _mm256_loadu_si256(chunk.as_ptr() as *const __m256i);
+            v = transform_u32(v, transform);
+            for level in (0..max_level).rev() {
+                let d = _mm256_and_si256(v, mask);
+                _mm256_storeu_si256(digits.as_mut_ptr() as *mut __m256i, d);
+                let base = level << radix;
+                for (lane, digit) in digits.iter().enumerate() {
+                    let copy = (lane % COPIES) * stride;
+                    *copies.get_unchecked_mut(copy + base + *digit as usize) +=
+                        1;
+                }
+                v = _mm256_srl_epi32(v, shift);
+            }
+        }
+
+        done
+    }
+
+    #[target_feature(enable = "avx2")]
+    pub unsafe fn count_u64(
+        arr: &[u64],
+        radix: usize,
+        max_level: usize,
+        transform: KeyTransform,
+        copies: &mut [usize],
+    ) -> usize {
+        let mask = _mm256_set1_epi64x(((1u64 << radix) - 1) as i64);
+        let shift = _mm_cvtsi32_si128(radix as i32);
+        let stride = max_level << radix;
+        let mut digits = [0u64; 4];
+
+        let chunks = arr.chunks_exact(4);
+        let done = arr.len() - chunks.remainder().len();
+
+        for chunk in chunks {
+            let mut v = _mm256_loadu_si256(chunk.as_ptr() as *const __m256i);
+            v = transform_u64(v, transform);
+            for level in (0..max_level).rev() {
+                let d = _mm256_and_si256(v, mask);
+                _mm256_storeu_si256(digits.as_mut_ptr() as *mut __m256i, d);
+                let base = level << radix;
+                for (lane, digit) in digits.iter().enumerate() {
+                    let copy = lane * stride;
+                    *copies.get_unchecked_mut(copy + base + *digit as usize) +=
+                        1;
+                }
+                v = _mm256_srl_epi64(v, shift);
+            }
+        }
+
+        done
+    }
+}
@@ -3,6 +3,7 @@ use super::super::algo::verge_sort_heuristic::{
     explore_simple_forward, verge_sort_preprocessing, Orientation,
 };
 use super::super::{RadixKey, Radixable};
+use super::comparative_sort::key_cmp;
 use super::counting_sort::counting_sort;
 use super::msd_sort::msd_radixsort_rec;
 use super::ska_sort::ska_swap;
@@ -16,7 +17,7 @@ pub fn voracious_sort_rec<T: Radixable<K>, K: RadixKey>(
     // Small optimization, use PDQ sort (sort implemented in Std Rust Unstable)
     // instead of insertion sort for small size array.
     if arr.len() <= 128 {
-        arr.sort_unstable_by(|a, b| a.partial_cmp(b).unwrap());
+        arr.sort_unstable_by(key_cmp);
         return;
     }
     // Main optimization is here: better diversion handling.
@@ -80,7 +81,7 @@ fn voracious_sort_aux<T: Radixable<K>, K: RadixKey>(
 ) {
     let size = arr.len();
     if size <= 128 {
-        arr.sort_unstable_by(|a, b| a.partial_cmp(b).unwrap());
+        arr.sort_unstable_by(key_cmp);
         return;
     }
 
@@ -135,7 +136,7 @@ where
     K: RadixKey,
 {
     if arr.len() <= 128 {
-        arr.sort_unstable_by(|a, b| a.partial_cmp(b).unwrap());
+        arr.sort_unstable_by(key_cmp);
         return;
     }
 
@@ -151,7 +152,7 @@ where
     K: RadixKey,
 {
     if arr.len() <= 128 {
-        arr.sort_unstable_by(|a, b| a.partial_cmp(b).unwrap());
+        arr.sort_unstable_by(key_cmp);
         return;
     }
 
@@ -0,0 +1,181 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Condvar, Mutex};
+
+// A small work queue on top of `std::thread::scope`, used by the multithread
+// sorts when rayon is not available.
+// A sort opens one scope with `with_work_queue`: its threads take the jobs
+// from a shared stack until the sort is done, so the same threads run all the
+// parallel phases of the sort. A job, or the sort itself, can wait for a
+// group of jobs with `join_all`, or for all the jobs with `wait_all`:
+// meanwhile, the waiting thread runs the queued jobs itself, so no thread is
+// idle and there is no deadlock.
+
+pub type Job<'a> = Box<dyn FnOnce(&WorkQueue<'a>) + Send + 'a>;
+
+struct State<'a> {
+    jobs: Vec<(Job<'a>, Option<Arc<AtomicUsize>>)>,
+    running: usize,
+    closed: bool,
+}
+
+pub struct WorkQueue<'a> {
+    state: Mutex<State<'a>>,
+    condvar: Condvar,
+}
+
+// Marks the end of a job, even if the job panics. Otherwise, the other
+// threads would wait for it forever.
+struct JobGuard<'q, 'a> {
+    queue: &'q WorkQueue<'a>,
+    group: Option<Arc<AtomicUsize>>,
+}
+
+impl<'q, 'a> Drop for JobGuard<'q, 'a> {
+    fn drop(&mut self) {
+        if let Some(group) = &self.group {
+            group.fetch_sub(1, Ordering::AcqRel);
+        }
+        let mut state = self.queue.state.lock().unwrap();
+        state.running -= 1;
+        self.queue.condvar.notify_all();
+    }
+}
+
+impl<'a> WorkQueue<'a> {
+    fn new() -> Self {
+        WorkQueue {
+            state: Mutex::new(State {
+                jobs: Vec::new(),
+                running: 0,
+                closed: false,
+            }),
+            condvar: Condvar::new(),
+        }
+    }
+
+    /// Push a job, run by the first available thread.
+    pub fn push<F>(&self, job: F)
+    where
+        F: FnOnce(&WorkQueue<'a>) + Send + 'a,
+    {
+        self.push_job(Box::new(job), None);
+    }
+
+    fn push_job(&self, job: Job<'a>, group: Option<Arc<AtomicUsize>>) {
+        let mut state = self.state.lock().unwrap();
+        state.jobs.push((job, group));
+        self.condvar.notify_one();
+    }
+
+    fn run_job(&self, (job, group): (Job<'a>, Option<Arc<AtomicUsize>>)) {
+        let _guard = JobGuard { queue: self, group };
+        job(self);
+    }
+
+    /// Run all the `jobs` and wait for them. The jobs can push other jobs,
+    /// which are not waited for.
+    pub fn join_all(&self, jobs: Vec<Job<'a>>) {
+        let counter = Arc::new(AtomicUsize::new(jobs.len()));
+        for job in jobs.into_iter() {
+            self.push_job(job, Some(counter.clone()));
+        }
+
+        let mut state = self.state.lock().unwrap();
+        while counter.load(Ordering::Acquire) > 0 {
+            if let Some(job) = state.jobs.pop() {
+                state.running += 1;
+                drop(state);
+                self.run_job(job);
+                state = self.state.lock().unwrap();
+            } else {
+                state = self.condvar.wait(state).unwrap();
+            }
+        }
+    }
+
+    /// Run the queued jobs, and the jobs they push, until all of them are
+    /// done.
+    pub fn wait_all(&self) {
+        let mut state = self.state.lock().unwrap();
+        loop {
+            if let Some(job) = state.jobs.pop() {
+                state.running += 1;
+                drop(state);
+                self.run_job(job);
+                state = self.state.lock().unwrap();
+            } else if state.running == 0 {
+                return;
+            } else {
+                state = self.condvar.wait(state).unwrap();
+            }
+        }
+    }
+
+    // Loop of the threads of the scope, until the queue is closed.
+    fn work(&self) {
+        let mut state = self.state.lock().unwrap();
+        loop {
+            if let Some(job) = state.jobs.pop() {
+                state.running += 1;
+                drop(state);
+                self.run_job(job);
+                state = self.state.lock().unwrap();
+            } else if state.closed {
+                return;
+            } else {
+                state = self.condvar.wait(state).unwrap();
+            }
+        }
+    }
+
+    fn close(&self) {
+        let mut state = self.state.lock().unwrap();
+        state.closed = true;
+        self.condvar.notify_all();
+    }
+}
+
+// Closes the queue at the end of the sort, even if the sort panics.
+// Otherwise, the scope would wait for its threads forever.
+struct CloseGuard<'q, 'a> {
+    queue: &'q WorkQueue<'a>,
+}
+
+impl<'q, 'a> Drop for CloseGuard<'q, 'a> {
+    fn drop(&mut self) { self.queue.close(); }
+}
+
+// The jobs of the work queue live as long as the whole sort, but each one
+// sorts its own part of the array. As in the rayon version, the parts are
+// disjoint, so they are given to the jobs as raw pointers.
+pub unsafe fn part_mut<'a, T>(ptr: usize, len: usize) -> &'a mut [T] {
+    std::slice::from_raw_parts_mut(ptr as *mut T, len)
+}
+
+// Number of threads to use: `thread_n`, or all the available parallelism if
+// `thread_n` is 0.
+pub fn thread_count(thread_n: usize) -> usize {
+    if thread_n == 0 {
+        std::thread::available_parallelism().map_or(1, |n| n.get())
+    } else {
+        thread_n
+    }
+}
+
+// Runs `f` with a work queue served by `thread_n` scoped threads (see
+// `thread_count`), the calling thread included. The threads stop once `f`
+// returns.
+pub fn with_work_queue<'a, F, R>(thread_n: usize, f: F) -> R
+where
+    F: FnOnce(&WorkQueue<'a>) -> R,
+{
+    let queue = WorkQueue::new();
+
+    std::thread::scope(|s| {
+        for _ in 1..thread_count(thread_n) {
+            s.spawn(|| queue.work());
+        }
+        let _guard = CloseGuard { queue: &queue };
+        f(&queue)
+    })
+}
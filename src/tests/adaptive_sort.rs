@@ -0,0 +1,127 @@
+use rand::{thread_rng, Rng};
+
+use super::super::algo::verge_sort_heuristic::compute_big_enough_run;
+use super::super::sorts::adaptive_sort::{
+    find_runs, voracious_mt_sort_adaptive, voracious_sort_adaptive, Run,
+};
+use super::super::types::custom::StructU64;
+use super::super::{RadixKey, Radixable};
+
+// The presorted inputs: sorted, reverse sorted, sorted arrays concatenated,
+// a sorted array with a random tail, and a random array.
+fn helper_presorted<T: Copy>(
+    arr: Vec<T>,
+    sort: &dyn Fn(&mut [T]),
+) -> Vec<Vec<T>> {
+    let size = arr.len();
+    let mut sorted = arr.to_vec();
+    sort(&mut sorted);
+    let mut reversed = sorted.to_vec();
+    reversed.reverse();
+    let mut concatenated = arr.to_vec();
+    for chunk in concatenated.chunks_mut(size / 3 + 1) {
+        sort(chunk);
+    }
+    let mut appended = arr.to_vec();
+    sort(&mut appended[..size - size / 50]);
+
+    vec![sorted, reversed, concatenated, appended, arr]
+}
+
+fn helper_adaptive<T, K>(arr: Vec<T>)
+where
+    T: Radixable<K> + std::fmt::Debug,
+    K: RadixKey,
+{
+    let key_sort = |a: &mut [T]| a.sort_by_key(|e| e.into_key_type());
+    for input in helper_presorted(arr, &key_sort).into_iter() {
+        let mut check = input.to_vec();
+        key_sort(&mut check);
+        let check: Vec<_> = check.iter().map(|e| e.into_key_type()).collect();
+
+        let mut adaptive = input.to_vec();
+        voracious_sort_adaptive(&mut adaptive);
+        let keys: Vec<_> = adaptive.iter().map(|e| e.into_key_type()).collect();
+        assert!(keys == check);
+
+        let mut mt = input.to_vec();
+        voracious_mt_sort_adaptive(&mut mt, 4);
+        let keys: Vec<_> = mt.iter().map(|e| e.into_key_type()).collect();
+        assert!(keys == check);
+    }
+}
+
+// The runs are sorted, in order and apart from each other.
+fn check_runs<T, K>(arr: &[T], runs: &[Run])
+where
+    T: Radixable<K>,
+    K: RadixKey,
+{
+    assert!(runs.windows(2).all(|w| w[0].end <= w[1].start));
+    for run in runs.iter() {
+        assert!(run.len() >= compute_big_enough_run(arr.len()));
+        assert!(arr[run.start..run.end].windows(2).all(|w| {
+            let (a, b) = (w[0].into_key_type(), w[1].into_key_type());
+            if run.descending {
+                a >= b
+            } else {
+                a <= b
+            }
+        }));
+    }
+}
+
+#[test]
+fn test_adaptive_sort_find_runs() {
+    let empty: Vec<f64> = Vec::new();
+    assert!(find_runs(&empty).is_empty());
+    assert!(find_runs(&[1u32]).is_empty());
+
+    let arr: Vec<u32> = (0..1000).chain((0..500).rev()).collect();
+    assert_eq!(find_runs(&arr), vec![
+        Run { start: 0, end: 1000, descending: false },
+        Run { start: 1000, end: 1500, descending: true },
+    ]);
+
+    let mut rng = thread_rng();
+    let arr: Vec<i64> = (0..10_000).map(|_| rng.gen()).collect();
+    assert!(find_runs(&arr).is_empty());
+
+    let mut arr: Vec<i64> = (0..10_100).map(|_| rng.gen()).collect();
+    arr[..10_000].sort_unstable();
+    let runs = find_runs(&arr);
+    check_runs(&arr, &runs);
+    assert_eq!(runs.len(), 1);
+    assert_eq!(runs[0].start, 0);
+    assert!(runs[0].end >= 10_000 && !runs[0].descending);
+
+    let mut arr: Vec<u16> = (0..30_000).map(|_| rng.gen()).collect();
+    for chunk in arr.chunks_mut(10_000) {
+        chunk.sort_unstable();
+    }
+    arr[10_000..20_000].reverse();
+    let runs = find_runs(&arr);
+    check_runs(&arr, &runs);
+    assert_eq!(runs.len(), 3);
+    assert!(runs[1].descending);
+}
+
+#[test]
+fn test_adaptive_sort_presorted() {
+    let mut rng = thread_rng();
+    for size in [50, 1_000, 100_000, 500_000].iter() {
+        helper_adaptive((0..*size).map(|_| rng.gen::<u32>()).collect());
+        helper_adaptive((0..*size).map(|_| rng.gen::<u8>()).collect());
+        helper_adaptive((0..*size).map(|_| rng.gen::<f64>() - 0.5).collect());
+        helper_adaptive(
+            (0..*size)
+                .map(|_| (rng.gen_range(0, 16u8), rng.gen::<i32>()))
+                .collect(),
+        );
+        helper_adaptive(
+            (0..*size)
+                .map(|i| StructU64 { value: rng.gen(), other: i as isize })
+                .collect(),
+        );
+    }
+}
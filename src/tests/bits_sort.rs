@@ -0,0 +1,169 @@
+use rand::{thread_rng, Rng};
+
+use super::super::{
+    voracious_sort_with_memory_limit, Dispatcher, RadixKey, RadixSort,
+    Radixable,
+};
+
+// Key types defined as a downstream crate would: a `RadixKey` implementation
+// and an empty `Dispatcher` implementation.
+
+// 48 bits identifier.
+#[derive(Copy, Clone, Debug, PartialEq, PartialOrd)]
+struct Id48(u64);
+
+impl RadixKey for Id48 {
+    type Key = u64;
+    #[inline]
+    fn into_keytype(&self) -> Self::Key { self.0 }
+    #[inline]
+    fn type_size(&self) -> usize { 48 }
+    #[inline]
+    fn usize_to_keytype(&self, item: usize) -> Self::Key { item as u64 }
+    #[inline]
+    fn keytype_to_usize(&self, item: Self::Key) -> usize { item as usize }
+    #[inline]
+    fn default_key(&self) -> Self::Key { 0 }
+    #[inline]
+    fn one(&self) -> Self::Key { 1 }
+}
+impl<T: Radixable<Id48>> Dispatcher<T, Id48> for Id48 {}
+impl Radixable<Id48> for Id48 {
+    type Key = Id48;
+    #[inline]
+    fn key(&self) -> Self::Key { *self }
+}
+
+// 96 bits timestamp: seconds and nanoseconds.
+#[derive(Copy, Clone, Debug, PartialEq, PartialOrd)]
+struct Timestamp {
+    secs: i64,
+    nanos: u32,
+}
+
+impl RadixKey for Timestamp {
+    type Key = u128;
+    #[inline]
+    fn into_keytype(&self) -> Self::Key {
+        let secs = (self.secs as u64) ^ 0x8000_0000_0000_0000;
+        ((secs as u128) << 32) | self.nanos as u128
+    }
+    #[inline]
+    fn type_size(&self) -> usize { 96 }
+    #[inline]
+    fn usize_to_keytype(&self, item: usize) -> Self::Key { item as u128 }
+    #[inline]
+    fn keytype_to_usize(&self, item: Self::Key) -> usize { item as usize }
+    #[inline]
+    fn default_key(&self) -> Self::Key { 0 }
+    #[inline]
+    fn one(&self) -> Self::Key { 1 }
+}
+impl<T: Radixable<Timestamp>> Dispatcher<T, Timestamp> for Timestamp {}
+
+#[derive(Copy, Clone, Debug)]
+struct Event {
+    at: Timestamp,
+    id: usize,
+}
+impl PartialOrd for Event {
+    fn partial_cmp(&self, other: &Event) -> Option<core::cmp::Ordering> {
+        self.at.partial_cmp(&other.at)
+    }
+}
+impl PartialEq for Event {
+    fn eq(&self, other: &Self) -> bool { self.at == other.at }
+}
+impl Radixable<Timestamp> for Event {
+    type Key = Timestamp;
+    #[inline]
+    fn key(&self) -> Self::Key { self.at }
+}
+
+fn helper_ids(size: usize) -> Vec<Id48> {
+    let mut rng = thread_rng();
+    (0..size).map(|_| Id48(rng.gen::<u64>() >> 16)).collect()
+}
+
+fn helper_events(size: usize) -> Vec<Event> {
+    let mut rng = thread_rng();
+    (0..size)
+        .map(|id| Event {
+            at: Timestamp {
+                secs: rng.gen_range(-1000, 1000),
+                nanos: rng.gen_range(0, 1_000_000_000),
+            },
+            id,
+        })
+        .collect()
+}
+
+const SIZES: [usize; 5] = [0, 1, 200, 10_000, 300_000];
+
+#[test]
+fn test_bits_sort_id48() {
+    for size in SIZES.iter() {
+        let arr = helper_ids(*size);
+        let mut check = arr.to_vec();
+        check.sort_by_key(|id| id.0);
+
+        let mut unstable = arr.to_vec();
+        unstable.voracious_sort();
+        assert_eq!(unstable, check);
+
+        let mut stable = arr.to_vec();
+        stable.voracious_stable_sort();
+        assert_eq!(stable, check);
+
+        let mut mt = arr.to_vec();
+        mt.voracious_mt_sort(4);
+        assert_eq!(mt, check);
+
+        // The default scratch bound and in place sort of `Dispatcher`.
+        for bytes in [0, 100_000, usize::MAX].iter() {
+            let mut limited = arr.to_vec();
+            voracious_sort_with_memory_limit(&mut limited, *bytes);
+            assert_eq!(limited, check);
+        }
+
+        check.reverse();
+        let mut desc = arr.to_vec();
+        desc.voracious_sort_desc();
+        assert_eq!(desc, check);
+
+        let mut mt_desc = arr.to_vec();
+        mt_desc.voracious_mt_sort_desc(4);
+        assert_eq!(mt_desc, check);
+    }
+}
+
+#[test]
+fn test_bits_sort_timestamp() {
+    for size in SIZES.iter() {
+        let arr = helper_events(*size);
+        let mut check = arr.to_vec();
+        check.sort_by_key(|e| (e.at.secs, e.at.nanos, e.id));
+
+        let mut unstable = arr.to_vec();
+        unstable.voracious_sort();
+        assert_eq!(unstable, check);
+
+        let mut mt = arr.to_vec();
+        mt.voracious_mt_sort(4);
+        assert_eq!(mt, check);
+
+        // The stable sorts keep the order of the ids.
+        let mut stable = arr.to_vec();
+        stable.voracious_stable_sort();
+        let ids: Vec<usize> = stable.iter().map(|e| e.id).collect();
+        let check_ids: Vec<usize> = check.iter().map(|e| e.id).collect();
+        assert_eq!(ids, check_ids);
+
+        check.sort_by_key(|e| (-e.at.secs, !e.at.nanos, e.id));
+        let mut stable_desc = arr.to_vec();
+        stable_desc.voracious_stable_sort_desc();
+        let ids: Vec<usize> = stable_desc.iter().map(|e| e.id).collect();
+        let check_ids: Vec<usize> = check.iter().map(|e| e.id).collect();
+        assert_eq!(ids, check_ids);
+    }
+}
@@ -1,5 +1,10 @@
-use super::super::sorts::comparative_sort::insertion_sort_try;
+use rand::{thread_rng, Rng};
+
+use super::super::sorts::comparative_sort::{
+    in_place_merge_sort, insertion_sort, insertion_sort_try, merge_sort,
+};
 use super::super::sorts::utils::Params;
+use super::super::{RadixSort, Radixable};
 
 #[test]
 fn test_comparative_sort_insertion_sort_try() {
@@ -91,3 +96,75 @@ fn test_comparative_sort_insertion_sort_try() {
     assert_eq!(unsorted_parts[0].0, 1);
     assert_eq!(unsorted_parts[0].1, 79);
 }
+
+// No `PartialOrd`: the elements are only compared by their key, whatever the
+// size of the array.
+#[derive(Copy, Clone, Debug)]
+struct KeyOnly {
+    key: f64,
+    id: usize,
+}
+impl Radixable<f64> for KeyOnly {
+    type Key = f64;
+    #[inline]
+    fn key(&self) -> Self::Key { self.key }
+}
+
+#[test]
+fn test_comparative_sort_fallback_by_key() {
+    let mut rng = thread_rng();
+    for size in [0, 1, 20, 150, 1000, 50_000].iter() {
+        let arr: Vec<KeyOnly> = (0..*size)
+            .map(|id| {
+                let key = match id % 7 {
+                    0 => f64::NAN,
+                    1 => -0.0,
+                    2 => 0.0,
+                    _ => rng.gen::<f64>() - 0.5,
+                };
+                KeyOnly { key, id }
+            })
+            .collect();
+        let mut check = arr.to_vec();
+        check.sort_by(|a, b| a.key.total_cmp(&b.key).then(a.id.cmp(&b.id)));
+        let check: Vec<usize> = check.iter().map(|e| e.id).collect();
+        let ids = |arr: &[KeyOnly]| -> Vec<usize> {
+            arr.iter().map(|e| e.id).collect()
+        };
+
+        let mut stable = arr.to_vec();
+        stable.voracious_stable_sort();
+        assert_eq!(ids(&stable), check);
+
+        let mut merge = arr.to_vec();
+        merge_sort(&mut merge);
+        assert_eq!(ids(&merge), check);
+
+        let mut insertion = arr.to_vec();
+        insertion_sort(&mut insertion);
+        assert_eq!(ids(&insertion), check);
+
+        let mut in_place = arr.to_vec();
+        in_place_merge_sort(&mut in_place);
+        assert_eq!(ids(&in_place), check);
+
+        let mut reversed = arr.to_vec();
+        reversed.sort_by(|a, b| b.key.total_cmp(&a.key).then(a.id.cmp(&b.id)));
+        in_place_merge_sort(&mut reversed);
+        assert_eq!(ids(&reversed), check);
+
+        let mut unstable = arr.to_vec();
+        unstable.voracious_sort();
+        let keys: Vec<u64> = unstable.iter().map(|e| e.key.to_bits()).collect();
+        let mut check_keys = arr.to_vec();
+        check_keys.sort_by(|a, b| a.key.total_cmp(&b.key));
+        let check_keys: Vec<u64> =
+            check_keys.iter().map(|e| e.key.to_bits()).collect();
+        assert_eq!(keys, check_keys);
+
+        let mut mt = arr.to_vec();
+        mt.voracious_mt_sort(4);
+        let keys: Vec<u64> = mt.iter().map(|e| e.key.to_bits()).collect();
+        assert_eq!(keys, check_keys);
+    }
+}
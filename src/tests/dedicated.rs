@@ -1,30 +1,79 @@
 use super::super::dedicated::cs_u16::cs_u16;
 use super::super::dedicated::lsd_f32::lsd_f32;
+use super::super::dedicated::lsd_f64::lsd_f64;
+use super::super::dedicated::lsd_i32::lsd_i32;
+use super::super::dedicated::lsd_i64::lsd_i64;
+use super::super::dedicated::lsd_u128::lsd_u128;
 use super::super::dedicated::lsd_u32::lsd_u32;
+use super::super::dedicated::lsd_u64::lsd_u64;
 
 use super::super::generators::float_32::*;
+use super::super::generators::float_64::*;
+use super::super::generators::signed_i32::*;
+use super::super::generators::signed_i64::*;
+use super::super::generators::unsigned_u128::*;
 use super::super::generators::unsigned_u16::*;
 use super::super::generators::unsigned_u32::*;
+use super::super::generators::unsigned_u64::*;
 
 use super::sorts::helper_sort;
 
+// Around the small array sort (128 elements) and the SIMD histograms
+// threshold (4096 elements) of the kernels.
+const SIZES: [usize; 8] = [0, 1, 128, 129, 4095, 4096, 4097, 100_000];
+
 #[test]
 fn test_ded_sort_lsd_f32() {
-    for size in [0, 1, 100_000].iter() {
+    for size in SIZES.iter() {
         helper_sort(false, &|a| lsd_f32(a), generators_f32(), *size);
     }
 }
 
+#[test]
+fn test_ded_sort_lsd_f64() {
+    for size in SIZES.iter() {
+        helper_sort(false, &|a| lsd_f64(a), generators_f64(), *size);
+    }
+}
+
+#[test]
+fn test_ded_sort_lsd_i32() {
+    for size in SIZES.iter() {
+        helper_sort(false, &|a| lsd_i32(a), generators_i32(), *size);
+    }
+}
+
+#[test]
+fn test_ded_sort_lsd_i64() {
+    for size in SIZES.iter() {
+        helper_sort(false, &|a| lsd_i64(a), generators_i64(), *size);
+    }
+}
+
 #[test]
 fn test_ded_sort_lsd_u32() {
-    for size in [0, 1, 100_000].iter() {
+    for size in SIZES.iter() {
         helper_sort(false, &|a| lsd_u32(a), generators_u32(), *size);
     }
 }
 
+#[test]
+fn test_ded_sort_lsd_u64() {
+    for size in SIZES.iter() {
+        helper_sort(false, &|a| lsd_u64(a), generators_u64(), *size);
+    }
+}
+
+#[test]
+fn test_ded_sort_lsd_u128() {
+    for size in SIZES.iter() {
+        helper_sort(false, &|a| lsd_u128(a), generators_u128(), *size);
+    }
+}
+
 #[test]
 fn test_ded_sort_counting_sort_u16() {
-    for size in [0, 1, 100_000].iter() {
+    for size in SIZES.iter() {
         helper_sort(false, &|a| cs_u16(a), generators_u16(), *size);
     }
 }
@@ -0,0 +1,151 @@
+use rand::{thread_rng, Rng};
+
+use super::super::sorts::float_sort::{
+    voracious_mt_sort_floats, voracious_sort_floats,
+    voracious_stable_sort_floats, FloatKey, FloatOrder, NanPolicy,
+};
+use super::super::sorts::try_sort::SortError;
+use super::super::types::custom::StructF64;
+use super::super::{RadixKey, RadixSort};
+
+fn helper_special_f64(size: usize) -> Vec<f64> {
+    let specials = [
+        f64::NAN,
+        -f64::NAN,
+        f64::from_bits(0x7ff0_0000_0000_0042),
+        f64::INFINITY,
+        f64::NEG_INFINITY,
+        0.0,
+        -0.0,
+        f64::MIN_POSITIVE,
+        f64::MAX,
+    ];
+    (0..size)
+        .map(|_| {
+            if thread_rng().gen_range(0, 4) == 0 {
+                specials[thread_rng().gen_range(0, specials.len())]
+            } else {
+                thread_rng().gen_range(-1000.0, 1000.0)
+            }
+        })
+        .collect()
+}
+
+fn orders() -> Vec<FloatOrder> {
+    let mut orders = Vec::new();
+    for nan in [NanPolicy::First, NanPolicy::Last, NanPolicy::TotalOrder].iter()
+    {
+        orders.push(FloatOrder::new(*nan));
+        orders.push(FloatOrder::new(*nan).with_equal_zeros(true));
+    }
+    orders
+}
+
+// The keys of `arr`, in the order of `order`.
+fn helper_check<K: FloatKey>(arr: &[K], check: &[K], order: &FloatOrder) {
+    let mut check: Vec<_> = check
+        .iter()
+        .map(|key| key.ordered_bits(order).into_keytype())
+        .collect();
+    check.sort();
+    let keys: Vec<_> = arr
+        .iter()
+        .map(|key| key.ordered_bits(order).into_keytype())
+        .collect();
+    assert!(keys == check);
+}
+
+#[test]
+fn test_float_sort_f64() {
+    for size in [0, 1, 50, 1000, 300_000].iter() {
+        let arr = helper_special_f64(*size);
+        for order in orders().iter() {
+            let mut unstable = arr.to_vec();
+            voracious_sort_floats(&mut unstable, *order).unwrap();
+            helper_check(&unstable, &arr, order);
+
+            let mut stable = arr.to_vec();
+            voracious_stable_sort_floats(&mut stable, *order).unwrap();
+            helper_check(&stable, &arr, order);
+
+            let mut mt = arr.to_vec();
+            voracious_mt_sort_floats(&mut mt, *order, 4).unwrap();
+            helper_check(&mt, &arr, order);
+        }
+
+        // The total order is the order of `total_cmp`, bit for bit.
+        let mut total = arr.to_vec();
+        voracious_sort_floats(&mut total, FloatOrder::default()).unwrap();
+        let mut check = arr.to_vec();
+        check.sort_by(|a, b| a.total_cmp(b));
+        let total: Vec<u64> = total.iter().map(|x| x.to_bits()).collect();
+        let check: Vec<u64> = check.iter().map(|x| x.to_bits()).collect();
+        assert_eq!(total, check);
+    }
+}
+
+#[test]
+fn test_float_sort_f32() {
+    for size in [0, 1, 50, 1000, 300_000].iter() {
+        let arr: Vec<f32> =
+            helper_special_f64(*size).iter().map(|x| *x as f32).collect();
+        for order in orders().iter() {
+            let mut unstable = arr.to_vec();
+            voracious_sort_floats(&mut unstable, *order).unwrap();
+            helper_check(&unstable, &arr, order);
+
+            let mut stable = arr.to_vec();
+            voracious_stable_sort_floats(&mut stable, *order).unwrap();
+            helper_check(&stable, &arr, order);
+        }
+    }
+}
+
+#[test]
+fn test_float_sort_struct_stable() {
+    let arr: Vec<StructF64> = helper_special_f64(10_000)
+        .iter()
+        .enumerate()
+        .map(|(i, value)| StructF64 { value: *value, other: i as isize })
+        .collect();
+    let order = FloatOrder::new(NanPolicy::Last).with_equal_zeros(true);
+    let mut sorted = arr.to_vec();
+    voracious_stable_sort_floats(&mut sorted, order).unwrap();
+
+    let mut check = arr.to_vec();
+    check.sort_by_key(|item| item.value.ordered_bits(&order));
+    let sorted: Vec<isize> = sorted.iter().map(|item| item.other).collect();
+    let check: Vec<isize> = check.iter().map(|item| item.other).collect();
+    assert_eq!(sorted, check);
+}
+
+#[test]
+fn test_float_sort_nan_error() {
+    let order = FloatOrder::new(NanPolicy::Error);
+    let mut arr = vec![3.0, -1.0, f64::NAN, 2.0];
+    assert_eq!(voracious_sort_floats(&mut arr, order), Err(SortError::NanKey));
+    assert_eq!(arr[..2], [3.0, -1.0]);
+    assert_eq!(
+        voracious_stable_sort_floats(&mut arr, order),
+        Err(SortError::NanKey)
+    );
+
+    let mut arr = vec![3.0, -1.0, 0.0, 2.0];
+    voracious_sort_floats(&mut arr, order).unwrap();
+    assert_eq!(arr, vec![-1.0, 0.0, 2.0, 3.0]);
+}
+
+#[test]
+fn test_float_sort_small_fallback() {
+    // The comparison fallbacks use the radix order: no panic with NaN.
+    let mut arr = vec![1.0f32, f32::NAN, -0.0, 0.0, f32::NEG_INFINITY];
+    arr.voracious_sort();
+    assert_eq!(arr[..4], [f32::NEG_INFINITY, -0.0, 0.0, 1.0]);
+    assert!(arr[1].is_sign_negative());
+    assert!(arr[4].is_nan());
+
+    let mut arr = vec![1.0f64, -f64::NAN, 0.0, -0.0];
+    arr.voracious_stable_sort();
+    assert!(arr[0].is_nan());
+    assert!(arr[1].is_sign_negative() && arr[2].is_sign_positive());
+}
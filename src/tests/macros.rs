@@ -0,0 +1,136 @@
+use rand::{thread_rng, Rng};
+
+use super::super::RadixSort;
+
+#[derive(Copy, Clone, Debug)]
+struct Inner {
+    weight: f64,
+}
+
+#[derive(Copy, Clone, Debug)]
+struct Record {
+    id: u32,
+    inner: Inner,
+    day: u16,
+}
+radixable!(Record, f64, inner.weight);
+
+#[derive(Copy, Clone, Debug)]
+struct Job {
+    day: u16,
+    priority: i32,
+    id: usize,
+}
+radixable!(Job, (u16, i32), (day, priority));
+
+#[derive(Copy, Clone, Debug)]
+struct Span {
+    start: u32,
+    end: u32,
+}
+radixable!(Span, u32, |span| span.end - span.start);
+
+#[derive(Copy, Clone, Debug)]
+struct Wrapper(i64, u8);
+radixable!(Wrapper, i64, 0);
+
+#[derive(Copy, Clone, Debug)]
+enum Level {
+    Debug = -1,
+    Info = 0,
+    Warning = 5,
+    Error = 10,
+}
+radixable!(enum Level, i8);
+
+#[test]
+fn test_macros_field_path() {
+    let mut rng = thread_rng();
+    let mut arr: Vec<Record> = (0..10_000)
+        .map(|i| Record {
+            id: i,
+            inner: Inner { weight: rng.gen::<f64>() - 0.5 },
+            day: 0,
+        })
+        .collect();
+    arr[0].inner.weight = -0.0;
+    arr[1].inner.weight = 0.0;
+    arr[2].inner.weight = f64::NAN;
+
+    let mut check = arr.to_vec();
+    check.sort_by(|a, b| a.inner.weight.total_cmp(&b.inner.weight));
+
+    // The generated `PartialOrd` agrees with the radix order, NaN included.
+    let mut small = arr[..100].to_vec();
+    small.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    assert!(small.windows(2).all(|w| w[0] <= w[1]));
+
+    arr.voracious_sort();
+    let bits = |arr: &[Record]| -> Vec<u64> {
+        arr.iter().map(|r| r.inner.weight.to_bits()).collect()
+    };
+    assert_eq!(bits(&arr), bits(&check));
+    assert_eq!(arr[0].day, 0);
+    assert!(arr.iter().any(|r| r.id == 2));
+}
+
+#[test]
+fn test_macros_composite_key() {
+    let mut rng = thread_rng();
+    let arr: Vec<Job> = (0..10_000)
+        .map(|id| Job {
+            day: rng.gen_range(0, 30),
+            priority: rng.gen_range(-100, 100),
+            id,
+        })
+        .collect();
+
+    let mut check = arr.to_vec();
+    check.sort_by_key(|job| (job.day, job.priority));
+    let mut stable = arr.to_vec();
+    stable.voracious_stable_sort();
+    let ids: Vec<usize> = stable.iter().map(|job| job.id).collect();
+    let check_ids: Vec<usize> = check.iter().map(|job| job.id).collect();
+    assert_eq!(ids, check_ids);
+}
+
+#[test]
+fn test_macros_key_expression() {
+    let mut rng = thread_rng();
+    let mut arr: Vec<Span> = (0..1_000)
+        .map(|_| {
+            let start = rng.gen_range(0, 1_000_000);
+            Span { start, end: start + rng.gen_range(0, 1_000) }
+        })
+        .collect();
+    arr.voracious_sort();
+    assert!(arr.windows(2).all(|w| {
+        w[0].end - w[0].start <= w[1].end - w[1].start
+    }));
+
+    let mut wrappers = vec![Wrapper(3, 0), Wrapper(-7, 1), Wrapper(0, 2)];
+    wrappers.voracious_sort();
+    let keys: Vec<i64> = wrappers.iter().map(|w| w.0).collect();
+    assert_eq!(keys, vec![-7, 0, 3]);
+    assert_eq!(wrappers[0].1, 1);
+}
+
+#[test]
+fn test_macros_enum() {
+    let mut arr = vec![
+        Level::Error,
+        Level::Info,
+        Level::Debug,
+        Level::Warning,
+        Level::Info,
+    ];
+    arr.voracious_sort();
+    assert_eq!(arr, vec![
+        Level::Debug,
+        Level::Info,
+        Level::Info,
+        Level::Warning,
+        Level::Error,
+    ]);
+    assert!(Level::Debug < Level::Error);
+}
@@ -1,10 +1,16 @@
+mod adaptive_sort;
+mod bits_sort;
 mod comparative_sort;
 mod dedicated;
+mod float_sort;
+mod macros;
 mod regions_graph;
+mod select_sort;
 #[rustfmt::skip] mod sorts;
 mod types;
 mod utils;
 mod utils_mt;
+mod utils_simd;
 mod verge_sort_heuristic;
 
 // mod test_helpers;
@@ -0,0 +1,85 @@
+use super::super::sorts::select_mt_sort::{
+    voracious_mt_partial_sort, voracious_mt_select_nth_unstable,
+    voracious_mt_top_k,
+};
+use super::super::sorts::select_sort::{
+    voracious_partial_sort, voracious_select_nth_unstable, voracious_top_k,
+};
+use super::super::{RadixKey, Radixable};
+
+use super::super::generators::custom::*;
+use super::super::generators::float_64::*;
+use super::super::generators::signed_i64::*;
+use super::super::generators::unsigned_u32::*;
+use super::super::generators::unsigned_u64::*;
+
+fn helper_select<T, K>(
+    generators: Vec<(&dyn Fn(usize) -> Vec<T>, &'static str)>,
+    thread_n: usize,
+) where
+    T: Radixable<K> + PartialOrd + std::fmt::Debug,
+    K: RadixKey,
+{
+    for size in [1, 200, 10_000, 300_000].iter() {
+        for (generator, _) in generators.iter() {
+            let arr = generator(*size);
+            let mut check = arr.to_vec();
+            check.sort_unstable_by(|a, b| a.partial_cmp(b).unwrap());
+
+            for k in [0, size / 3, size - 1].iter() {
+                let mut select = arr.to_vec();
+                if thread_n == 0 {
+                    voracious_select_nth_unstable(&mut select, *k);
+                } else {
+                    voracious_mt_select_nth_unstable(&mut select, *k, thread_n);
+                }
+                assert_eq!(select[*k], check[*k]);
+                assert!(select[..*k].iter().all(|x| *x <= select[*k]));
+                assert!(select[*k..].iter().all(|x| *x >= select[*k]));
+
+                let mut partial = arr.to_vec();
+                if thread_n == 0 {
+                    voracious_partial_sort(&mut partial, *k);
+                } else {
+                    voracious_mt_partial_sort(&mut partial, *k, thread_n);
+                }
+                assert_eq!(partial[..*k], check[..*k]);
+
+                let mut top = arr.to_vec();
+                let top_k = if thread_n == 0 {
+                    voracious_top_k(&mut top, *k).to_vec()
+                } else {
+                    voracious_mt_top_k(&mut top, *k, thread_n).to_vec()
+                };
+                let mut check_top = check[size - k..].to_vec();
+                check_top.reverse();
+                assert_eq!(top_k, check_top);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_select_sort() {
+    helper_select(generators_u32(), 0);
+    helper_select(generators_u64(), 0);
+    helper_select(generators_i64(), 0);
+    helper_select(generators_f64(), 0);
+    helper_select(generators_structu64(), 0);
+}
+
+#[test]
+fn test_select_mt_sort() {
+    helper_select(generators_u32(), 4);
+    helper_select(generators_u64(), 4);
+    helper_select(generators_i64(), 4);
+    helper_select(generators_f64(), 4);
+    helper_select(generators_structu64(), 4);
+}
+
+#[test]
+#[should_panic]
+fn test_select_out_of_bounds() {
+    let mut arr = vec![1u32, 2, 3];
+    voracious_select_nth_unstable(&mut arr, 3);
+}
@@ -1,20 +1,47 @@
 use rayon::prelude::*;
 
-use super::super::{RadixKey, RadixSort, Radixable};
+use super::super::{
+    RadixArgSort, RadixKey, RadixSort, RadixSortByKey, Radixable,
+    RadixableOwned,
+};
 
-use super::super::sorts::american_flag_sort::american_flag_sort;
+use super::super::sorts::american_flag_sort::{
+    american_flag_sort, try_american_flag_sort,
+};
 use super::super::sorts::boolean_sort::boolean_sort;
-use super::super::sorts::comparative_sort::insertion_sort;
+use super::super::sorts::comparative_sort::{insertion_sort, merge_sort};
 use super::super::sorts::counting_sort::counting_sort;
-use super::super::sorts::dlsd_sort::dlsd_radixsort;
+use super::super::sorts::dlsd_sort::{dlsd_radixsort, dlsd_radixsort_wc};
 use super::super::sorts::rollercoaster_sort::rollercoaster_sort;
-use super::super::sorts::lsd_sort::lsd_radixsort;
+use super::super::sorts::lsd_mt_sort::lsd_mt_radixsort;
+use super::super::sorts::lsd_sort::{lsd_radixsort, lsd_radixsort_wc};
 use super::super::sorts::lsd_stable_sort::lsd_stable_radixsort;
+use super::super::sorts::msd_mt_stable_sort::msd_mt_stable_radixsort;
 use super::super::sorts::msd_stable_sort::msd_stable_radixsort;
-use super::super::sorts::msd_sort::msd_radixsort;
+use super::super::sorts::msd_sort::{msd_radixsort, msd_radixsort_wc};
+use super::super::sorts::owned_sort::{
+    lsd_stable_radixsort_owned, msd_radixsort_owned, thiel_radixsort_owned,
+};
+use super::super::sorts::pairs_sort::{
+    lsd_stable_radixsort_pairs, rollercoaster_sort_pairs, voracious_sort_pairs,
+    voracious_stable_sort_pairs,
+};
 use super::super::sorts::peeka_sort::peeka_sort;
+#[cfg(feature = "voracious_multithread_std")]
+use super::super::sorts::peeka_std_sort::peeka_std_sort;
 use super::super::sorts::ska_sort::ska_sort;
+use super::super::sorts::sort_context::{
+    voracious_sort_with_buffer, SortContext,
+};
+#[cfg(feature = "voracious_multithread_std")]
+use super::super::sorts::std_mt_stable_sort::std_mt_stable_sort;
+use super::super::sorts::string_sort::{
+    string_mt_sort, string_sort, string_stable_sort,
+};
 use super::super::sorts::thiel_sort::thiel_radixsort;
+use super::super::sorts::try_sort::{
+    voracious_sort_with_memory_limit, SortError,
+};
 use super::super::sorts::voracious_sort::voracious_sort;
 
 use super::super::generators::boolean::*;
@@ -27,19 +54,23 @@ use super::super::generators::signed_i16::*;
 use super::super::generators::signed_i32::*;
 use super::super::generators::signed_i64::*;
 use super::super::generators::signed_i8::*;
+use super::super::generators::string::*;
+use super::super::generators::tuple::*;
 use super::super::generators::unsigned_u128::*;
 use super::super::generators::unsigned_u16::*;
 use super::super::generators::unsigned_u32::*;
 use super::super::generators::unsigned_u64::*;
 use super::super::generators::unsigned_u8::*;
 
+use super::super::types::custom::*;
+
 pub fn helper_sort_aux<T, K>(
     sort: &dyn Fn(&mut Vec<T>) -> (),
     generator: &dyn Fn(usize) -> Vec<T>,
     array_size: usize,
     stable: bool,
 ) where
-    T: Radixable<K> + std::fmt::Debug,
+    T: Radixable<K> + PartialOrd + std::fmt::Debug,
     K: RadixKey,
 {
     let mut array = generator(array_size);
@@ -59,7 +90,7 @@ pub fn helper_sort<T, K>(
     generators: Vec<(&dyn Fn(usize) -> Vec<T>, &'static str)>,
     array_size: usize,
 ) where
-    T: Radixable<K> + std::fmt::Debug,
+    T: Radixable<K> + PartialOrd + std::fmt::Debug,
     K: RadixKey,
 {
     generators.iter().for_each(|(generator, _gen_name)| {
@@ -68,6 +99,40 @@ pub fn helper_sort<T, K>(
     });
 }
 
+pub fn helper_sort_desc_aux<T, K>(
+    sort: &dyn Fn(&mut Vec<T>),
+    generator: &dyn Fn(usize) -> Vec<T>,
+    array_size: usize,
+    stable: bool,
+) where
+    T: Radixable<K> + PartialOrd + std::fmt::Debug,
+    K: RadixKey,
+{
+    let mut array = generator(array_size);
+    let mut check = array.to_vec();
+    sort(&mut array);
+    if stable {
+        check.par_sort_by(|a, b| b.partial_cmp(a).unwrap());
+    } else {
+        check.par_sort_unstable_by(|a, b| b.partial_cmp(a).unwrap());
+    }
+    assert_eq!(check, array);
+}
+
+pub fn helper_sort_desc<T, K>(
+    stable: bool,
+    sort: &dyn Fn(&mut Vec<T>),
+    generators: Vec<(&dyn Fn(usize) -> Vec<T>, &'static str)>,
+    array_size: usize,
+) where
+    T: Radixable<K> + PartialOrd + std::fmt::Debug,
+    K: RadixKey,
+{
+    generators.iter().for_each(|(generator, _gen_name)| {
+        helper_sort_desc_aux(sort, generator, array_size, stable);
+    });
+}
+
 #[test]
 fn test_sort_boolean_sort() {
     for size in [0, 1, 10_000].iter() {
@@ -124,6 +189,44 @@ fn test_sort_insertion_sort() {
     }
 }
 
+#[test]
+fn test_sort_merge_sort() {
+    for size in [0, 1, 33, 5_000, 100_000].iter() {
+        helper_sort(true, &|a| merge_sort(a), generators_bool(), *size);
+        helper_sort(true, &|a| merge_sort(a), generators_char(), *size);
+        helper_sort(true, &|a| merge_sort(a), generators_f32(), *size);
+        helper_sort(true, &|a| merge_sort(a), generators_f64(), *size);
+        helper_sort(true, &|a| merge_sort(a), generators_u8(), *size);
+        helper_sort(true, &|a| merge_sort(a), generators_u16(), *size);
+        helper_sort(true, &|a| merge_sort(a), generators_u32(), *size);
+        helper_sort(true, &|a| merge_sort(a), generators_u64(), *size);
+        helper_sort(true, &|a| merge_sort(a), generators_u128(), *size);
+        helper_sort(true, &|a| merge_sort(a), generators_usize(), *size);
+        helper_sort(true, &|a| merge_sort(a), generators_i8(), *size);
+        helper_sort(true, &|a| merge_sort(a), generators_i16(), *size);
+        helper_sort(true, &|a| merge_sort(a), generators_i32(), *size);
+        helper_sort(true, &|a| merge_sort(a), generators_i64(), *size);
+        helper_sort(true, &|a| merge_sort(a), generators_i128(), *size);
+        helper_sort(true, &|a| merge_sort(a), generators_isize(), *size);
+        helper_sort(true, &|a| merge_sort(a), generators_structbool(), *size);
+        helper_sort(true, &|a| merge_sort(a), generators_structchar(), *size);
+        helper_sort(true, &|a| merge_sort(a), generators_structf32(), *size);
+        helper_sort(true, &|a| merge_sort(a), generators_structf64(), *size);
+        helper_sort(true, &|a| merge_sort(a), generators_structu8(), *size);
+        helper_sort(true, &|a| merge_sort(a), generators_structu16(), *size);
+        helper_sort(true, &|a| merge_sort(a), generators_structu32(), *size);
+        helper_sort(true, &|a| merge_sort(a), generators_structu64(), *size);
+        helper_sort(true, &|a| merge_sort(a), generators_structu128(), *size);
+        helper_sort(true, &|a| merge_sort(a), generators_structusize(), *size);
+        helper_sort(true, &|a| merge_sort(a), generators_structi8(), *size);
+        helper_sort(true, &|a| merge_sort(a), generators_structi16(), *size);
+        helper_sort(true, &|a| merge_sort(a), generators_structi32(), *size);
+        helper_sort(true, &|a| merge_sort(a), generators_structi64(), *size);
+        helper_sort(true, &|a| merge_sort(a), generators_structi128(), *size);
+        helper_sort(true, &|a| merge_sort(a), generators_structisize(), *size);
+    }
+}
+
 #[test]
 fn test_sort_insertion_sort_f32_inf() {
     let mut a = helper_random_array_uniform_f32(2000);
@@ -245,6 +348,45 @@ fn test_sort_dlsd_radixsort() {
     } }
 }
 
+#[test]
+fn test_sort_dlsd_radixsort_wc() {
+    // This sort has a variable radix, so no need to check other radix.
+    for size in [0, 1, 200, 50_000, 100_000].iter() { for radix in [8].iter() {
+        helper_sort(false, &|a| dlsd_radixsort_wc(a, *radix), generators_bool(), *size);
+        helper_sort(false, &|a| dlsd_radixsort_wc(a, *radix), generators_char(), *size);
+        helper_sort(false, &|a| dlsd_radixsort_wc(a, *radix), generators_f32(), *size);
+        helper_sort(false, &|a| dlsd_radixsort_wc(a, *radix), generators_f64(), *size);
+        helper_sort(false, &|a| dlsd_radixsort_wc(a, *radix), generators_u8(), *size);
+        helper_sort(false, &|a| dlsd_radixsort_wc(a, *radix), generators_u16(), *size);
+        helper_sort(false, &|a| dlsd_radixsort_wc(a, *radix), generators_u32(), *size);
+        helper_sort(false, &|a| dlsd_radixsort_wc(a, *radix), generators_u64(), *size);
+        helper_sort(false, &|a| dlsd_radixsort_wc(a, *radix), generators_u128(), *size);
+        helper_sort(false, &|a| dlsd_radixsort_wc(a, *radix), generators_usize(), *size);
+        helper_sort(false, &|a| dlsd_radixsort_wc(a, *radix), generators_i8(), *size);
+        helper_sort(false, &|a| dlsd_radixsort_wc(a, *radix), generators_i16(), *size);
+        helper_sort(false, &|a| dlsd_radixsort_wc(a, *radix), generators_i32(), *size);
+        helper_sort(false, &|a| dlsd_radixsort_wc(a, *radix), generators_i64(), *size);
+        helper_sort(false, &|a| dlsd_radixsort_wc(a, *radix), generators_i128(), *size);
+        helper_sort(false, &|a| dlsd_radixsort_wc(a, *radix), generators_isize(), *size);
+        helper_sort(false, &|a| dlsd_radixsort_wc(a, *radix), generators_structbool(), *size);
+        helper_sort(false, &|a| dlsd_radixsort_wc(a, *radix), generators_structchar(), *size);
+        helper_sort(false, &|a| dlsd_radixsort_wc(a, *radix), generators_structf32(), *size);
+        helper_sort(false, &|a| dlsd_radixsort_wc(a, *radix), generators_structf64(), *size);
+        helper_sort(false, &|a| dlsd_radixsort_wc(a, *radix), generators_structu8(), *size);
+        helper_sort(false, &|a| dlsd_radixsort_wc(a, *radix), generators_structu16(), *size);
+        helper_sort(false, &|a| dlsd_radixsort_wc(a, *radix), generators_structu32(), *size);
+        helper_sort(false, &|a| dlsd_radixsort_wc(a, *radix), generators_structu64(), *size);
+        helper_sort(false, &|a| dlsd_radixsort_wc(a, *radix), generators_structu128(), *size);
+        helper_sort(false, &|a| dlsd_radixsort_wc(a, *radix), generators_structusize(), *size);
+        helper_sort(false, &|a| dlsd_radixsort_wc(a, *radix), generators_structi8(), *size);
+        helper_sort(false, &|a| dlsd_radixsort_wc(a, *radix), generators_structi16(), *size);
+        helper_sort(false, &|a| dlsd_radixsort_wc(a, *radix), generators_structi32(), *size);
+        helper_sort(false, &|a| dlsd_radixsort_wc(a, *radix), generators_structi64(), *size);
+        helper_sort(false, &|a| dlsd_radixsort_wc(a, *radix), generators_structi128(), *size);
+        helper_sort(false, &|a| dlsd_radixsort_wc(a, *radix), generators_structisize(), *size);
+    } }
+}
+
 #[test]
 fn test_sort_dlsd_radixsort_f32_inf() {
     let mut a = helper_random_array_uniform_f32(2000);
@@ -306,6 +448,45 @@ fn test_sort_lsd_radixsort() {
     } }
 }
 
+#[test]
+fn test_sort_lsd_radixsort_wc() {
+    // Can't sort 128bits key (because of the histogram)
+    for size in [0, 1, 200, 10_000].iter() { for radix in [8, 11].iter() {
+        helper_sort(false, &|a| lsd_radixsort_wc(a, *radix), generators_bool(), *size);
+        helper_sort(false, &|a| lsd_radixsort_wc(a, *radix), generators_char(), *size);
+        helper_sort(false, &|a| lsd_radixsort_wc(a, *radix), generators_f32(), *size);
+        helper_sort(false, &|a| lsd_radixsort_wc(a, *radix), generators_f64(), *size);
+        helper_sort(false, &|a| lsd_radixsort_wc(a, *radix), generators_u8(), *size);
+        helper_sort(false, &|a| lsd_radixsort_wc(a, *radix), generators_u16(), *size);
+        helper_sort(false, &|a| lsd_radixsort_wc(a, *radix), generators_u32(), *size);
+        helper_sort(false, &|a| lsd_radixsort_wc(a, *radix), generators_u64(), *size);
+        // helper_sort(false, &|a| lsd_radixsort_wc(a, *radix), generators_u128(), *size);
+        helper_sort(false, &|a| lsd_radixsort_wc(a, *radix), generators_usize(), *size);
+        helper_sort(false, &|a| lsd_radixsort_wc(a, *radix), generators_i8(), *size);
+        helper_sort(false, &|a| lsd_radixsort_wc(a, *radix), generators_i16(), *size);
+        helper_sort(false, &|a| lsd_radixsort_wc(a, *radix), generators_i32(), *size);
+        helper_sort(false, &|a| lsd_radixsort_wc(a, *radix), generators_i64(), *size);
+        // helper_sort(false, &|a| lsd_radixsort_wc(a, *radix), generators_i128(), *size);
+        helper_sort(false, &|a| lsd_radixsort_wc(a, *radix), generators_isize(), *size);
+        helper_sort(false, &|a| lsd_radixsort_wc(a, *radix), generators_structbool(), *size);
+        helper_sort(false, &|a| lsd_radixsort_wc(a, *radix), generators_structchar(), *size);
+        helper_sort(false, &|a| lsd_radixsort_wc(a, *radix), generators_structf32(), *size);
+        helper_sort(false, &|a| lsd_radixsort_wc(a, *radix), generators_structf64(), *size);
+        helper_sort(false, &|a| lsd_radixsort_wc(a, *radix), generators_structu8(), *size);
+        helper_sort(false, &|a| lsd_radixsort_wc(a, *radix), generators_structu16(), *size);
+        helper_sort(false, &|a| lsd_radixsort_wc(a, *radix), generators_structu32(), *size);
+        helper_sort(false, &|a| lsd_radixsort_wc(a, *radix), generators_structu64(), *size);
+        // helper_sort(false, &|a| lsd_radixsort_wc(a, *radix), generators_structu128(), *size);
+        helper_sort(false, &|a| lsd_radixsort_wc(a, *radix), generators_structusize(), *size);
+        helper_sort(false, &|a| lsd_radixsort_wc(a, *radix), generators_structi8(), *size);
+        helper_sort(false, &|a| lsd_radixsort_wc(a, *radix), generators_structi16(), *size);
+        helper_sort(false, &|a| lsd_radixsort_wc(a, *radix), generators_structi32(), *size);
+        helper_sort(false, &|a| lsd_radixsort_wc(a, *radix), generators_structi64(), *size);
+        // helper_sort(false, &|a| lsd_radixsort_wc(a, *radix), generators_structi128(), *size);
+        helper_sort(false, &|a| lsd_radixsort_wc(a, *radix), generators_structisize(), *size);
+    } }
+}
+
 #[test]
 fn test_sort_lsd_radixsort_f32_inf() {
     let mut a = helper_random_array_uniform_f32(2000);
@@ -487,6 +668,44 @@ fn test_sort_msd_radixsort() {
     } }
 }
 
+#[test]
+fn test_sort_msd_radixsort_wc() {
+    for size in [0, 1, 200, 10_000].iter() { for radix in [7, 8].iter() {
+        helper_sort(false, &|a| msd_radixsort_wc(a, *radix), generators_bool(), *size);
+        helper_sort(false, &|a| msd_radixsort_wc(a, *radix), generators_char(), *size);
+        helper_sort(false, &|a| msd_radixsort_wc(a, *radix), generators_f32(), *size);
+        helper_sort(false, &|a| msd_radixsort_wc(a, *radix), generators_f64(), *size);
+        helper_sort(false, &|a| msd_radixsort_wc(a, *radix), generators_u8(), *size);
+        helper_sort(false, &|a| msd_radixsort_wc(a, *radix), generators_u16(), *size);
+        helper_sort(false, &|a| msd_radixsort_wc(a, *radix), generators_u32(), *size);
+        helper_sort(false, &|a| msd_radixsort_wc(a, *radix), generators_u64(), *size);
+        helper_sort(false, &|a| msd_radixsort_wc(a, *radix), generators_u128(), *size);
+        helper_sort(false, &|a| msd_radixsort_wc(a, *radix), generators_usize(), *size);
+        helper_sort(false, &|a| msd_radixsort_wc(a, *radix), generators_i8(), *size);
+        helper_sort(false, &|a| msd_radixsort_wc(a, *radix), generators_i16(), *size);
+        helper_sort(false, &|a| msd_radixsort_wc(a, *radix), generators_i32(), *size);
+        helper_sort(false, &|a| msd_radixsort_wc(a, *radix), generators_i64(), *size);
+        helper_sort(false, &|a| msd_radixsort_wc(a, *radix), generators_i128(), *size);
+        helper_sort(false, &|a| msd_radixsort_wc(a, *radix), generators_isize(), *size);
+        helper_sort(false, &|a| msd_radixsort_wc(a, *radix), generators_structbool(), *size);
+        helper_sort(false, &|a| msd_radixsort_wc(a, *radix), generators_structchar(), *size);
+        helper_sort(false, &|a| msd_radixsort_wc(a, *radix), generators_structf32(), *size);
+        helper_sort(false, &|a| msd_radixsort_wc(a, *radix), generators_structf64(), *size);
+        helper_sort(false, &|a| msd_radixsort_wc(a, *radix), generators_structu8(), *size);
+        helper_sort(false, &|a| msd_radixsort_wc(a, *radix), generators_structu16(), *size);
+        helper_sort(false, &|a| msd_radixsort_wc(a, *radix), generators_structu32(), *size);
+        helper_sort(false, &|a| msd_radixsort_wc(a, *radix), generators_structu64(), *size);
+        helper_sort(false, &|a| msd_radixsort_wc(a, *radix), generators_structu128(), *size);
+        helper_sort(false, &|a| msd_radixsort_wc(a, *radix), generators_structusize(), *size);
+        helper_sort(false, &|a| msd_radixsort_wc(a, *radix), generators_structi8(), *size);
+        helper_sort(false, &|a| msd_radixsort_wc(a, *radix), generators_structi16(), *size);
+        helper_sort(false, &|a| msd_radixsort_wc(a, *radix), generators_structi32(), *size);
+        helper_sort(false, &|a| msd_radixsort_wc(a, *radix), generators_structi64(), *size);
+        helper_sort(false, &|a| msd_radixsort_wc(a, *radix), generators_structi128(), *size);
+        helper_sort(false, &|a| msd_radixsort_wc(a, *radix), generators_structisize(), *size);
+    } }
+}
+
 #[test]
 fn test_sort_msd_radixsort_f32_inf() {
     let mut a = helper_random_array_uniform_f32(2000);
@@ -751,6 +970,45 @@ fn test_sort_rollercoaster_sort_f64_inf() {
     assert_eq!(a, check);
 }
 
+#[test]
+fn test_sort_key_range_reduction() {
+    let generators_u64: Vec<(&dyn Fn(usize) -> Vec<u64>, &'static str)> =
+        vec![(&helper_random_array_clustered_u64, "")];
+    let generators_i32: Vec<(&dyn Fn(usize) -> Vec<i32>, &'static str)> =
+        vec![(&helper_random_array_clustered_i32, "")];
+    let generators_i64: Vec<(&dyn Fn(usize) -> Vec<i64>, &'static str)> =
+        vec![(&helper_random_array_clustered_i64, "")];
+    let generators_f64: Vec<(&dyn Fn(usize) -> Vec<f64>, &'static str)> =
+        vec![(&helper_random_array_clustered_f64, "")];
+    let generators_char: Vec<(&dyn Fn(usize) -> Vec<char>, &'static str)> =
+        vec![(&helper_random_array_clustered_char, "")];
+    for size in [200, 10_000, 500_000].iter() {
+        helper_sort(false, &|a| lsd_radixsort(a, 8), generators_u64.clone(), *size);
+        helper_sort(false, &|a| lsd_radixsort(a, 8), generators_i32.clone(), *size);
+        helper_sort(false, &|a| lsd_radixsort(a, 8), generators_i64.clone(), *size);
+        helper_sort(false, &|a| lsd_radixsort(a, 8), generators_f64.clone(), *size);
+        helper_sort(false, &|a| lsd_radixsort(a, 8), generators_char.clone(), *size);
+        helper_sort(false, &|a| lsd_radixsort_wc(a, 8), generators_u64.clone(), *size);
+        helper_sort(false, &|a| msd_radixsort(a, 8), generators_u64.clone(), *size);
+        helper_sort(false, &|a| msd_radixsort(a, 8), generators_i32.clone(), *size);
+        helper_sort(false, &|a| msd_radixsort(a, 8), generators_i64.clone(), *size);
+        helper_sort(false, &|a| msd_radixsort(a, 8), generators_f64.clone(), *size);
+        helper_sort(false, &|a| msd_radixsort(a, 8), generators_char.clone(), *size);
+        helper_sort(false, &|a| msd_radixsort_wc(a, 8), generators_u64.clone(), *size);
+        helper_sort(false, &|a| dlsd_radixsort(a, 8), generators_u64.clone(), *size);
+        helper_sort(false, &|a| dlsd_radixsort(a, 8), generators_i32.clone(), *size);
+        helper_sort(false, &|a| dlsd_radixsort(a, 8), generators_i64.clone(), *size);
+        helper_sort(false, &|a| dlsd_radixsort(a, 8), generators_f64.clone(), *size);
+        helper_sort(false, &|a| dlsd_radixsort(a, 8), generators_char.clone(), *size);
+        helper_sort(false, &|a| dlsd_radixsort_wc(a, 8), generators_u64.clone(), *size);
+        helper_sort(false, &|a| peeka_sort(a, 8, 100_000, 4), generators_u64.clone(), *size);
+        helper_sort(false, &|a| peeka_sort(a, 8, 100_000, 4), generators_i32.clone(), *size);
+        helper_sort(false, &|a| peeka_sort(a, 8, 100_000, 4), generators_i64.clone(), *size);
+        helper_sort(false, &|a| peeka_sort(a, 8, 100_000, 4), generators_f64.clone(), *size);
+        helper_sort(false, &|a| peeka_sort(a, 8, 100_000, 4), generators_char.clone(), *size);
+    }
+}
+
 #[test]
 fn test_sort_peeka_sort() {
     for size in [0, 1, 10_000, 30_000, 500_000].iter() { for radix in [8].iter() {
@@ -789,6 +1047,194 @@ fn test_sort_peeka_sort() {
     } }
 }
 
+#[cfg(feature = "voracious_multithread_std")]
+#[test]
+fn test_sort_peeka_std_sort() {
+    for size in [0, 1, 10_000, 30_000, 500_000].iter() { for radix in [7, 8].iter() {
+        helper_sort(false, &|a| peeka_std_sort(a, *radix, 100_000, 4), generators_bool(), *size);
+        helper_sort(false, &|a| peeka_std_sort(a, *radix, 100_000, 4), generators_char(), *size);
+        helper_sort(false, &|a| peeka_std_sort(a, *radix, 100_000, 4), generators_f32(), *size);
+        helper_sort(false, &|a| peeka_std_sort(a, *radix, 100_000, 4), generators_f64(), *size);
+        helper_sort(false, &|a| peeka_std_sort(a, *radix, 100_000, 4), generators_u8(), *size);
+        helper_sort(false, &|a| peeka_std_sort(a, *radix, 100_000, 4), generators_u16(), *size);
+        helper_sort(false, &|a| peeka_std_sort(a, *radix, 100_000, 4), generators_u32(), *size);
+        helper_sort(false, &|a| peeka_std_sort(a, *radix, 30_000, 0), generators_u32(), *size);
+        helper_sort(false, &|a| peeka_std_sort(a, *radix, 100_000, 4), generators_u64(), *size);
+        helper_sort(false, &|a| peeka_std_sort(a, *radix, 100_000, 4), generators_u128(), *size);
+        helper_sort(false, &|a| peeka_std_sort(a, *radix, 100_000, 4), generators_usize(), *size);
+        helper_sort(false, &|a| peeka_std_sort(a, *radix, 100_000, 4), generators_i8(), *size);
+        helper_sort(false, &|a| peeka_std_sort(a, *radix, 100_000, 4), generators_i16(), *size);
+        helper_sort(false, &|a| peeka_std_sort(a, *radix, 100_000, 4), generators_i32(), *size);
+        helper_sort(false, &|a| peeka_std_sort(a, *radix, 100_000, 4), generators_i64(), *size);
+        helper_sort(false, &|a| peeka_std_sort(a, *radix, 100_000, 4), generators_i128(), *size);
+        helper_sort(false, &|a| peeka_std_sort(a, *radix, 100_000, 4), generators_isize(), *size);
+        helper_sort(false, &|a| peeka_std_sort(a, *radix, 100_000, 4), generators_structbool(), *size);
+        helper_sort(false, &|a| peeka_std_sort(a, *radix, 100_000, 4), generators_structchar(), *size);
+        helper_sort(false, &|a| peeka_std_sort(a, *radix, 100_000, 4), generators_structf32(), *size);
+        helper_sort(false, &|a| peeka_std_sort(a, *radix, 100_000, 4), generators_structf64(), *size);
+        helper_sort(false, &|a| peeka_std_sort(a, *radix, 100_000, 4), generators_structu8(), *size);
+        helper_sort(false, &|a| peeka_std_sort(a, *radix, 100_000, 4), generators_structu16(), *size);
+        helper_sort(false, &|a| peeka_std_sort(a, *radix, 100_000, 4), generators_structu32(), *size);
+        helper_sort(false, &|a| peeka_std_sort(a, *radix, 100_000, 4), generators_structu64(), *size);
+        helper_sort(false, &|a| peeka_std_sort(a, *radix, 100_000, 4), generators_structu128(), *size);
+        helper_sort(false, &|a| peeka_std_sort(a, *radix, 100_000, 4), generators_structusize(), *size);
+        helper_sort(false, &|a| peeka_std_sort(a, *radix, 100_000, 4), generators_structi8(), *size);
+        helper_sort(false, &|a| peeka_std_sort(a, *radix, 100_000, 4), generators_structi16(), *size);
+        helper_sort(false, &|a| peeka_std_sort(a, *radix, 100_000, 4), generators_structi32(), *size);
+        helper_sort(false, &|a| peeka_std_sort(a, *radix, 100_000, 4), generators_structi64(), *size);
+        helper_sort(false, &|a| peeka_std_sort(a, *radix, 100_000, 4), generators_structi128(), *size);
+        helper_sort(false, &|a| peeka_std_sort(a, *radix, 100_000, 4), generators_structisize(), *size);
+    } }
+}
+
+#[cfg(feature = "voracious_multithread_std")]
+#[test]
+fn test_sort_std_mt_stable_sort() {
+    for size in [0, 1, 10_000, 30_000, 500_000].iter() { for thread_n in [4, 0].iter() {
+        helper_sort(true, &|a| std_mt_stable_sort(a, *thread_n), generators_bool(), *size);
+        helper_sort(true, &|a| std_mt_stable_sort(a, *thread_n), generators_char(), *size);
+        helper_sort(true, &|a| std_mt_stable_sort(a, *thread_n), generators_f32(), *size);
+        helper_sort(true, &|a| std_mt_stable_sort(a, *thread_n), generators_f64(), *size);
+        helper_sort(true, &|a| std_mt_stable_sort(a, *thread_n), generators_u8(), *size);
+        helper_sort(true, &|a| std_mt_stable_sort(a, *thread_n), generators_u16(), *size);
+        helper_sort(true, &|a| std_mt_stable_sort(a, *thread_n), generators_u32(), *size);
+        helper_sort(true, &|a| std_mt_stable_sort(a, *thread_n), generators_u64(), *size);
+        helper_sort(true, &|a| std_mt_stable_sort(a, *thread_n), generators_u128(), *size);
+        helper_sort(true, &|a| std_mt_stable_sort(a, *thread_n), generators_usize(), *size);
+        helper_sort(true, &|a| std_mt_stable_sort(a, *thread_n), generators_i8(), *size);
+        helper_sort(true, &|a| std_mt_stable_sort(a, *thread_n), generators_i16(), *size);
+        helper_sort(true, &|a| std_mt_stable_sort(a, *thread_n), generators_i32(), *size);
+        helper_sort(true, &|a| std_mt_stable_sort(a, *thread_n), generators_i64(), *size);
+        helper_sort(true, &|a| std_mt_stable_sort(a, *thread_n), generators_i128(), *size);
+        helper_sort(true, &|a| std_mt_stable_sort(a, *thread_n), generators_isize(), *size);
+        helper_sort(true, &|a| std_mt_stable_sort(a, *thread_n), generators_structbool(), *size);
+        helper_sort(true, &|a| std_mt_stable_sort(a, *thread_n), generators_structchar(), *size);
+        helper_sort(true, &|a| std_mt_stable_sort(a, *thread_n), generators_structf32(), *size);
+        helper_sort(true, &|a| std_mt_stable_sort(a, *thread_n), generators_structf64(), *size);
+        helper_sort(true, &|a| std_mt_stable_sort(a, *thread_n), generators_structu8(), *size);
+        helper_sort(true, &|a| std_mt_stable_sort(a, *thread_n), generators_structu16(), *size);
+        helper_sort(true, &|a| std_mt_stable_sort(a, *thread_n), generators_structu32(), *size);
+        helper_sort(true, &|a| std_mt_stable_sort(a, *thread_n), generators_structu64(), *size);
+        helper_sort(true, &|a| std_mt_stable_sort(a, *thread_n), generators_structu128(), *size);
+        helper_sort(true, &|a| std_mt_stable_sort(a, *thread_n), generators_structusize(), *size);
+        helper_sort(true, &|a| std_mt_stable_sort(a, *thread_n), generators_structi8(), *size);
+        helper_sort(true, &|a| std_mt_stable_sort(a, *thread_n), generators_structi16(), *size);
+        helper_sort(true, &|a| std_mt_stable_sort(a, *thread_n), generators_structi32(), *size);
+        helper_sort(true, &|a| std_mt_stable_sort(a, *thread_n), generators_structi64(), *size);
+        helper_sort(true, &|a| std_mt_stable_sort(a, *thread_n), generators_structi128(), *size);
+        helper_sort(true, &|a| std_mt_stable_sort(a, *thread_n), generators_structisize(), *size);
+    } }
+}
+
+#[test]
+fn test_sort_lsd_mt_radixsort() {
+    for size in [0, 1, 10_000, 30_000, 500_000].iter() { for radix in [8, 9].iter() {
+        helper_sort(true, &|a| lsd_mt_radixsort(a, *radix, 4), generators_bool(), *size);
+        helper_sort(true, &|a| lsd_mt_radixsort(a, *radix, 4), generators_char(), *size);
+        helper_sort(true, &|a| lsd_mt_radixsort(a, *radix, 4), generators_f32(), *size);
+        helper_sort(true, &|a| lsd_mt_radixsort(a, *radix, 4), generators_f64(), *size);
+        helper_sort(true, &|a| lsd_mt_radixsort(a, *radix, 4), generators_u8(), *size);
+        helper_sort(true, &|a| lsd_mt_radixsort(a, *radix, 4), generators_u16(), *size);
+        helper_sort(true, &|a| lsd_mt_radixsort(a, *radix, 4), generators_u32(), *size);
+        helper_sort(true, &|a| lsd_mt_radixsort(a, *radix, 4), generators_u64(), *size);
+        helper_sort(true, &|a| lsd_mt_radixsort(a, *radix, 4), generators_u128(), *size);
+        helper_sort(true, &|a| lsd_mt_radixsort(a, *radix, 4), generators_usize(), *size);
+        helper_sort(true, &|a| lsd_mt_radixsort(a, *radix, 4), generators_i8(), *size);
+        helper_sort(true, &|a| lsd_mt_radixsort(a, *radix, 4), generators_i16(), *size);
+        helper_sort(true, &|a| lsd_mt_radixsort(a, *radix, 4), generators_i32(), *size);
+        helper_sort(true, &|a| lsd_mt_radixsort(a, *radix, 4), generators_i64(), *size);
+        helper_sort(true, &|a| lsd_mt_radixsort(a, *radix, 4), generators_i128(), *size);
+        helper_sort(true, &|a| lsd_mt_radixsort(a, *radix, 4), generators_isize(), *size);
+        helper_sort(true, &|a| lsd_mt_radixsort(a, *radix, 4), generators_structbool(), *size);
+        helper_sort(true, &|a| lsd_mt_radixsort(a, *radix, 4), generators_structchar(), *size);
+        helper_sort(true, &|a| lsd_mt_radixsort(a, *radix, 4), generators_structf32(), *size);
+        helper_sort(true, &|a| lsd_mt_radixsort(a, *radix, 4), generators_structf64(), *size);
+        helper_sort(true, &|a| lsd_mt_radixsort(a, *radix, 4), generators_structu8(), *size);
+        helper_sort(true, &|a| lsd_mt_radixsort(a, *radix, 4), generators_structu16(), *size);
+        helper_sort(true, &|a| lsd_mt_radixsort(a, *radix, 4), generators_structu32(), *size);
+        helper_sort(true, &|a| lsd_mt_radixsort(a, *radix, 4), generators_structu64(), *size);
+        helper_sort(true, &|a| lsd_mt_radixsort(a, *radix, 4), generators_structu128(), *size);
+        helper_sort(true, &|a| lsd_mt_radixsort(a, *radix, 4), generators_structusize(), *size);
+        helper_sort(true, &|a| lsd_mt_radixsort(a, *radix, 4), generators_structi8(), *size);
+        helper_sort(true, &|a| lsd_mt_radixsort(a, *radix, 4), generators_structi16(), *size);
+        helper_sort(true, &|a| lsd_mt_radixsort(a, *radix, 4), generators_structi32(), *size);
+        helper_sort(true, &|a| lsd_mt_radixsort(a, *radix, 4), generators_structi64(), *size);
+        helper_sort(true, &|a| lsd_mt_radixsort(a, *radix, 4), generators_structi128(), *size);
+        helper_sort(true, &|a| lsd_mt_radixsort(a, *radix, 4), generators_structisize(), *size);
+        // One thread: the single thread LSD sort is used.
+        helper_sort(true, &|a| lsd_mt_radixsort(a, *radix, 1), generators_u32(), *size);
+        helper_sort(true, &|a| lsd_mt_radixsort(a, *radix, 1), generators_f64(), *size);
+        helper_sort(true, &|a| lsd_mt_radixsort(a, *radix, 3), generators_structu64(), *size);
+    } }
+}
+
+// Reversed input with duplicate keys: the Verge sort pre-processing reverses
+// the descending runs, the equal keys must keep their order. The ids are
+// compared, since `PartialEq` only looks at the key.
+#[test]
+fn test_sort_mt_stable_sort_reversed_duplicates() {
+    let ids = |a: &[StructU32]| a.iter().map(|e| e.other).collect::<Vec<_>>();
+    for size in [400_000, 2_000_000].iter() {
+        let arr: Vec<StructU32> = (0..*size)
+            .map(|i| StructU32 { value: ((size - i) / 4) as u32, other: i })
+            .collect();
+        let mut check = arr.to_vec();
+        check.sort_by_key(|e| e.value);
+
+        let mut lsd_mt = arr.to_vec();
+        lsd_mt_radixsort(&mut lsd_mt, 8, 4);
+        assert_eq!(ids(&lsd_mt), ids(&check));
+
+        let mut mt_stable = arr.to_vec();
+        mt_stable.voracious_mt_stable_sort(4);
+        assert_eq!(ids(&mt_stable), ids(&check));
+
+        // The descending sort sees an ascending input.
+        let mut arr = check.to_vec();
+        check.sort_by_key(|e| core::cmp::Reverse(e.value));
+        arr.voracious_mt_stable_sort_desc(4);
+        assert_eq!(ids(&arr), ids(&check));
+    }
+}
+
+#[test]
+fn test_sort_msd_mt_stable_radixsort() {
+    for size in [0, 1, 10_000, 30_000, 500_000].iter() { for radix in [7, 8].iter() {
+        helper_sort(true, &|a| msd_mt_stable_radixsort(a, *radix, 4), generators_bool(), *size);
+        helper_sort(true, &|a| msd_mt_stable_radixsort(a, *radix, 4), generators_char(), *size);
+        helper_sort(true, &|a| msd_mt_stable_radixsort(a, *radix, 4), generators_f32(), *size);
+        helper_sort(true, &|a| msd_mt_stable_radixsort(a, *radix, 4), generators_f64(), *size);
+        helper_sort(true, &|a| msd_mt_stable_radixsort(a, *radix, 4), generators_u8(), *size);
+        helper_sort(true, &|a| msd_mt_stable_radixsort(a, *radix, 4), generators_u16(), *size);
+        helper_sort(true, &|a| msd_mt_stable_radixsort(a, *radix, 4), generators_u32(), *size);
+        helper_sort(true, &|a| msd_mt_stable_radixsort(a, *radix, 4), generators_u64(), *size);
+        helper_sort(true, &|a| msd_mt_stable_radixsort(a, *radix, 4), generators_u128(), *size);
+        helper_sort(true, &|a| msd_mt_stable_radixsort(a, *radix, 4), generators_usize(), *size);
+        helper_sort(true, &|a| msd_mt_stable_radixsort(a, *radix, 4), generators_i8(), *size);
+        helper_sort(true, &|a| msd_mt_stable_radixsort(a, *radix, 4), generators_i16(), *size);
+        helper_sort(true, &|a| msd_mt_stable_radixsort(a, *radix, 4), generators_i32(), *size);
+        helper_sort(true, &|a| msd_mt_stable_radixsort(a, *radix, 4), generators_i64(), *size);
+        helper_sort(true, &|a| msd_mt_stable_radixsort(a, *radix, 4), generators_i128(), *size);
+        helper_sort(true, &|a| msd_mt_stable_radixsort(a, *radix, 4), generators_isize(), *size);
+        helper_sort(true, &|a| msd_mt_stable_radixsort(a, *radix, 4), generators_structbool(), *size);
+        helper_sort(true, &|a| msd_mt_stable_radixsort(a, *radix, 4), generators_structchar(), *size);
+        helper_sort(true, &|a| msd_mt_stable_radixsort(a, *radix, 4), generators_structf32(), *size);
+        helper_sort(true, &|a| msd_mt_stable_radixsort(a, *radix, 4), generators_structf64(), *size);
+        helper_sort(true, &|a| msd_mt_stable_radixsort(a, *radix, 4), generators_structu8(), *size);
+        helper_sort(true, &|a| msd_mt_stable_radixsort(a, *radix, 4), generators_structu16(), *size);
+        helper_sort(true, &|a| msd_mt_stable_radixsort(a, *radix, 4), generators_structu32(), *size);
+        helper_sort(true, &|a| msd_mt_stable_radixsort(a, *radix, 4), generators_structu64(), *size);
+        helper_sort(true, &|a| msd_mt_stable_radixsort(a, *radix, 4), generators_structu128(), *size);
+        helper_sort(true, &|a| msd_mt_stable_radixsort(a, *radix, 4), generators_structusize(), *size);
+        helper_sort(true, &|a| msd_mt_stable_radixsort(a, *radix, 4), generators_structi8(), *size);
+        helper_sort(true, &|a| msd_mt_stable_radixsort(a, *radix, 4), generators_structi16(), *size);
+        helper_sort(true, &|a| msd_mt_stable_radixsort(a, *radix, 4), generators_structi32(), *size);
+        helper_sort(true, &|a| msd_mt_stable_radixsort(a, *radix, 4), generators_structi64(), *size);
+        helper_sort(true, &|a| msd_mt_stable_radixsort(a, *radix, 4), generators_structi128(), *size);
+        helper_sort(true, &|a| msd_mt_stable_radixsort(a, *radix, 4), generators_structisize(), *size);
+    } }
+}
+
 #[test]
 fn test_sort_peeka_sort_f32_inf() {
     let mut a = helper_random_array_uniform_f32(200_000);
@@ -969,6 +1415,44 @@ fn test_sort_trait_voracious_mt_sort() {
     }
 }
 
+#[test]
+fn test_sort_trait_voracious_mt_stable_sort() {
+    for size in [0, 1, 10_000, 30_000, 500_000].iter() {
+        helper_sort(true, &|a| a.voracious_mt_stable_sort(4), generators_bool(), *size);
+        helper_sort(true, &|a| a.voracious_mt_stable_sort(4), generators_char(), *size);
+        helper_sort(true, &|a| a.voracious_mt_stable_sort(4), generators_f32(), *size);
+        helper_sort(true, &|a| a.voracious_mt_stable_sort(4), generators_f64(), *size);
+        helper_sort(true, &|a| a.voracious_mt_stable_sort(4), generators_u8(), *size);
+        helper_sort(true, &|a| a.voracious_mt_stable_sort(4), generators_u16(), *size);
+        helper_sort(true, &|a| a.voracious_mt_stable_sort(4), generators_u32(), *size);
+        helper_sort(true, &|a| a.voracious_mt_stable_sort(4), generators_u64(), *size);
+        helper_sort(true, &|a| a.voracious_mt_stable_sort(4), generators_u128(), *size);
+        helper_sort(true, &|a| a.voracious_mt_stable_sort(4), generators_usize(), *size);
+        helper_sort(true, &|a| a.voracious_mt_stable_sort(4), generators_i8(), *size);
+        helper_sort(true, &|a| a.voracious_mt_stable_sort(4), generators_i16(), *size);
+        helper_sort(true, &|a| a.voracious_mt_stable_sort(4), generators_i32(), *size);
+        helper_sort(true, &|a| a.voracious_mt_stable_sort(4), generators_i64(), *size);
+        helper_sort(true, &|a| a.voracious_mt_stable_sort(4), generators_i128(), *size);
+        helper_sort(true, &|a| a.voracious_mt_stable_sort(4), generators_isize(), *size);
+        helper_sort(true, &|a| a.voracious_mt_stable_sort(4), generators_structbool(), *size);
+        helper_sort(true, &|a| a.voracious_mt_stable_sort(4), generators_structchar(), *size);
+        helper_sort(true, &|a| a.voracious_mt_stable_sort(4), generators_structf32(), *size);
+        helper_sort(true, &|a| a.voracious_mt_stable_sort(4), generators_structf64(), *size);
+        helper_sort(true, &|a| a.voracious_mt_stable_sort(4), generators_structu8(), *size);
+        helper_sort(true, &|a| a.voracious_mt_stable_sort(4), generators_structu16(), *size);
+        helper_sort(true, &|a| a.voracious_mt_stable_sort(4), generators_structu32(), *size);
+        helper_sort(true, &|a| a.voracious_mt_stable_sort(4), generators_structu64(), *size);
+        helper_sort(true, &|a| a.voracious_mt_stable_sort(4), generators_structu128(), *size);
+        helper_sort(true, &|a| a.voracious_mt_stable_sort(4), generators_structusize(), *size);
+        helper_sort(true, &|a| a.voracious_mt_stable_sort(4), generators_structi8(), *size);
+        helper_sort(true, &|a| a.voracious_mt_stable_sort(4), generators_structi16(), *size);
+        helper_sort(true, &|a| a.voracious_mt_stable_sort(4), generators_structi32(), *size);
+        helper_sort(true, &|a| a.voracious_mt_stable_sort(4), generators_structi64(), *size);
+        helper_sort(true, &|a| a.voracious_mt_stable_sort(4), generators_structi128(), *size);
+        helper_sort(true, &|a| a.voracious_mt_stable_sort(4), generators_structisize(), *size);
+    }
+}
+
 #[test]
 fn test_sort_trait_voracious_mt_sort_f32_inf() {
     let mut a = helper_random_array_uniform_f32(40_000);
@@ -990,3 +1474,731 @@ fn test_sort_trait_voracious_mt_sort_f64_inf() {
     check.par_sort_unstable_by(|a, b| a.partial_cmp(b).unwrap());
     assert_eq!(a, check);
 }
+
+#[test]
+fn test_sort_trait_voracious_desc() {
+    for size in [0, 1, 200, 500, 20_000, 200_000].iter() {
+        helper_sort_desc(false, &|a| a.voracious_sort_desc(), generators_bool(), *size);
+        helper_sort_desc(false, &|a| a.voracious_sort_desc(), generators_char(), *size);
+        helper_sort_desc(false, &|a| a.voracious_sort_desc(), generators_f32(), *size);
+        helper_sort_desc(false, &|a| a.voracious_sort_desc(), generators_f64(), *size);
+        helper_sort_desc(false, &|a| a.voracious_sort_desc(), generators_u8(), *size);
+        helper_sort_desc(false, &|a| a.voracious_sort_desc(), generators_u16(), *size);
+        helper_sort_desc(false, &|a| a.voracious_sort_desc(), generators_u32(), *size);
+        helper_sort_desc(false, &|a| a.voracious_sort_desc(), generators_u64(), *size);
+        helper_sort_desc(false, &|a| a.voracious_sort_desc(), generators_u128(), *size);
+        helper_sort_desc(false, &|a| a.voracious_sort_desc(), generators_usize(), *size);
+        helper_sort_desc(false, &|a| a.voracious_sort_desc(), generators_i8(), *size);
+        helper_sort_desc(false, &|a| a.voracious_sort_desc(), generators_i16(), *size);
+        helper_sort_desc(false, &|a| a.voracious_sort_desc(), generators_i32(), *size);
+        helper_sort_desc(false, &|a| a.voracious_sort_desc(), generators_i64(), *size);
+        helper_sort_desc(false, &|a| a.voracious_sort_desc(), generators_i128(), *size);
+        helper_sort_desc(false, &|a| a.voracious_sort_desc(), generators_isize(), *size);
+        helper_sort_desc(false, &|a| a.voracious_sort_desc(), generators_structbool(), *size);
+        helper_sort_desc(false, &|a| a.voracious_sort_desc(), generators_structchar(), *size);
+        helper_sort_desc(false, &|a| a.voracious_sort_desc(), generators_structf32(), *size);
+        helper_sort_desc(false, &|a| a.voracious_sort_desc(), generators_structf64(), *size);
+        helper_sort_desc(false, &|a| a.voracious_sort_desc(), generators_structu8(), *size);
+        helper_sort_desc(false, &|a| a.voracious_sort_desc(), generators_structu16(), *size);
+        helper_sort_desc(false, &|a| a.voracious_sort_desc(), generators_structu32(), *size);
+        helper_sort_desc(false, &|a| a.voracious_sort_desc(), generators_structu64(), *size);
+        helper_sort_desc(false, &|a| a.voracious_sort_desc(), generators_structu128(), *size);
+        helper_sort_desc(false, &|a| a.voracious_sort_desc(), generators_structusize(), *size);
+        helper_sort_desc(false, &|a| a.voracious_sort_desc(), generators_structi8(), *size);
+        helper_sort_desc(false, &|a| a.voracious_sort_desc(), generators_structi16(), *size);
+        helper_sort_desc(false, &|a| a.voracious_sort_desc(), generators_structi32(), *size);
+        helper_sort_desc(false, &|a| a.voracious_sort_desc(), generators_structi64(), *size);
+        helper_sort_desc(false, &|a| a.voracious_sort_desc(), generators_structi128(), *size);
+        helper_sort_desc(false, &|a| a.voracious_sort_desc(), generators_structisize(), *size);
+    }
+}
+
+#[test]
+fn test_sort_trait_voracious_stable_desc() {
+    for size in [0, 1, 200, 500, 20_000, 200_000].iter() {
+        helper_sort_desc(true, &|a| a.voracious_stable_sort_desc(), generators_bool(), *size);
+        helper_sort_desc(true, &|a| a.voracious_stable_sort_desc(), generators_char(), *size);
+        helper_sort_desc(true, &|a| a.voracious_stable_sort_desc(), generators_f32(), *size);
+        helper_sort_desc(true, &|a| a.voracious_stable_sort_desc(), generators_f64(), *size);
+        helper_sort_desc(true, &|a| a.voracious_stable_sort_desc(), generators_u8(), *size);
+        helper_sort_desc(true, &|a| a.voracious_stable_sort_desc(), generators_u16(), *size);
+        helper_sort_desc(true, &|a| a.voracious_stable_sort_desc(), generators_u32(), *size);
+        helper_sort_desc(true, &|a| a.voracious_stable_sort_desc(), generators_u64(), *size);
+        helper_sort_desc(true, &|a| a.voracious_stable_sort_desc(), generators_u128(), *size);
+        helper_sort_desc(true, &|a| a.voracious_stable_sort_desc(), generators_usize(), *size);
+        helper_sort_desc(true, &|a| a.voracious_stable_sort_desc(), generators_i8(), *size);
+        helper_sort_desc(true, &|a| a.voracious_stable_sort_desc(), generators_i16(), *size);
+        helper_sort_desc(true, &|a| a.voracious_stable_sort_desc(), generators_i32(), *size);
+        helper_sort_desc(true, &|a| a.voracious_stable_sort_desc(), generators_i64(), *size);
+        helper_sort_desc(true, &|a| a.voracious_stable_sort_desc(), generators_i128(), *size);
+        helper_sort_desc(true, &|a| a.voracious_stable_sort_desc(), generators_isize(), *size);
+        helper_sort_desc(true, &|a| a.voracious_stable_sort_desc(), generators_structbool(), *size);
+        helper_sort_desc(true, &|a| a.voracious_stable_sort_desc(), generators_structchar(), *size);
+        helper_sort_desc(true, &|a| a.voracious_stable_sort_desc(), generators_structf32(), *size);
+        helper_sort_desc(true, &|a| a.voracious_stable_sort_desc(), generators_structf64(), *size);
+        helper_sort_desc(true, &|a| a.voracious_stable_sort_desc(), generators_structu8(), *size);
+        helper_sort_desc(true, &|a| a.voracious_stable_sort_desc(), generators_structu16(), *size);
+        helper_sort_desc(true, &|a| a.voracious_stable_sort_desc(), generators_structu32(), *size);
+        helper_sort_desc(true, &|a| a.voracious_stable_sort_desc(), generators_structu64(), *size);
+        helper_sort_desc(true, &|a| a.voracious_stable_sort_desc(), generators_structu128(), *size);
+        helper_sort_desc(true, &|a| a.voracious_stable_sort_desc(), generators_structusize(), *size);
+        helper_sort_desc(true, &|a| a.voracious_stable_sort_desc(), generators_structi8(), *size);
+        helper_sort_desc(true, &|a| a.voracious_stable_sort_desc(), generators_structi16(), *size);
+        helper_sort_desc(true, &|a| a.voracious_stable_sort_desc(), generators_structi32(), *size);
+        helper_sort_desc(true, &|a| a.voracious_stable_sort_desc(), generators_structi64(), *size);
+        helper_sort_desc(true, &|a| a.voracious_stable_sort_desc(), generators_structi128(), *size);
+        helper_sort_desc(true, &|a| a.voracious_stable_sort_desc(), generators_structisize(), *size);
+    }
+}
+
+#[test]
+fn test_sort_trait_voracious_mt_sort_desc() {
+    for size in [0, 1, 10_000, 500_000].iter() {
+        helper_sort_desc(false, &|a| a.voracious_mt_sort_desc(4), generators_bool(), *size);
+        helper_sort_desc(false, &|a| a.voracious_mt_sort_desc(4), generators_char(), *size);
+        helper_sort_desc(false, &|a| a.voracious_mt_sort_desc(4), generators_f32(), *size);
+        helper_sort_desc(false, &|a| a.voracious_mt_sort_desc(4), generators_f64(), *size);
+        helper_sort_desc(false, &|a| a.voracious_mt_sort_desc(4), generators_u8(), *size);
+        helper_sort_desc(false, &|a| a.voracious_mt_sort_desc(4), generators_u16(), *size);
+        helper_sort_desc(false, &|a| a.voracious_mt_sort_desc(4), generators_u32(), *size);
+        helper_sort_desc(false, &|a| a.voracious_mt_sort_desc(4), generators_u64(), *size);
+        helper_sort_desc(false, &|a| a.voracious_mt_sort_desc(4), generators_u128(), *size);
+        helper_sort_desc(false, &|a| a.voracious_mt_sort_desc(4), generators_usize(), *size);
+        helper_sort_desc(false, &|a| a.voracious_mt_sort_desc(4), generators_i8(), *size);
+        helper_sort_desc(false, &|a| a.voracious_mt_sort_desc(4), generators_i16(), *size);
+        helper_sort_desc(false, &|a| a.voracious_mt_sort_desc(4), generators_i32(), *size);
+        helper_sort_desc(false, &|a| a.voracious_mt_sort_desc(4), generators_i64(), *size);
+        helper_sort_desc(false, &|a| a.voracious_mt_sort_desc(4), generators_i128(), *size);
+        helper_sort_desc(false, &|a| a.voracious_mt_sort_desc(4), generators_isize(), *size);
+        helper_sort_desc(false, &|a| a.voracious_mt_sort_desc(4), generators_structbool(), *size);
+        helper_sort_desc(false, &|a| a.voracious_mt_sort_desc(4), generators_structchar(), *size);
+        helper_sort_desc(false, &|a| a.voracious_mt_sort_desc(4), generators_structf32(), *size);
+        helper_sort_desc(false, &|a| a.voracious_mt_sort_desc(4), generators_structf64(), *size);
+        helper_sort_desc(false, &|a| a.voracious_mt_sort_desc(4), generators_structu8(), *size);
+        helper_sort_desc(false, &|a| a.voracious_mt_sort_desc(4), generators_structu16(), *size);
+        helper_sort_desc(false, &|a| a.voracious_mt_sort_desc(4), generators_structu32(), *size);
+        helper_sort_desc(false, &|a| a.voracious_mt_sort_desc(4), generators_structu64(), *size);
+        helper_sort_desc(false, &|a| a.voracious_mt_sort_desc(4), generators_structu128(), *size);
+        helper_sort_desc(false, &|a| a.voracious_mt_sort_desc(4), generators_structusize(), *size);
+        helper_sort_desc(false, &|a| a.voracious_mt_sort_desc(4), generators_structi8(), *size);
+        helper_sort_desc(false, &|a| a.voracious_mt_sort_desc(4), generators_structi16(), *size);
+        helper_sort_desc(false, &|a| a.voracious_mt_sort_desc(4), generators_structi32(), *size);
+        helper_sort_desc(false, &|a| a.voracious_mt_sort_desc(4), generators_structi64(), *size);
+        helper_sort_desc(false, &|a| a.voracious_mt_sort_desc(4), generators_structi128(), *size);
+        helper_sort_desc(false, &|a| a.voracious_mt_sort_desc(4), generators_structisize(), *size);
+    }
+}
+
+#[test]
+fn test_sort_trait_voracious_mt_stable_sort_desc() {
+    for size in [0, 1, 10_000, 500_000].iter() {
+        helper_sort_desc(true, &|a| a.voracious_mt_stable_sort_desc(4), generators_bool(), *size);
+        helper_sort_desc(true, &|a| a.voracious_mt_stable_sort_desc(4), generators_char(), *size);
+        helper_sort_desc(true, &|a| a.voracious_mt_stable_sort_desc(4), generators_f32(), *size);
+        helper_sort_desc(true, &|a| a.voracious_mt_stable_sort_desc(4), generators_f64(), *size);
+        helper_sort_desc(true, &|a| a.voracious_mt_stable_sort_desc(4), generators_u8(), *size);
+        helper_sort_desc(true, &|a| a.voracious_mt_stable_sort_desc(4), generators_u16(), *size);
+        helper_sort_desc(true, &|a| a.voracious_mt_stable_sort_desc(4), generators_u32(), *size);
+        helper_sort_desc(true, &|a| a.voracious_mt_stable_sort_desc(4), generators_u64(), *size);
+        helper_sort_desc(true, &|a| a.voracious_mt_stable_sort_desc(4), generators_u128(), *size);
+        helper_sort_desc(true, &|a| a.voracious_mt_stable_sort_desc(4), generators_usize(), *size);
+        helper_sort_desc(true, &|a| a.voracious_mt_stable_sort_desc(4), generators_i8(), *size);
+        helper_sort_desc(true, &|a| a.voracious_mt_stable_sort_desc(4), generators_i16(), *size);
+        helper_sort_desc(true, &|a| a.voracious_mt_stable_sort_desc(4), generators_i32(), *size);
+        helper_sort_desc(true, &|a| a.voracious_mt_stable_sort_desc(4), generators_i64(), *size);
+        helper_sort_desc(true, &|a| a.voracious_mt_stable_sort_desc(4), generators_i128(), *size);
+        helper_sort_desc(true, &|a| a.voracious_mt_stable_sort_desc(4), generators_isize(), *size);
+        helper_sort_desc(true, &|a| a.voracious_mt_stable_sort_desc(4), generators_structbool(), *size);
+        helper_sort_desc(true, &|a| a.voracious_mt_stable_sort_desc(4), generators_structchar(), *size);
+        helper_sort_desc(true, &|a| a.voracious_mt_stable_sort_desc(4), generators_structf32(), *size);
+        helper_sort_desc(true, &|a| a.voracious_mt_stable_sort_desc(4), generators_structf64(), *size);
+        helper_sort_desc(true, &|a| a.voracious_mt_stable_sort_desc(4), generators_structu8(), *size);
+        helper_sort_desc(true, &|a| a.voracious_mt_stable_sort_desc(4), generators_structu16(), *size);
+        helper_sort_desc(true, &|a| a.voracious_mt_stable_sort_desc(4), generators_structu32(), *size);
+        helper_sort_desc(true, &|a| a.voracious_mt_stable_sort_desc(4), generators_structu64(), *size);
+        helper_sort_desc(true, &|a| a.voracious_mt_stable_sort_desc(4), generators_structu128(), *size);
+        helper_sort_desc(true, &|a| a.voracious_mt_stable_sort_desc(4), generators_structusize(), *size);
+        helper_sort_desc(true, &|a| a.voracious_mt_stable_sort_desc(4), generators_structi8(), *size);
+        helper_sort_desc(true, &|a| a.voracious_mt_stable_sort_desc(4), generators_structi16(), *size);
+        helper_sort_desc(true, &|a| a.voracious_mt_stable_sort_desc(4), generators_structi32(), *size);
+        helper_sort_desc(true, &|a| a.voracious_mt_stable_sort_desc(4), generators_structi64(), *size);
+        helper_sort_desc(true, &|a| a.voracious_mt_stable_sort_desc(4), generators_structi128(), *size);
+        helper_sort_desc(true, &|a| a.voracious_mt_stable_sort_desc(4), generators_structisize(), *size);
+    }
+}
+
+#[test]
+fn test_sort_trait_voracious_stable_desc_is_stable() {
+    let mut a = helper_random_array_uniform_structu8(100_000);
+    let mut check = a.to_vec();
+    a.voracious_stable_sort_desc();
+    check.sort_by_key(|e| std::cmp::Reverse(e.value));
+    let a: Vec<(u8, isize)> = a.iter().map(|e| (e.value, e.other)).collect();
+    let check: Vec<(u8, isize)> =
+        check.iter().map(|e| (e.value, e.other)).collect();
+    assert_eq!(a, check);
+}
+
+#[test]
+fn test_sort_trait_voracious_sort_by_key() {
+    for size in [0, 1, 200, 500, 20_000, 200_000].iter() {
+        helper_sort(false, &|a| a.voracious_sort_by_key(|e: &StructBool| e.value), generators_structbool(), *size);
+        helper_sort(false, &|a| a.voracious_sort_by_key(|e: &StructChar| e.value), generators_structchar(), *size);
+        helper_sort(false, &|a| a.voracious_sort_by_key(|e: &StructF32| e.value), generators_structf32(), *size);
+        helper_sort(false, &|a| a.voracious_sort_by_key(|e: &StructF64| e.value), generators_structf64(), *size);
+        helper_sort(false, &|a| a.voracious_sort_by_key(|e: &StructU8| e.value), generators_structu8(), *size);
+        helper_sort(false, &|a| a.voracious_sort_by_key(|e: &StructU16| e.value), generators_structu16(), *size);
+        helper_sort(false, &|a| a.voracious_sort_by_key(|e: &StructU32| e.value), generators_structu32(), *size);
+        helper_sort(false, &|a| a.voracious_sort_by_key(|e: &StructU64| e.value), generators_structu64(), *size);
+        helper_sort(false, &|a| a.voracious_sort_by_key(|e: &StructU128| e.value), generators_structu128(), *size);
+        helper_sort(false, &|a| a.voracious_sort_by_key(|e: &StructUsize| e.value), generators_structusize(), *size);
+        helper_sort(false, &|a| a.voracious_sort_by_key(|e: &StructI8| e.value), generators_structi8(), *size);
+        helper_sort(false, &|a| a.voracious_sort_by_key(|e: &StructI16| e.value), generators_structi16(), *size);
+        helper_sort(false, &|a| a.voracious_sort_by_key(|e: &StructI32| e.value), generators_structi32(), *size);
+        helper_sort(false, &|a| a.voracious_sort_by_key(|e: &StructI64| e.value), generators_structi64(), *size);
+        helper_sort(false, &|a| a.voracious_sort_by_key(|e: &StructI128| e.value), generators_structi128(), *size);
+        helper_sort(false, &|a| a.voracious_sort_by_key(|e: &StructIsize| e.value), generators_structisize(), *size);
+    }
+}
+
+#[test]
+fn test_sort_trait_voracious_stable_sort_by_key() {
+    for size in [0, 1, 200, 500, 20_000, 200_000].iter() {
+        let mut a = helper_random_array_uniform_structu8(*size);
+        let mut check = a.to_vec();
+        a.voracious_stable_sort_by_key(|e| e.value);
+        check.sort_by_key(|e| e.value);
+        let a: Vec<(u8, isize)> = a.iter().map(|e| (e.value, e.other)).collect();
+        let check: Vec<(u8, isize)> =
+            check.iter().map(|e| (e.value, e.other)).collect();
+        assert_eq!(a, check);
+    }
+}
+
+#[test]
+fn test_sort_trait_voracious_mt_sort_by_key() {
+    for size in [0, 1, 10_000, 500_000].iter() {
+        helper_sort(false, &|a| a.voracious_mt_sort_by_key(|e: &StructU32| e.value, 4), generators_structu32(), *size);
+        helper_sort(false, &|a| a.voracious_mt_sort_by_key(|e: &StructF64| e.value, 4), generators_structf64(), *size);
+        helper_sort(false, &|a| a.voracious_mt_sort_by_key(|e: &StructI64| e.value, 4), generators_structi64(), *size);
+    }
+}
+
+#[test]
+fn test_sort_trait_voracious_sort_by_key_not_copy() {
+    let mut a: Vec<(String, u64)> = helper_random_array_uniform_u64(10_000)
+        .into_iter()
+        .map(|v| (v.to_string(), v))
+        .collect();
+    let mut check = a.to_vec();
+    a.voracious_sort_by_key(|e| e.1);
+    check.sort_by_key(|e| e.1);
+    assert_eq!(a, check);
+}
+
+#[test]
+fn test_sort_composite_key() {
+    for size in [0, 1, 200, 500, 20_000, 200_000].iter() {
+        helper_sort(false, &|a| msd_radixsort(a, 8), generators_tuple_u32_u64(), *size);
+        helper_sort(false, &|a| msd_radixsort(a, 8), generators_tuple_i16_f32_char(), *size);
+        helper_sort(false, &|a| msd_radixsort(a, 8), generators_tuple_u8_i8_u16_bool(), *size);
+        helper_sort(false, &|a| msd_radixsort(a, 8), generators_bytes_5(), *size);
+        helper_sort(false, &|a| msd_radixsort(a, 8), generators_bytes_16(), *size);
+        helper_sort(false, &|a| voracious_sort(a, 8), generators_tuple_u32_u64(), *size);
+        helper_sort(false, &|a| voracious_sort(a, 8), generators_tuple_i16_f32_char(), *size);
+        helper_sort(false, &|a| voracious_sort(a, 8), generators_tuple_u8_i8_u16_bool(), *size);
+        helper_sort(false, &|a| voracious_sort(a, 8), generators_bytes_5(), *size);
+        helper_sort(false, &|a| voracious_sort(a, 8), generators_bytes_16(), *size);
+        helper_sort(true, &|a| lsd_stable_radixsort(a, 8), generators_tuple_u32_u64(), *size);
+        helper_sort(true, &|a| lsd_stable_radixsort(a, 8), generators_tuple_i16_f32_char(), *size);
+        helper_sort(true, &|a| lsd_stable_radixsort(a, 8), generators_tuple_u8_i8_u16_bool(), *size);
+        helper_sort(true, &|a| lsd_stable_radixsort(a, 8), generators_bytes_5(), *size);
+        helper_sort(true, &|a| lsd_stable_radixsort(a, 8), generators_bytes_16(), *size);
+    }
+}
+
+#[test]
+fn test_sort_trait_composite_key() {
+    for size in [0, 1, 200, 500, 20_000, 200_000].iter() {
+        helper_sort(false, &|a| a.voracious_sort(), generators_tuple_u32_u64(), *size);
+        helper_sort(false, &|a| a.voracious_sort(), generators_tuple_i16_f32_char(), *size);
+        helper_sort(false, &|a| a.voracious_sort(), generators_tuple_u8_i8_u16_bool(), *size);
+        helper_sort(false, &|a| a.voracious_sort(), generators_bytes_5(), *size);
+        helper_sort(false, &|a| a.voracious_sort(), generators_bytes_16(), *size);
+        helper_sort(true, &|a| a.voracious_stable_sort(), generators_tuple_u32_u64(), *size);
+        helper_sort(true, &|a| a.voracious_stable_sort(), generators_tuple_i16_f32_char(), *size);
+        helper_sort(true, &|a| a.voracious_stable_sort(), generators_tuple_u8_i8_u16_bool(), *size);
+        helper_sort(true, &|a| a.voracious_stable_sort(), generators_bytes_5(), *size);
+        helper_sort(true, &|a| a.voracious_stable_sort(), generators_bytes_16(), *size);
+        helper_sort(false, &|a| a.voracious_mt_sort(4), generators_tuple_u32_u64(), *size);
+        helper_sort(false, &|a| a.voracious_mt_sort(4), generators_tuple_i16_f32_char(), *size);
+        helper_sort(false, &|a| a.voracious_mt_sort(4), generators_bytes_16(), *size);
+        helper_sort_desc(false, &|a| a.voracious_sort_desc(), generators_tuple_u32_u64(), *size);
+        helper_sort_desc(false, &|a| a.voracious_sort_desc(), generators_tuple_u8_i8_u16_bool(), *size);
+        helper_sort_desc(false, &|a| a.voracious_sort_desc(), generators_bytes_5(), *size);
+    }
+}
+
+// Below 200 elements, the type size is not read before the keys are compared,
+// so the key itself must reject the fields which do not fit in 128 bits.
+#[test]
+#[should_panic(expected = "A composite key cannot be wider than 128 bits")]
+fn test_sort_composite_key_too_wide() {
+    let mut a: Vec<(u64, u64, u64)> = (0..100).map(|i| (i, 0, 0)).collect();
+    a.reverse();
+    a.voracious_sort();
+}
+
+#[test]
+fn test_sort_trait_voracious_sort_by_composite_key() {
+    let mut a = helper_random_array_uniform_structi64(100_000);
+    let mut check = a.to_vec();
+    a.voracious_stable_sort_by_key(|e: &StructI64| ((e.value % 7) as i8, e.other));
+    check.sort_by_key(|e| ((e.value % 7) as i8, e.other));
+    let a: Vec<(i64, isize)> = a.iter().map(|e| (e.value, e.other)).collect();
+    let check: Vec<(i64, isize)> =
+        check.iter().map(|e| (e.value, e.other)).collect();
+    assert_eq!(a, check);
+}
+
+fn helper_sort_string(
+    sort: &dyn Fn(&mut Vec<String>),
+    generators: Vec<(&dyn Fn(usize, usize) -> Vec<String>, &str)>,
+    size: usize,
+) {
+    for (generator, gen_name) in generators.iter() {
+        for string_size in [0, 1, 8, 40].iter() {
+            let mut a = generator(size, *string_size);
+            let mut check = a.to_vec();
+            sort(&mut a);
+            check.sort();
+            if a != check {
+                panic!("String sort failed: {}", gen_name);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_sort_string() {
+    for size in [0, 1, 50, 500, 20_000, 100_000].iter() {
+        helper_sort_string(&|a| string_sort(a), generators_string(), *size);
+        helper_sort_string(&|a| string_stable_sort(a), generators_string(), *size);
+        helper_sort_string(&|a| string_mt_sort(a, 4), generators_string(), *size);
+    }
+}
+
+#[test]
+fn test_sort_trait_string() {
+    // Not imported at the top of the file: `RadixSort` has the same methods.
+    use super::super::RadixSortString;
+
+    for size in [0, 1, 500, 100_000].iter() {
+        helper_sort_string(&|a| RadixSortString::voracious_sort(a), generators_string(), *size);
+        helper_sort_string(&|a| RadixSortString::voracious_stable_sort(a), generators_string(), *size);
+        helper_sort_string(&|a| RadixSortString::voracious_mt_sort(a, 4), generators_string(), *size);
+    }
+
+    let strings = helper_random_array_variable_length_string(50_000, 12);
+    let mut a: Vec<&str> = strings.iter().map(|s| s.as_str()).collect();
+    let mut check = a.to_vec();
+    a.voracious_sort();
+    check.sort_unstable();
+    assert_eq!(a, check);
+
+    let mut a: Vec<Vec<u8>> = strings.iter().map(|s| s.clone().into_bytes()).collect();
+    let mut check = a.to_vec();
+    a.voracious_mt_sort(4);
+    check.sort_unstable();
+    assert_eq!(a, check);
+}
+
+#[test]
+fn test_sort_trait_string_stable_is_stable() {
+    use super::super::RadixSortString;
+
+    // All the slices point into the same String, so equal strings are
+    // ordered by their address before the sort.
+    let strings = helper_random_array_prefix_string(50_000, 4);
+    let all = strings.concat();
+    let mut start = 0;
+    let mut a: Vec<&str> = Vec::new();
+    for s in strings.iter() {
+        a.push(&all[start..start + s.len()]);
+        start += s.len();
+    }
+    a.voracious_stable_sort();
+    for w in a.windows(2) {
+        assert!(w[0] <= w[1]);
+        if w[0] == w[1] {
+            assert!(w[0].as_ptr() < w[1].as_ptr());
+        }
+    }
+}
+
+fn helper_sort_owned<T, K>(
+    stable: bool,
+    sort: &dyn Fn(&mut Vec<T>),
+    generators: Vec<(&dyn Fn(usize) -> Vec<T>, &'static str)>,
+    array_size: usize,
+) where
+    T: RadixableOwned<K> + Clone + PartialOrd + std::fmt::Debug,
+    K: RadixKey,
+{
+    for (generator, _gen_name) in generators.iter() {
+        let mut array = generator(array_size);
+        let mut check = array.to_vec();
+        sort(&mut array);
+        if stable {
+            check.sort_by(|a, b| a.partial_cmp(b).unwrap());
+            // Equal keys: the whole element must be at the same position.
+            assert_eq!(format!("{:?}", check), format!("{:?}", array));
+        } else {
+            check.sort_unstable_by(|a, b| a.partial_cmp(b).unwrap());
+            assert_eq!(check, array);
+            // No element has been duplicated or lost.
+            let mut check: Vec<String> =
+                check.iter().map(|e| format!("{:?}", e)).collect();
+            let mut array: Vec<String> =
+                array.iter().map(|e| format!("{:?}", e)).collect();
+            check.sort_unstable();
+            array.sort_unstable();
+            assert_eq!(check, array);
+        }
+    }
+}
+
+#[test]
+fn test_sort_owned() {
+    for size in [0, 1, 50, 200, 500, 20_000, 100_000].iter() {
+        helper_sort_owned(false, &|a| msd_radixsort_owned(a, 8), generators_structownedu64(), *size);
+        helper_sort_owned(false, &|a| msd_radixsort_owned(a, 8), generators_structownedf32(), *size);
+        helper_sort_owned(false, &|a| msd_radixsort_owned(a, 11), generators_structownedu64(), *size);
+        helper_sort_owned(true, &|a| lsd_stable_radixsort_owned(a, 8), generators_structownedu64(), *size);
+        helper_sort_owned(true, &|a| lsd_stable_radixsort_owned(a, 8), generators_structownedf32(), *size);
+        helper_sort_owned(true, &|a| lsd_stable_radixsort_owned(a, 11), generators_structownedu64(), *size);
+        helper_sort_owned(true, &|a| thiel_radixsort_owned(a, 8), generators_structownedu64(), *size);
+        helper_sort_owned(true, &|a| thiel_radixsort_owned(a, 8), generators_structownedf32(), *size);
+        helper_sort_owned(true, &|a| thiel_radixsort_owned(a, 11), generators_structownedu64(), *size);
+    }
+}
+
+#[test]
+fn test_sort_trait_owned() {
+    // Not imported at the top of the file: `RadixSort` has the same methods.
+    use super::super::RadixSortOwned;
+
+    for size in [0, 1, 50, 200, 500, 20_000, 100_000].iter() {
+        helper_sort_owned(false, &|a| RadixSortOwned::voracious_sort(a), generators_structownedu64(), *size);
+        helper_sort_owned(false, &|a| RadixSortOwned::voracious_sort(a), generators_structownedf32(), *size);
+        helper_sort_owned(true, &|a| RadixSortOwned::voracious_stable_sort(a), generators_structownedu64(), *size);
+        helper_sort_owned(true, &|a| RadixSortOwned::voracious_stable_sort(a), generators_structownedf32(), *size);
+    }
+
+    let mut a = helper_random_array_uniform_structownedu64(10_000);
+    let mut check = a.to_vec();
+    a.voracious_stable_sort();
+    check.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    assert_eq!(format!("{:?}", check), format!("{:?}", a));
+}
+
+fn helper_argsort<T, K>(
+    stable: bool,
+    argsort: &dyn Fn(&Vec<T>) -> Vec<usize>,
+    generators: Vec<(&dyn Fn(usize) -> Vec<T>, &'static str)>,
+    array_size: usize,
+) where
+    T: Radixable<K> + PartialOrd + std::fmt::Debug,
+    K: RadixKey,
+{
+    for (generator, _gen_name) in generators.iter() {
+        let array = generator(array_size);
+        let indices = argsort(&array);
+        let mut check: Vec<usize> = (0..array.len()).collect();
+        if stable {
+            check.sort_by(|a, b| array[*a].partial_cmp(&array[*b]).unwrap());
+            assert_eq!(check, indices);
+        } else {
+            check.sort_unstable();
+            let mut sorted_indices = indices.to_vec();
+            sorted_indices.sort_unstable();
+            assert_eq!(check, sorted_indices);
+            let sorted: Vec<T> = indices.iter().map(|i| array[*i]).collect();
+            let mut check = array.to_vec();
+            check.sort_unstable_by(|a, b| a.partial_cmp(b).unwrap());
+            assert_eq!(check, sorted);
+        }
+    }
+}
+
+#[test]
+fn test_sort_trait_argsort() {
+    for size in [0, 1, 64, 65, 128, 129, 200, 500, 20_000, 200_000].iter() {
+        helper_argsort(false, &|a| a.voracious_argsort(), generators_u32(), *size);
+        helper_argsort(false, &|a| a.voracious_argsort(), generators_f64(), *size);
+        helper_argsort(false, &|a| a.voracious_argsort(), generators_i16(), *size);
+        helper_argsort(false, &|a| a.voracious_argsort(), generators_structf32(), *size);
+        helper_argsort(false, &|a| a.voracious_argsort(), generators_tuple_u32_u64(), *size);
+        helper_argsort(true, &|a| a.voracious_stable_argsort(), generators_u32(), *size);
+        helper_argsort(true, &|a| a.voracious_stable_argsort(), generators_i64(), *size);
+        helper_argsort(true, &|a| a.voracious_stable_argsort(), generators_structu64(), *size);
+        helper_argsort(false, &|a| a.voracious_mt_argsort(4), generators_u64(), *size);
+        helper_argsort(false, &|a| a.voracious_mt_argsort(4), generators_f32(), *size);
+        helper_argsort(false, &|a| widen(a.voracious_argsort_u32()), generators_u64(), *size);
+        helper_argsort(true, &|a| widen(a.voracious_stable_argsort_u32()), generators_structf64(), *size);
+        helper_argsort(false, &|a| widen(a.voracious_mt_argsort_u32(4)), generators_i32(), *size);
+    }
+    // Large enough for the Peeka partition of the multithread argsort.
+    helper_argsort(false, &|a| a.voracious_mt_argsort(4), generators_u32(), 1_000_000);
+    helper_argsort(false, &|a| widen(a.voracious_mt_argsort_u32(2)), generators_f64(), 1_000_000);
+}
+
+fn widen(indices: Vec<u32>) -> Vec<usize> {
+    indices.into_iter().map(|index| index as usize).collect()
+}
+
+fn helper_sort_pairs<T, K>(
+    stable: bool,
+    sort: &dyn Fn(&mut [T], &mut [usize]),
+    generators: Vec<(&dyn Fn(usize) -> Vec<T>, &'static str)>,
+    array_size: usize,
+) where
+    T: Radixable<K> + PartialOrd + std::fmt::Debug,
+    K: RadixKey,
+{
+    for (generator, _gen_name) in generators.iter() {
+        let mut keys = generator(array_size);
+        let mut values: Vec<usize> = (0..keys.len()).collect();
+        let initial_keys = keys.to_vec();
+        sort(&mut keys, &mut values);
+        // Each value still points to its key.
+        for (key, value) in keys.iter().zip(values.iter()) {
+            assert_eq!(*key, initial_keys[*value]);
+        }
+        let mut check: Vec<usize> = (0..keys.len()).collect();
+        if stable {
+            check.sort_by(|a, b| {
+                initial_keys[*a].partial_cmp(&initial_keys[*b]).unwrap()
+            });
+            assert_eq!(check, values);
+        } else {
+            let mut check_keys = initial_keys.to_vec();
+            check_keys.sort_unstable_by(|a, b| a.partial_cmp(b).unwrap());
+            assert_eq!(check_keys, keys);
+            let mut sorted_values = values.to_vec();
+            sorted_values.sort_unstable();
+            assert_eq!(check, sorted_values);
+        }
+    }
+}
+
+#[test]
+fn test_sort_pairs() {
+    for size in [0, 1, 200, 500, 20_000, 200_000].iter() {
+        helper_sort_pairs(false, &|k, v| rollercoaster_sort_pairs(k, v, 8), generators_u32(), *size);
+        helper_sort_pairs(false, &|k, v| rollercoaster_sort_pairs(k, v, 8), generators_i64(), *size);
+        helper_sort_pairs(false, &|k, v| rollercoaster_sort_pairs(k, v, 8), generators_f32(), *size);
+        helper_sort_pairs(false, &|k, v| rollercoaster_sort_pairs(k, v, 8), generators_structu64(), *size);
+        helper_sort_pairs(false, &|k, v| rollercoaster_sort_pairs(k, v, 8), generators_tuple_u32_u64(), *size);
+        helper_sort_pairs(true, &|k, v| lsd_stable_radixsort_pairs(k, v, 8), generators_u32(), *size);
+        helper_sort_pairs(true, &|k, v| lsd_stable_radixsort_pairs(k, v, 8), generators_i64(), *size);
+        helper_sort_pairs(true, &|k, v| lsd_stable_radixsort_pairs(k, v, 8), generators_f64(), *size);
+        helper_sort_pairs(true, &|k, v| lsd_stable_radixsort_pairs(k, v, 11), generators_u64(), *size);
+        helper_sort_pairs(true, &|k, v| lsd_stable_radixsort_pairs(k, v, 8), generators_structi32(), *size);
+        helper_sort_pairs(false, &|k, v| voracious_sort_pairs(k, v), generators_u64(), *size);
+        helper_sort_pairs(true, &|k, v| voracious_stable_sort_pairs(k, v), generators_u64(), *size);
+    }
+}
+
+#[test]
+fn test_sort_with_buffer() {
+    for size in [0, 1, 200, 500, 20_000, 200_000].iter() {
+        helper_sort(true, &|a| { let mut b = a.to_vec(); voracious_sort_with_buffer(a, &mut b) }, generators_u32(), *size);
+        helper_sort(true, &|a| { let mut b = vec![0; a.len() + 7]; voracious_sort_with_buffer(a, &mut b) }, generators_i64(), *size);
+        helper_sort(true, &|a| { let mut b = a.to_vec(); voracious_sort_with_buffer(a, &mut b) }, generators_f64(), *size);
+        helper_sort(true, &|a| { let mut b = a.to_vec(); voracious_sort_with_buffer(a, &mut b) }, generators_structi32(), *size);
+    }
+}
+
+#[test]
+fn test_sort_context() {
+    // The same context is reused for all the sizes and all the generators.
+    let context_u64 = std::cell::RefCell::new(SortContext::new());
+    let context_f32 = std::cell::RefCell::new(SortContext::with_capacity(500));
+    let context_char = std::cell::RefCell::new(SortContext::default());
+    let context_i128 = std::cell::RefCell::new(SortContext::new());
+    for size in [200_000, 0, 1, 200, 500, 20_000].iter() {
+        helper_sort(true, &|a| context_u64.borrow_mut().voracious_sort(a), generators_u64(), *size);
+        helper_sort(true, &|a| context_f32.borrow_mut().voracious_stable_sort(a), generators_f32(), *size);
+        helper_sort(true, &|a| context_char.borrow_mut().voracious_sort(a), generators_char(), *size);
+        helper_sort(true, &|a| context_i128.borrow_mut().voracious_sort(a), generators_i128(), *size);
+    }
+}
+
+// Counts the allocations of the current thread, so a sort can be checked not
+// to allocate while the other tests run.
+struct CountingAllocator;
+
+std::thread_local! {
+    static ALLOCATIONS: std::cell::Cell<usize> = const { std::cell::Cell::new(0) };
+}
+
+unsafe impl std::alloc::GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: std::alloc::Layout) -> *mut u8 {
+        let _ = ALLOCATIONS.try_with(|count| count.set(count.get() + 1));
+        std::alloc::System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: std::alloc::Layout) {
+        std::alloc::System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+fn count_allocations(f: &mut dyn FnMut()) -> usize {
+    let before = ALLOCATIONS.with(|count| count.get());
+    f();
+    ALLOCATIONS.with(|count| count.get()) - before
+}
+
+#[test]
+fn test_sort_context_warm_no_allocation() {
+    // Large enough for the SIMD histograms.
+    let size = 50_000;
+    let mut context = SortContext::with_capacity(size);
+    let mut arr = helper_random_array_uniform_u64(size);
+    assert_eq!(count_allocations(&mut || context.voracious_sort(&mut arr)), 0);
+    assert!(arr.windows(2).all(|w| w[0] <= w[1]));
+
+    // The first sort warms the context up.
+    let mut context = SortContext::new();
+    context.voracious_sort(&mut helper_random_array_uniform_i32(size));
+    for size in [size, 200, size / 2, 20_000].iter() {
+        let mut arr = helper_random_array_uniform_i32(*size);
+        let mut check = arr.to_vec();
+        check.sort_unstable();
+        assert_eq!(count_allocations(&mut || context.voracious_sort(&mut arr)), 0);
+        assert_eq!(arr, check);
+    }
+}
+
+#[test]
+fn test_sort_trait_try() {
+    for size in [0, 1, 200, 500, 20_000, 200_000].iter() {
+        helper_sort(false, &|a| a.try_voracious_sort().unwrap(), generators_u32(), *size);
+        helper_sort(false, &|a| a.try_voracious_sort().unwrap(), generators_f64(), *size);
+        helper_sort(false, &|a| a.as_mut_slice().try_voracious_sort().unwrap(), generators_i64(), *size);
+        helper_sort(true, &|a| a.try_voracious_stable_sort().unwrap(), generators_u64(), *size);
+        helper_sort(true, &|a| a.try_voracious_stable_sort().unwrap(), generators_structf64(), *size);
+        helper_sort(true, &|a| a.as_mut_slice().try_voracious_stable_sort().unwrap(), generators_i32(), *size);
+    }
+}
+
+#[test]
+fn test_sort_try_scratch_memory() {
+    assert!(SortContext::<u64>::try_with_capacity(1_000).is_ok());
+    assert!(SortContext::<u64>::try_with_capacity(usize::MAX / 4).is_err());
+
+    let error = SortError::AllocationFailed { bytes: 8_000 };
+    assert_eq!(format!("{}", error), "cannot allocate 8000 bytes of scratch memory");
+}
+
+#[test]
+fn test_sort_try_american_flag_sort() {
+    for size in [0, 1, 64, 65, 500, 20_000, 200_000].iter() {
+        helper_sort(false, &|a| try_american_flag_sort(a, 8).unwrap(), generators_u8(), *size);
+        helper_sort(false, &|a| try_american_flag_sort(a, 8).unwrap(), generators_u32(), *size);
+        helper_sort(false, &|a| try_american_flag_sort(a, 8).unwrap(), generators_i64(), *size);
+        helper_sort(false, &|a| try_american_flag_sort(a, 8).unwrap(), generators_f64(), *size);
+        helper_sort(false, &|a| try_american_flag_sort(a, 8).unwrap(), generators_structu16(), *size);
+        helper_sort(false, &|a| try_american_flag_sort(a, 8).unwrap(), generators_u128(), *size);
+    }
+}
+
+#[test]
+fn test_sort_with_memory_limit() {
+    for size in [0, 1, 200, 500, 20_000, 200_000].iter() {
+        for bytes in [0, 1_000, 1_000_000, usize::MAX].iter() {
+            helper_sort(false, &|a| voracious_sort_with_memory_limit(a, *bytes), generators_bool(), *size);
+            helper_sort(false, &|a| voracious_sort_with_memory_limit(a, *bytes), generators_char(), *size);
+            helper_sort(false, &|a| voracious_sort_with_memory_limit(a, *bytes), generators_u8(), *size);
+            helper_sort(false, &|a| voracious_sort_with_memory_limit(a, *bytes), generators_structi8(), *size);
+            helper_sort(false, &|a| voracious_sort_with_memory_limit(a, *bytes), generators_structu16(), *size);
+            helper_sort(false, &|a| voracious_sort_with_memory_limit(a, *bytes), generators_tuple_u8_i8_u16_bool(), *size);
+            helper_sort(false, &|a| voracious_sort_with_memory_limit(a, *bytes), generators_u64(), *size);
+            helper_sort(false, &|a| voracious_sort_with_memory_limit(a, *bytes), generators_i32(), *size);
+            helper_sort(false, &|a| voracious_sort_with_memory_limit(a, *bytes), generators_f32(), *size);
+            helper_sort(false, &|a| voracious_sort_with_memory_limit(a, *bytes), generators_f64(), *size);
+            helper_sort(false, &|a| voracious_sort_with_memory_limit(a, *bytes), generators_i128(), *size);
+            helper_sort(false, &|a| voracious_sort_with_memory_limit(a, *bytes), generators_structu64(), *size);
+            helper_sort(false, &|a| voracious_sort_with_memory_limit(a, *bytes), generators_tuple_u32_u64(), *size);
+        }
+    }
+}
+
+#[test]
+fn test_sort_trait_voracious_mt_stable_is_stable() {
+    let mut a = helper_random_array_uniform_structu16(500_000);
+    let mut check = a.to_vec();
+    a.voracious_mt_stable_sort(4);
+    check.sort_by_key(|e| e.value);
+    let a: Vec<(u16, isize)> = a.iter().map(|e| (e.value, e.other)).collect();
+    let check: Vec<(u16, isize)> =
+        check.iter().map(|e| (e.value, e.other)).collect();
+    assert_eq!(a, check);
+
+    // Wide keys are sorted by the multithread MSD sort.
+    let mut a = helper_random_array_uniform_structu128(500_000);
+    a.iter_mut().for_each(|e| e.value = (e.value % 1000) << 100);
+    let mut check = a.to_vec();
+    a.voracious_mt_stable_sort(4);
+    check.sort_by_key(|e| e.value);
+    let a: Vec<(u128, isize)> = a.iter().map(|e| (e.value, e.other)).collect();
+    let check: Vec<(u128, isize)> =
+        check.iter().map(|e| (e.value, e.other)).collect();
+    assert_eq!(a, check);
+
+    let mut a = helper_random_array_uniform_structu8(500_000);
+    let mut check = a.to_vec();
+    a.voracious_mt_stable_sort_desc(4);
+    check.sort_by_key(|e| std::cmp::Reverse(e.value));
+    let a: Vec<(u8, isize)> = a.iter().map(|e| (e.value, e.other)).collect();
+    let check: Vec<(u8, isize)> =
+        check.iter().map(|e| (e.value, e.other)).collect();
+    assert_eq!(a, check);
+}
+
+#[test]
+fn test_sort_trait_voracious_mt_sort_in_pool() {
+    let pool = rayon::ThreadPoolBuilder::new().num_threads(3).build().unwrap();
+    for size in [0, 1, 10_000, 30_000, 500_000].iter() {
+        helper_sort(false, &|a| a.voracious_mt_sort_in_pool(&pool), generators_u32(), *size);
+        helper_sort(false, &|a| a.voracious_mt_sort_in_pool(&pool), generators_f64(), *size);
+        helper_sort(false, &|a| a.voracious_mt_sort_in_pool(&pool), generators_i64(), *size);
+        helper_sort(false, &|a| a.voracious_mt_sort_in_pool(&pool), generators_structu64(), *size);
+        helper_sort(true, &|a| a.voracious_mt_stable_sort_in_pool(&pool), generators_u64(), *size);
+        helper_sort(true, &|a| a.voracious_mt_stable_sort_in_pool(&pool), generators_u128(), *size);
+        helper_sort(true, &|a| a.voracious_mt_stable_sort_in_pool(&pool), generators_structi32(), *size);
+        // The current pool is used when there is no thread count.
+        helper_sort(false, &|a| a.voracious_mt_sort(0), generators_u64(), *size);
+        helper_sort(true, &|a| a.voracious_mt_stable_sort(0), generators_i32(), *size);
+        helper_sort(false, &|a| peeka_sort(a, 8, 100_000, 0), generators_u32(), *size);
+        helper_sort_string(&|a| string_mt_sort(a, 0), generators_string(), *size);
+    }
+}
+
+#[test]
+fn test_sort_trait_voracious_mt_sort_from_rayon_tasks() {
+    // Sorting inside rayon tasks uses the pool of the tasks.
+    let pool = rayon::ThreadPoolBuilder::new().num_threads(2).build().unwrap();
+    let mut arrays: Vec<Vec<u64>> =
+        (0..4).map(|_| helper_random_array_uniform_u64(300_000)).collect();
+    let mut checks = arrays.to_vec();
+    checks.iter_mut().for_each(|check| check.sort_unstable());
+    pool.install(|| {
+        arrays.par_iter_mut().for_each(|array| array.voracious_mt_sort(0));
+    });
+    assert_eq!(arrays, checks);
+}
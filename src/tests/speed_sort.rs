@@ -70,7 +70,7 @@ fn helper_sort<T, K>(
     test_name: &str,
     generators: Vec<(&dyn Fn(usize) -> Vec<T>, &'static str)>,
 ) where
-    T: Radixable<K> + std::fmt::Debug,
+    T: Radixable<K> + PartialOrd + std::fmt::Debug,
     K: RadixKey,
     T: Ord,
     // T: PartialOrd,
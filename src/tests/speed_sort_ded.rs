@@ -1,12 +1,22 @@
-use super::super::{Radixable, RadixKey};
+use super::super::{RadixSort, Radixable, RadixKey};
 
 use super::super::dedicated::lsd_f32::lsd_f32;
+use super::super::dedicated::lsd_f64::lsd_f64;
+use super::super::dedicated::lsd_i32::lsd_i32;
+use super::super::dedicated::lsd_i64::lsd_i64;
+use super::super::dedicated::lsd_u128::lsd_u128;
 use super::super::dedicated::lsd_u32::lsd_u32;
+use super::super::dedicated::lsd_u64::lsd_u64;
 use super::super::dedicated::cs_u16::cs_u16;
 
 use super::super::generators::float_32::*;
+use super::super::generators::float_64::generators_f64;
+use super::super::generators::signed_i32::generators_i32;
+use super::super::generators::signed_i64::generators_i64;
+use super::super::generators::unsigned_u128::generators_u128;
 use super::super::generators::unsigned_u16::*;
 use super::super::generators::unsigned_u32::*;
+use super::super::generators::unsigned_u64::generators_u64;
 
 use super::test_helpers::helper_sort_aux;
 
@@ -15,7 +25,7 @@ fn speed_dedicated<T, K>(
     sort: &dyn Fn(&mut [T]) -> (),
     generators: Vec<(&dyn Fn(usize) -> Vec<T>, &'static str)>,
 ) where
-    T: Radixable<K> + std::fmt::Debug,
+    T: Radixable<K> + PartialOrd + std::fmt::Debug,
     K: RadixKey,
 {
     let runs = 3;
@@ -42,6 +52,10 @@ fn speed_dedicated<T, K>(
         for (generator, gen_name) in generators.iter() {
             print!("{}", gen_name);
             helper_sort_aux(&|arr: &mut [T]| sort(arr), runs, *size, generator, with_check);
+            // The Voracious sort on the same arrays: the dedicated sort is
+            // only worth routing to where it is faster.
+            print!(" | voracious_sort");
+            helper_sort_aux(&|arr: &mut [T]| arr.voracious_sort(), runs, *size, generator, with_check);
             println!();
         }
     }
@@ -61,3 +75,28 @@ fn speed_dedicated_lsd_u32() {
 fn speed_dedicated_cs_u16() {
     speed_dedicated("CS u16", &cs_u16, generators_u16());
 }
+
+#[test]
+fn speed_dedicated_lsd_u64() {
+    speed_dedicated("LSD u64", &lsd_u64, generators_u64());
+}
+
+#[test]
+fn speed_dedicated_lsd_i64() {
+    speed_dedicated("LSD i64", &lsd_i64, generators_i64());
+}
+
+#[test]
+fn speed_dedicated_lsd_f64() {
+    speed_dedicated("LSD f64", &lsd_f64, generators_f64());
+}
+
+#[test]
+fn speed_dedicated_lsd_i32() {
+    speed_dedicated("LSD i32", &lsd_i32, generators_i32());
+}
+
+#[test]
+fn speed_dedicated_lsd_u128() {
+    speed_dedicated("LSD u128", &lsd_u128, generators_u128());
+}
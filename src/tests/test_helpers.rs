@@ -28,7 +28,7 @@ pub fn helper_sort_aux<T, K>(
     generator: &dyn Fn(usize) -> Vec<T>,
     with_check: bool,
 ) where
-    T: Radixable<K> + std::fmt::Debug,
+    T: Radixable<K> + PartialOrd + std::fmt::Debug,
     K: RadixKey,
 {
     let mut nanos: Vec<u64> = Vec::with_capacity(runs);
@@ -3,6 +3,11 @@ use super::super::sorts::utils::{
     get_empty_histograms, get_histogram, get_partial_histograms,
     offset_from_bits, only_one_bucket_filled, prefix_sums, Params,
 };
+use super::super::generators::custom::helper_random_array_uniform_structu32;
+use super::super::sorts::key_range::{
+    compute_key_range, compute_offset_reduced, reduce_key_range,
+};
+use super::super::sorts::msd_sort::{copy_by_histogram, copy_by_histogram_wc};
 use super::super::Radixable;
 
 #[test]
@@ -49,28 +54,28 @@ fn test_utils_only_one_bucket_filled() {
 
 #[test]
 fn test_utils_offset_from_bits() {
-    let mut arr: Vec<u32> = vec![0];
+    let arr: Vec<u32> = vec![0];
     let max: u32 = 0b0000_0111_0000_0000_0000_0000_0000_0000;
     let radix = 8;
     let bits = 32;
     let zero: u32 = 0;
     let one: u32 = 1;
     let (offset, raw_offset) =
-        offset_from_bits(&mut arr, max, radix, bits, zero, one);
+        offset_from_bits(&arr, max, radix, bits, zero, one);
     assert_eq!(offset, 0);
     assert_eq!(raw_offset, 5);
 }
 
 #[test]
 fn test_utils_compute_offset() {
-    let mut arr: Vec<u32> = vec![0b0000_0111_0000_0000_0000_0000_0000_0000];
-    let (offset, raw_offset) = compute_offset(&mut arr, 8);
+    let arr: Vec<u32> = vec![0b0000_0111_0000_0000_0000_0000_0000_0000];
+    let (offset, raw_offset) = compute_offset(&arr, 8);
     assert_eq!(offset, 0);
     assert_eq!(raw_offset, 5);
 
-    let mut arr: Vec<char> = vec!['a'];
+    let arr: Vec<char> = vec!['a'];
     // 'a': 0b0000_0000_0000_0000_0000_0000_0110_0001
-    let (offset, raw_offset) = compute_offset(&mut arr, 8);
+    let (offset, raw_offset) = compute_offset(&arr, 8);
     assert_eq!(offset, 24);
     assert_eq!(raw_offset, 25);
 }
@@ -143,3 +148,66 @@ fn test_utils_get_partial_histograms() {
         5, 1, 1, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0
     ]);
 }
+
+#[test]
+fn test_utils_copy_by_histogram_wc() {
+    let p = Params::new(0, 11, 0, 3); // level, radix, offset, max_level
+    for size in [1, 3, 10_003].iter() {
+        let mut arr = helper_random_array_uniform_structu32(*size);
+        let (mask, shift) = arr[0].get_mask_and_shift(&p.new_level(2));
+        let h = get_histogram(&mut arr, &p.new_level(2), mask, shift);
+        let (_, heads, _) = prefix_sums(&h);
+
+        let mut direct = arr.to_vec();
+        let mut direct_heads = heads.to_vec();
+        copy_by_histogram(
+            *size,
+            &mut arr.to_vec(),
+            &mut direct,
+            &mut direct_heads,
+            mask,
+            shift,
+        );
+
+        let mut staged = arr.to_vec();
+        let mut staged_heads = heads.to_vec();
+        copy_by_histogram_wc(
+            *size,
+            &mut arr.to_vec(),
+            &mut staged,
+            &mut staged_heads,
+            mask,
+            shift,
+        );
+
+        // `PartialEq` only looks at the key, so the payloads are checked too.
+        let direct: Vec<_> =
+            direct.iter().map(|s| (s.value, s.other)).collect();
+        let staged: Vec<_> =
+            staged.iter().map(|s| (s.value, s.other)).collect();
+        assert_eq!(staged, direct);
+        assert_eq!(staged_heads, direct_heads);
+    }
+}
+
+#[test]
+fn test_utils_key_range() {
+    let mut arr: Vec<u64> = vec![1_000_300, 1_000_000, 1_000_255, 1_000_042];
+    let (min, max) = compute_key_range(&arr);
+    assert_eq!((min, max), (1_000_000, 1_000_300));
+
+    // 20 bits (3 levels) against 9 bits (2 levels)
+    let reduced = reduce_key_range(&arr[0], min, max, 8);
+    assert_eq!(reduced, Some((48, 55, 1_000_000)));
+    assert_eq!(compute_offset_reduced(&mut arr, 8), (48, 55, Some(1_000_000)));
+
+    // Same number of levels: no reduction
+    let mut arr: Vec<u64> = vec![0, 200, 255];
+    assert_eq!(compute_offset_reduced(&mut arr, 8), (56, 56, None));
+
+    // Signed keys around zero are clustered around the flipped sign bit.
+    let arr: Vec<i32> = vec![-3, 5, 0, -1];
+    let (min, max) = compute_key_range(&arr);
+    let reduced = reduce_key_range(&arr[0], min, max, 8);
+    assert_eq!(reduced, Some((24, 28, 0x8000_0000 - 3)));
+}
@@ -1,4 +1,9 @@
-use super::super::sorts::utils_mt::{aggregate_histograms, compute_offset_mt};
+use super::super::sorts::key_range::compute_offset_reduced;
+use super::super::sorts::utils::Params;
+use super::super::sorts::utils_mt::{
+    aggregate_histograms, compute_offset_mt, stable_pass_mt,
+};
+use super::super::Radixable;
 
 #[test]
 fn test_utils_mt_compute_offset_mt() {
@@ -23,3 +28,30 @@ fn test_utils_mt_aggregate_histograms() {
     assert_eq!(g[3], 9);
     assert_eq!(g[4], 12);
 }
+
+#[test]
+fn test_utils_mt_stable_pass_mt_key_min() {
+    let arr: Vec<u64> =
+        (0..10_000).map(|i| 1_000_000 + (i * 7919) % 301).collect();
+    let (offset, _, key_min) = compute_offset_reduced(&arr, 8);
+    assert_eq!(key_min, Some(1_000_000));
+
+    let dummy = arr[0];
+    let max_level = dummy.compute_max_level(offset, 8);
+    let p = Params::new(0, 8, offset, max_level).with_key_min(key_min);
+
+    // The passes scatter by the digits of the reduced keys, as the histograms.
+    let mut source = arr.clone();
+    let mut destination = vec![0; arr.len()];
+    for level in (0..max_level).rev() {
+        let (mask, shift) = dummy.get_mask_and_shift(&p.new_level(level));
+        let pass =
+            stable_pass_mt(&mut source, &mut destination, &p, mask, shift);
+        assert!(pass.is_some());
+        std::mem::swap(&mut source, &mut destination);
+    }
+
+    let mut check = arr;
+    check.sort_unstable();
+    assert_eq!(source, check);
+}
@@ -0,0 +1,133 @@
+use super::super::generators::float_32::*;
+use super::super::generators::float_64::*;
+use super::super::generators::signed_i32::*;
+use super::super::generators::signed_i64::*;
+use super::super::generators::unsigned_u32::*;
+use super::super::generators::unsigned_u64::*;
+use super::super::sorts::utils::{get_empty_histograms, Params};
+use super::super::sorts::utils_simd::{
+    bits_of_f32, bits_of_f64, bits_of_i32, bits_of_i64,
+    fill_full_histograms_simd, fill_histograms_u32,
+    fill_histograms_u32_scalar, fill_histograms_u64,
+    fill_histograms_u64_scalar, KeyTransform,
+};
+
+// An odd size, so the SIMD kernels leave a remainder to the scalar path.
+const SIZE: usize = 10_003;
+
+fn helper_check_u32(arr: &[u32], transform: KeyTransform) {
+    for (radix, max_level) in [(8, 4), (8, 2), (11, 3), (4, 8)].iter() {
+        let radix_range = 1 << radix;
+        let mut simd = get_empty_histograms(*max_level, radix_range);
+        let mut scalar = get_empty_histograms(*max_level, radix_range);
+
+        fill_histograms_u32(arr, *radix, *max_level, transform, &mut simd);
+        fill_histograms_u32_scalar(
+            arr,
+            *radix,
+            *max_level,
+            transform,
+            &mut scalar,
+        );
+
+        assert_eq!(simd, scalar);
+    }
+}
+
+fn helper_check_u64(arr: &[u64], transform: KeyTransform) {
+    for (radix, max_level) in [(8, 8), (8, 3), (11, 6), (7, 10)].iter() {
+        let radix_range = 1 << radix;
+        let mut simd = get_empty_histograms(*max_level, radix_range);
+        let mut scalar = get_empty_histograms(*max_level, radix_range);
+
+        fill_histograms_u64(arr, *radix, *max_level, transform, &mut simd);
+        fill_histograms_u64_scalar(
+            arr,
+            *radix,
+            *max_level,
+            transform,
+            &mut scalar,
+        );
+
+        assert_eq!(simd, scalar);
+    }
+}
+
+#[test]
+fn test_utils_simd_histograms_u32() {
+    for (generator, _) in generators_u32().iter() {
+        helper_check_u32(&generator(SIZE), KeyTransform::Identity);
+    }
+}
+
+#[test]
+fn test_utils_simd_histograms_i32() {
+    for (generator, _) in generators_i32().iter() {
+        helper_check_u32(bits_of_i32(&generator(SIZE)), KeyTransform::FlipSign);
+    }
+}
+
+#[test]
+fn test_utils_simd_histograms_f32() {
+    let arr = helper_random_array_uniform_f32(SIZE);
+    helper_check_u32(bits_of_f32(&arr), KeyTransform::FlipFloat);
+}
+
+#[test]
+fn test_utils_simd_histograms_u64() {
+    for (generator, _) in generators_u64().iter() {
+        helper_check_u64(&generator(SIZE), KeyTransform::Identity);
+    }
+}
+
+#[test]
+fn test_utils_simd_histograms_i64() {
+    for (generator, _) in generators_i64().iter() {
+        helper_check_u64(bits_of_i64(&generator(SIZE)), KeyTransform::FlipSign);
+    }
+}
+
+#[test]
+fn test_utils_simd_histograms_f64() {
+    let arr = helper_random_array_uniform_f64(SIZE);
+    helper_check_u64(bits_of_f64(&arr), KeyTransform::FlipFloat);
+}
+
+#[test]
+fn test_utils_simd_full_histograms_generic() {
+    let p = Params::new(0, 8, 0, 4);
+    let arr = helper_random_array_uniform_i32(SIZE);
+    let mut simd = get_empty_histograms(p.max_level, p.radix_range);
+    let mut scalar = get_empty_histograms(p.max_level, p.radix_range);
+
+    let used = fill_full_histograms_simd(&arr, &p, &mut simd, &mut Vec::new());
+    fill_histograms_u32_scalar(
+        bits_of_i32(&arr),
+        p.radix,
+        p.max_level,
+        KeyTransform::FlipSign,
+        &mut scalar,
+    );
+
+    if used {
+        assert_eq!(simd, scalar);
+    }
+
+    let p = Params::new(0, 8, 0, 8);
+    let arr = helper_random_array_uniform_f64(SIZE);
+    let mut simd = get_empty_histograms(p.max_level, p.radix_range);
+    let mut scalar = get_empty_histograms(p.max_level, p.radix_range);
+
+    let used = fill_full_histograms_simd(&arr, &p, &mut simd, &mut Vec::new());
+    fill_histograms_u64_scalar(
+        bits_of_f64(&arr),
+        p.radix,
+        p.max_level,
+        KeyTransform::FlipFloat,
+        &mut scalar,
+    );
+
+    if used {
+        assert_eq!(simd, scalar);
+    }
+}
@@ -3,9 +3,11 @@ use super::super::algo::verge_sort_heuristic::{
     explore_backward_asc, explore_backward_desc, explore_backward_plateau,
     explore_forward_asc, explore_forward_desc, explore_forward_plateau,
     explore_simple_forward, forward_orientation, jump,
-    verge_sort_preprocessing, BackwardGrowth, ForwardGrowth, Orientation,
+    verge_sort_preprocessing, verge_sort_preprocessing_stable, BackwardGrowth,
+    ForwardGrowth, Orientation,
 };
 use super::super::generators::unsigned_u64::*;
+use super::super::types::custom::StructU64;
 
 fn default_sort(arr: &mut [u64], _radix: usize) { arr.sort_unstable(); }
 
@@ -20,134 +22,134 @@ fn test_verge_sort_heuristic_compute_big_enough_run() {
 
 #[test]
 fn test_verge_sort_heuristic_explore_forward_asc() {
-    let mut arr = vec![5, 1, 2, 3, 4, 5, 5, 3, 4, 2, 1, 10];
-    assert_eq!(explore_forward_asc(&mut arr, 5), 7);
+    let arr = vec![5, 1, 2, 3, 4, 5, 5, 3, 4, 2, 1, 10];
+    assert_eq!(explore_forward_asc(&arr, 5), 7);
 
-    let mut arr = vec![5, 1, 2, 3, 4, 5, 4, 3, 4, 2, 1, 10];
-    assert_eq!(explore_forward_asc(&mut arr, 5), 6);
+    let arr = vec![5, 1, 2, 3, 4, 5, 4, 3, 4, 2, 1, 10];
+    assert_eq!(explore_forward_asc(&arr, 5), 6);
 
-    let mut arr = vec![5, 1, 2, 3, 4, 5, 6, 6, 6, 7, 8, 11];
-    assert_eq!(explore_forward_asc(&mut arr, 5), 12);
+    let arr = vec![5, 1, 2, 3, 4, 5, 6, 6, 6, 7, 8, 11];
+    assert_eq!(explore_forward_asc(&arr, 5), 12);
 
-    let mut arr = vec![5, 1, 2, 3, 4, 5, 6, 6, 6, 7, 8, 7];
-    assert_eq!(explore_forward_asc(&mut arr, 5), 11);
+    let arr = vec![5, 1, 2, 3, 4, 5, 6, 6, 6, 7, 8, 7];
+    assert_eq!(explore_forward_asc(&arr, 5), 11);
 
-    let mut arr = vec![5, 1, 2, 3, 4, 5];
-    assert_eq!(explore_forward_asc(&mut arr, 5), 6);
+    let arr = vec![5, 1, 2, 3, 4, 5];
+    assert_eq!(explore_forward_asc(&arr, 5), 6);
 
-    let mut arr = vec![9, 9, 8, 8, 7, 7, 6, 6, 6, 5, 4, 3];
-    assert_eq!(explore_forward_desc(&mut arr, 0), 12);
+    let arr = vec![9, 9, 8, 8, 7, 7, 6, 6, 6, 5, 4, 3];
+    assert_eq!(explore_forward_desc(&arr, 0), 12);
 }
 
 #[test]
 fn test_verge_sort_heuristic_explore_backward_asc() {
-    let mut arr = vec![5, 1, 2, 3, 4, 5, 5, 3, 4, 2, 1, 10];
-    assert_eq!(explore_backward_asc(&mut arr, 5, 0), 1);
+    let arr = vec![5, 1, 2, 3, 4, 5, 5, 3, 4, 2, 1, 10];
+    assert_eq!(explore_backward_asc(&arr, 5, 0), 1);
 
-    let mut arr = vec![0, 1, 2, 3, 4, 5, 4, 3, 4, 2, 1, 10];
-    assert_eq!(explore_backward_asc(&mut arr, 5, 0), 0);
+    let arr = vec![0, 1, 2, 3, 4, 5, 4, 3, 4, 2, 1, 10];
+    assert_eq!(explore_backward_asc(&arr, 5, 0), 0);
 
-    let mut arr = vec![5, 1, 2, 7, 5, 5, 6, 6, 6, 7, 8, 11];
-    assert_eq!(explore_backward_asc(&mut arr, 5, 0), 4);
+    let arr = vec![5, 1, 2, 7, 5, 5, 6, 6, 6, 7, 8, 11];
+    assert_eq!(explore_backward_asc(&arr, 5, 0), 4);
 
-    let mut arr = vec![5, 1, 2, 3, 7, 5, 6, 6, 6, 7, 8, 7];
-    assert_eq!(explore_backward_asc(&mut arr, 5, 0), 5);
+    let arr = vec![5, 1, 2, 3, 7, 5, 6, 6, 6, 7, 8, 7];
+    assert_eq!(explore_backward_asc(&arr, 5, 0), 5);
 
-    let mut arr = vec![5, 1, 2, 3, 4, 5];
-    assert_eq!(explore_backward_asc(&mut arr, 0, 0), 0);
+    let arr = vec![5, 1, 2, 3, 4, 5];
+    assert_eq!(explore_backward_asc(&arr, 0, 0), 0);
 
-    let mut arr = vec![1, 2, 3, 4, 5, 6, 7, 8, 9, 9, 9, 10];
-    assert_eq!(explore_backward_asc(&mut arr, 11, 0), 0);
+    let arr = vec![1, 2, 3, 4, 5, 6, 7, 8, 9, 9, 9, 10];
+    assert_eq!(explore_backward_asc(&arr, 11, 0), 0);
 }
 
 #[test]
 fn test_verge_sort_heuristic_explore_forward_desc() {
-    let mut arr = vec![5, 1, 2, 3, 4, 5, 5, 7, 4, 2, 1, 10];
-    assert_eq!(explore_forward_desc(&mut arr, 5), 7);
+    let arr = vec![5, 1, 2, 3, 4, 5, 5, 7, 4, 2, 1, 10];
+    assert_eq!(explore_forward_desc(&arr, 5), 7);
 
-    let mut arr = vec![5, 1, 2, 3, 4, 5, 7, 3, 4, 2, 1, 10];
-    assert_eq!(explore_forward_desc(&mut arr, 5), 6);
+    let arr = vec![5, 1, 2, 3, 4, 5, 7, 3, 4, 2, 1, 10];
+    assert_eq!(explore_forward_desc(&arr, 5), 6);
 
-    let mut arr = vec![5, 1, 2, 3, 4, 5, 4, 3, 3, 2, 1, 1];
-    assert_eq!(explore_forward_desc(&mut arr, 5), 12);
+    let arr = vec![5, 1, 2, 3, 4, 5, 4, 3, 3, 2, 1, 1];
+    assert_eq!(explore_forward_desc(&arr, 5), 12);
 
-    let mut arr = vec![5, 1, 2, 3, 4, 5, 4, 3, 3, 2, 1, 10];
-    assert_eq!(explore_forward_desc(&mut arr, 5), 11);
+    let arr = vec![5, 1, 2, 3, 4, 5, 4, 3, 3, 2, 1, 10];
+    assert_eq!(explore_forward_desc(&arr, 5), 11);
 
-    let mut arr = vec![5, 1, 2, 3, 4, 5];
-    assert_eq!(explore_forward_desc(&mut arr, 5), 6);
+    let arr = vec![5, 1, 2, 3, 4, 5];
+    assert_eq!(explore_forward_desc(&arr, 5), 6);
 
-    let mut arr = vec![9, 9, 8, 8, 7, 7, 6, 6, 6, 5, 4, 3];
-    assert_eq!(explore_forward_desc(&mut arr, 0), 12);
+    let arr = vec![9, 9, 8, 8, 7, 7, 6, 6, 6, 5, 4, 3];
+    assert_eq!(explore_forward_desc(&arr, 0), 12);
 }
 
 #[test]
 fn test_verge_sort_heuristic_explore_backward_desc() {
-    let mut arr = vec![0, 8, 7, 6, 6, 5, 4, 3, 4, 2, 1, 10];
-    assert_eq!(explore_backward_desc(&mut arr, 5, 0), 1);
+    let arr = vec![0, 8, 7, 6, 6, 5, 4, 3, 4, 2, 1, 10];
+    assert_eq!(explore_backward_desc(&arr, 5, 0), 1);
 
-    let mut arr = vec![8, 8, 7, 6, 6, 5, 4, 3, 4, 2, 1, 10];
-    assert_eq!(explore_backward_desc(&mut arr, 5, 0), 0);
+    let arr = vec![8, 8, 7, 6, 6, 5, 4, 3, 4, 2, 1, 10];
+    assert_eq!(explore_backward_desc(&arr, 5, 0), 0);
 
-    let mut arr = vec![5, 1, 2, 4, 6, 5, 6, 6, 6, 7, 8, 11];
-    assert_eq!(explore_backward_desc(&mut arr, 5, 0), 4);
+    let arr = vec![5, 1, 2, 4, 6, 5, 6, 6, 6, 7, 8, 11];
+    assert_eq!(explore_backward_desc(&arr, 5, 0), 4);
 
-    let mut arr = vec![5, 1, 2, 3, 4, 5, 6, 6, 6, 7, 8, 7];
-    assert_eq!(explore_backward_desc(&mut arr, 5, 0), 5);
+    let arr = vec![5, 1, 2, 3, 4, 5, 6, 6, 6, 7, 8, 7];
+    assert_eq!(explore_backward_desc(&arr, 5, 0), 5);
 
-    let mut arr = vec![5, 1, 2, 3, 4, 5];
-    assert_eq!(explore_backward_desc(&mut arr, 0, 0), 0);
+    let arr = vec![5, 1, 2, 3, 4, 5];
+    assert_eq!(explore_backward_desc(&arr, 0, 0), 0);
 
-    let mut arr = vec![9, 9, 8, 8, 7, 7, 6, 6, 6, 5, 4, 3];
-    assert_eq!(explore_backward_desc(&mut arr, 11, 0), 0);
+    let arr = vec![9, 9, 8, 8, 7, 7, 6, 6, 6, 5, 4, 3];
+    assert_eq!(explore_backward_desc(&arr, 11, 0), 0);
 }
 
 #[test]
 fn test_verge_sort_heuristic_explore_backward_plateau() {
-    let mut arr = vec![0, 5, 5, 5, 5, 5, 4, 3, 4, 2, 1, 10];
-    assert_eq!(explore_backward_plateau(&mut arr, 5, 0), 1);
+    let arr = vec![0, 5, 5, 5, 5, 5, 4, 3, 4, 2, 1, 10];
+    assert_eq!(explore_backward_plateau(&arr, 5, 0), 1);
 
-    let mut arr = vec![5, 5, 5, 5, 5, 5, 4, 3, 4, 2, 1, 10];
-    assert_eq!(explore_backward_plateau(&mut arr, 5, 0), 0);
+    let arr = vec![5, 5, 5, 5, 5, 5, 4, 3, 4, 2, 1, 10];
+    assert_eq!(explore_backward_plateau(&arr, 5, 0), 0);
 
-    let mut arr = vec![5, 1, 2, 4, 5, 5, 6, 6, 6, 7, 8, 11];
-    assert_eq!(explore_backward_plateau(&mut arr, 5, 0), 4);
+    let arr = vec![5, 1, 2, 4, 5, 5, 6, 6, 6, 7, 8, 11];
+    assert_eq!(explore_backward_plateau(&arr, 5, 0), 4);
 
-    let mut arr = vec![5, 1, 2, 3, 4, 5, 6, 6, 6, 7, 8, 7];
-    assert_eq!(explore_backward_plateau(&mut arr, 5, 0), 5);
+    let arr = vec![5, 1, 2, 3, 4, 5, 6, 6, 6, 7, 8, 7];
+    assert_eq!(explore_backward_plateau(&arr, 5, 0), 5);
 
-    let mut arr = vec![5, 1, 2, 3, 6, 5, 6, 6, 6, 7, 8, 7];
-    assert_eq!(explore_backward_plateau(&mut arr, 5, 0), 5);
+    let arr = vec![5, 1, 2, 3, 6, 5, 6, 6, 6, 7, 8, 7];
+    assert_eq!(explore_backward_plateau(&arr, 5, 0), 5);
 
-    let mut arr = vec![5, 1, 2, 3, 4, 5];
-    assert_eq!(explore_backward_plateau(&mut arr, 0, 0), 0);
+    let arr = vec![5, 1, 2, 3, 4, 5];
+    assert_eq!(explore_backward_plateau(&arr, 0, 0), 0);
 
-    let mut arr = vec![5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5];
-    assert_eq!(explore_backward_plateau(&mut arr, 9, 0), 0);
+    let arr = vec![5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5];
+    assert_eq!(explore_backward_plateau(&arr, 9, 0), 0);
 }
 
 #[test]
 fn test_verge_sort_heuristic_explore_forward_plateau() {
-    let mut arr = vec![0, 8, 7, 6, 6, 5, 5, 5, 5, 5, 5, 10];
-    assert_eq!(explore_forward_plateau(&mut arr, 5), 11);
+    let arr = vec![0, 8, 7, 6, 6, 5, 5, 5, 5, 5, 5, 10];
+    assert_eq!(explore_forward_plateau(&arr, 5), 11);
 
-    let mut arr = vec![0, 8, 7, 6, 6, 5, 5, 5, 5, 5, 5, 5];
-    assert_eq!(explore_forward_plateau(&mut arr, 5), 12);
+    let arr = vec![0, 8, 7, 6, 6, 5, 5, 5, 5, 5, 5, 5];
+    assert_eq!(explore_forward_plateau(&arr, 5), 12);
 
-    let mut arr = vec![5, 1, 2, 4, 6, 5, 5, 6, 6, 7, 8, 11];
-    assert_eq!(explore_forward_plateau(&mut arr, 5), 7);
+    let arr = vec![5, 1, 2, 4, 6, 5, 5, 6, 6, 7, 8, 11];
+    assert_eq!(explore_forward_plateau(&arr, 5), 7);
 
-    let mut arr = vec![5, 1, 2, 3, 4, 5, 6, 6, 6, 7, 8, 7];
-    assert_eq!(explore_forward_plateau(&mut arr, 5), 6);
+    let arr = vec![5, 1, 2, 3, 4, 5, 6, 6, 6, 7, 8, 7];
+    assert_eq!(explore_forward_plateau(&arr, 5), 6);
 
-    let mut arr = vec![5, 1, 2, 3, 4, 5, 4, 6, 6, 7, 8, 7];
-    assert_eq!(explore_forward_plateau(&mut arr, 5), 6);
+    let arr = vec![5, 1, 2, 3, 4, 5, 4, 6, 6, 7, 8, 7];
+    assert_eq!(explore_forward_plateau(&arr, 5), 6);
 
-    let mut arr = vec![5, 1, 2, 3, 4, 5];
-    assert_eq!(explore_forward_plateau(&mut arr, 5), 6);
+    let arr = vec![5, 1, 2, 3, 4, 5];
+    assert_eq!(explore_forward_plateau(&arr, 5), 6);
 
-    let mut arr = vec![5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5];
-    assert_eq!(explore_forward_plateau(&mut arr, 5), 12);
+    let arr = vec![5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5];
+    assert_eq!(explore_forward_plateau(&arr, 5), 12);
 }
 
 #[test]
@@ -169,110 +171,110 @@ fn test_verge_sort_heuristic_jump() {
 
 #[test]
 fn test_verge_sort_heuristic_backward_orientation() {
-    let mut arr = vec![0, 1, 2, 3, 0];
-    assert_eq!(backward_orientation(&mut arr, 2), Orientation::IsAsc);
+    let arr = vec![0, 1, 2, 3, 0];
+    assert_eq!(backward_orientation(&arr, 2), Orientation::IsAsc);
 
-    let mut arr = vec![0, 2, 2, 3, 0];
-    assert_eq!(backward_orientation(&mut arr, 2), Orientation::IsPlateau);
+    let arr = vec![0, 2, 2, 3, 0];
+    assert_eq!(backward_orientation(&arr, 2), Orientation::IsPlateau);
 
-    let mut arr = vec![0, 3, 2, 3, 0];
-    assert_eq!(backward_orientation(&mut arr, 2), Orientation::IsDesc);
+    let arr = vec![0, 3, 2, 3, 0];
+    assert_eq!(backward_orientation(&arr, 2), Orientation::IsDesc);
 
-    let mut arr = vec![0, 1, 2, 3, 0];
-    assert_eq!(backward_orientation(&mut arr, 0), Orientation::IsNone);
+    let arr = vec![0, 1, 2, 3, 0];
+    assert_eq!(backward_orientation(&arr, 0), Orientation::IsNone);
 }
 
 #[test]
 fn test_verge_sort_heuristic_forward_orientation() {
-    let mut arr = vec![0, 1, 2, 3, 0];
-    assert_eq!(forward_orientation(&mut arr, 2), Orientation::IsAsc);
+    let arr = vec![0, 1, 2, 3, 0];
+    assert_eq!(forward_orientation(&arr, 2), Orientation::IsAsc);
 
-    let mut arr = vec![0, 2, 2, 2, 0];
-    assert_eq!(forward_orientation(&mut arr, 2), Orientation::IsPlateau);
+    let arr = vec![0, 2, 2, 2, 0];
+    assert_eq!(forward_orientation(&arr, 2), Orientation::IsPlateau);
 
-    let mut arr = vec![0, 3, 2, 1, 0];
-    assert_eq!(forward_orientation(&mut arr, 2), Orientation::IsDesc);
+    let arr = vec![0, 3, 2, 1, 0];
+    assert_eq!(forward_orientation(&arr, 2), Orientation::IsDesc);
 
-    let mut arr = vec![0, 1, 2, 3, 0];
-    assert_eq!(forward_orientation(&mut arr, 4), Orientation::IsNone);
+    let arr = vec![0, 1, 2, 3, 0];
+    assert_eq!(forward_orientation(&arr, 4), Orientation::IsNone);
 }
 
 #[test]
 fn test_verge_sort_heuristic_explore_simple_forward() {
-    let mut arr: Vec<u64> = vec![0, 0, 0, 1, 1];
-    assert_eq!(explore_simple_forward(&mut arr), Orientation::IsAsc);
+    let arr: Vec<u64> = vec![0, 0, 0, 1, 1];
+    assert_eq!(explore_simple_forward(&arr), Orientation::IsAsc);
 
-    let mut arr: Vec<u64> = vec![0, 0, 0, 0, 0];
-    assert_eq!(explore_simple_forward(&mut arr), Orientation::IsPlateau);
+    let arr: Vec<u64> = vec![0, 0, 0, 0, 0];
+    assert_eq!(explore_simple_forward(&arr), Orientation::IsPlateau);
 
-    let mut arr: Vec<u64> = vec![2, 2, 2, 1, 1];
-    assert_eq!(explore_simple_forward(&mut arr), Orientation::IsDesc);
+    let arr: Vec<u64> = vec![2, 2, 2, 1, 1];
+    assert_eq!(explore_simple_forward(&arr), Orientation::IsDesc);
 
-    let mut arr: Vec<u64> = vec![0, 2, 0, 1, 1];
-    assert_eq!(explore_simple_forward(&mut arr), Orientation::IsNone);
+    let arr: Vec<u64> = vec![0, 2, 0, 1, 1];
+    assert_eq!(explore_simple_forward(&arr), Orientation::IsNone);
 
-    let mut arr: Vec<u64> = vec![0, 1];
-    assert_eq!(explore_simple_forward(&mut arr), Orientation::IsAsc);
+    let arr: Vec<u64> = vec![0, 1];
+    assert_eq!(explore_simple_forward(&arr), Orientation::IsAsc);
 
-    let mut arr: Vec<u64> = vec![0, 0];
-    assert_eq!(explore_simple_forward(&mut arr), Orientation::IsPlateau);
+    let arr: Vec<u64> = vec![0, 0];
+    assert_eq!(explore_simple_forward(&arr), Orientation::IsPlateau);
 
-    let mut arr: Vec<u64> = vec![2, 1];
-    assert_eq!(explore_simple_forward(&mut arr), Orientation::IsDesc);
+    let arr: Vec<u64> = vec![2, 1];
+    assert_eq!(explore_simple_forward(&arr), Orientation::IsDesc);
 }
 
 #[test]
 fn test_verge_sort_heuristic_explore_around() {
     // test forward
-    let mut arr = vec![0, 0, 0, 1, 2, 0, 0, 0, 0, 0, 0];
-    let ((bpat, bp1, bp2), (fpat, fp1, fp2)) = explore_around(&mut arr, 0, 0);
+    let arr = vec![0, 0, 0, 1, 2, 0, 0, 0, 0, 0, 0];
+    let ((bpat, bp1, bp2), (fpat, fp1, fp2)) = explore_around(&arr, 0, 0);
     assert_eq!((bpat, bp1, bp2), (BackwardGrowth::NN, 0, 0));
     assert_eq!((fpat, fp1, fp2), (ForwardGrowth::PA, 3, 5));
 
-    let mut arr = vec![3, 3, 3, 2, 1, 9, 0, 0, 0, 0, 0];
-    let ((_, _, _), (fpat, fp1, fp2)) = explore_around(&mut arr, 0, 0);
+    let arr = vec![3, 3, 3, 2, 1, 9, 0, 0, 0, 0, 0];
+    let ((_, _, _), (fpat, fp1, fp2)) = explore_around(&arr, 0, 0);
     assert_eq!((fpat, fp1, fp2), (ForwardGrowth::PD, 3, 5));
 
-    let mut arr = vec![0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0];
-    let ((_, _, _), (fpat, fp1, fp2)) = explore_around(&mut arr, 0, 0);
+    let arr = vec![0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0];
+    let ((_, _, _), (fpat, fp1, fp2)) = explore_around(&arr, 0, 0);
     assert_eq!((fpat, fp1, fp2), (ForwardGrowth::PN, 11, 11));
 
-    let mut arr = vec![0, 1, 1, 2, 2, 0, 0, 0, 0, 0, 0];
-    let ((_, _, _), (fpat, fp1, fp2)) = explore_around(&mut arr, 0, 0);
+    let arr = vec![0, 1, 1, 2, 2, 0, 0, 0, 0, 0, 0];
+    let ((_, _, _), (fpat, fp1, fp2)) = explore_around(&arr, 0, 0);
     assert_eq!((fpat, fp1, fp2), (ForwardGrowth::NA, 0, 5));
 
-    let mut arr = vec![5, 4, 4, 2, 2, 3, 0, 0, 0, 0, 0];
-    let ((_, _, _), (fpat, fp1, fp2)) = explore_around(&mut arr, 0, 0);
+    let arr = vec![5, 4, 4, 2, 2, 3, 0, 0, 0, 0, 0];
+    let ((_, _, _), (fpat, fp1, fp2)) = explore_around(&arr, 0, 0);
     assert_eq!((fpat, fp1, fp2), (ForwardGrowth::ND, 0, 5));
 
-    let mut arr = vec![5, 4, 4, 2, 2, 0, 0, 0, 0, 0, 0];
-    let ((_, _, _), (fpat, fp1, fp2)) = explore_around(&mut arr, 0, 0);
+    let arr = vec![5, 4, 4, 2, 2, 0, 0, 0, 0, 0, 0];
+    let ((_, _, _), (fpat, fp1, fp2)) = explore_around(&arr, 0, 0);
     assert_eq!((fpat, fp1, fp2), (ForwardGrowth::ND, 0, 11));
 
     // test backward
-    let mut arr = vec![0, 0, 0, 0, 0, 9, 1, 2, 3, 3, 3];
-    let ((bpat, bp1, bp2), (fpat, fp1, fp2)) = explore_around(&mut arr, 10, 0);
+    let arr = vec![0, 0, 0, 0, 0, 9, 1, 2, 3, 3, 3];
+    let ((bpat, bp1, bp2), (fpat, fp1, fp2)) = explore_around(&arr, 10, 0);
     assert_eq!((bpat, bp1, bp2), (BackwardGrowth::AP, 8, 6));
     assert_eq!((fpat, fp1, fp2), (ForwardGrowth::NN, 11, 11));
 
-    let mut arr = vec![0, 0, 0, 0, 0, 0, 5, 4, 3, 3, 3];
-    let ((bpat, bp1, bp2), (_, _, _)) = explore_around(&mut arr, 10, 0);
+    let arr = vec![0, 0, 0, 0, 0, 0, 5, 4, 3, 3, 3];
+    let ((bpat, bp1, bp2), (_, _, _)) = explore_around(&arr, 10, 0);
     assert_eq!((bpat, bp1, bp2), (BackwardGrowth::DP, 8, 6));
 
-    let mut arr = vec![3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3];
-    let ((bpat, bp1, bp2), (_, _, _)) = explore_around(&mut arr, 10, 0);
+    let arr = vec![3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3];
+    let ((bpat, bp1, bp2), (_, _, _)) = explore_around(&arr, 10, 0);
     assert_eq!((bpat, bp1, bp2), (BackwardGrowth::NP, 0, 0));
 
-    let mut arr = vec![0, 0, 0, 0, 0, 0, 1, 2, 3, 3, 3];
-    let ((bpat, bp1, bp2), (_, _, _)) = explore_around(&mut arr, 10, 0);
+    let arr = vec![0, 0, 0, 0, 0, 0, 1, 2, 3, 3, 3];
+    let ((bpat, bp1, bp2), (_, _, _)) = explore_around(&arr, 10, 0);
     assert_eq!((bpat, bp1, bp2), (BackwardGrowth::AP, 8, 0));
 
-    let mut arr = vec![0, 0, 0, 0, 0, 0, 1, 2, 5, 4, 3];
-    let ((bpat, bp1, bp2), (_, _, _)) = explore_around(&mut arr, 10, 0);
+    let arr = vec![0, 0, 0, 0, 0, 0, 1, 2, 5, 4, 3];
+    let ((bpat, bp1, bp2), (_, _, _)) = explore_around(&arr, 10, 0);
     assert_eq!((bpat, bp1, bp2), (BackwardGrowth::DN, 10, 8));
 
-    let mut arr = vec![0, 0, 0, 0, 0, 0, 1, 2, 3, 4, 5];
-    let ((bpat, bp1, bp2), (_, _, _)) = explore_around(&mut arr, 10, 0);
+    let arr = vec![0, 0, 0, 0, 0, 0, 1, 2, 3, 4, 5];
+    let ((bpat, bp1, bp2), (_, _, _)) = explore_around(&arr, 10, 0);
     assert_eq!((bpat, bp1, bp2), (BackwardGrowth::AN, 10, 0));
 }
 
@@ -326,3 +328,33 @@ fn test_verge_sort_heuristic_verge_sort_preprocessing() {
         }
     }
 }
+
+#[test]
+fn test_verge_sort_heuristic_verge_sort_preprocessing_stable() {
+    // A descending run with equal keys, `other` is the initial position.
+    let size = 10_000;
+    let descending: Vec<StructU64> = (0..size)
+        .map(|i| StructU64 { value: ((size - i) / 10) as u64, other: i })
+        .collect();
+    let stable_sort =
+        |arr: &mut [StructU64], _radix: usize| arr.sort_by_key(|e| e.value);
+
+    let mut arr = descending.to_vec();
+    let separators =
+        verge_sort_preprocessing_stable(arr.as_mut_slice(), 8, &stable_sort);
+    assert_eq!(separators, vec![0, size as usize]);
+    assert!(arr.windows(2).all(|w| {
+        w[0].value < w[1].value
+            || (w[0].value == w[1].value && w[0].other < w[1].other)
+    }));
+
+    // The unstable pre-processing only reverses the run.
+    let mut arr = descending.to_vec();
+    verge_sort_preprocessing(arr.as_mut_slice(), 8, &stable_sort);
+    let mut reversed = descending.to_vec();
+    reversed.reverse();
+    let others = |arr: &[StructU64]| -> Vec<isize> {
+        arr.iter().map(|e| e.other).collect()
+    };
+    assert_eq!(others(&arr), others(&reversed));
+}
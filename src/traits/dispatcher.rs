@@ -1,33 +1,138 @@
 #[cfg(feature = "voracious_multithread")]
 use rayon::slice::ParallelSliceMut;
 
-use super::super::sorts::dlsd_sort::dlsd_radixsort;
-use super::super::sorts::lsd_sort::lsd_radixsort;
+use super::super::sorts::american_flag_sort::american_flag_sort;
+#[cfg(any(
+    feature = "voracious_multithread",
+    feature = "voracious_multithread_std"
+))]
+use super::super::sorts::bits_sort::bits_mt_sort;
+use super::super::sorts::bits_sort::{
+    bits_in_place_sort, bits_sort, bits_sort_scratch, bits_stable_sort,
+};
+use super::super::sorts::comparative_sort::{key_cmp, stable_sort};
+use super::super::sorts::dlsd_sort::{dlsd_radixsort, dlsd_radixsort_wc};
+#[cfg(feature = "voracious_multithread")]
+use super::super::sorts::lsd_mt_sort::lsd_mt_radixsort;
+use super::super::sorts::lsd_sort::{lsd_radixsort, lsd_radixsort_wc};
 use super::super::sorts::lsd_stable_sort::lsd_stable_radixsort;
+#[cfg(feature = "voracious_multithread")]
+use super::super::sorts::msd_mt_stable_sort::msd_mt_stable_radixsort;
 use super::super::sorts::msd_sort::msd_radixsort;
 use super::super::sorts::msd_stable_sort::msd_stable_radixsort;
 #[cfg(feature = "voracious_multithread")]
 use super::super::sorts::peeka_sort::peeka_sort;
 use super::super::sorts::rollercoaster_sort::rollercoaster_sort;
+use super::super::sorts::scratch::{
+    dlsd_scratch, in_place_scratch, lsd_scratch, msd_scratch,
+    rollercoaster_scratch, voracious_scratch,
+};
+use super::super::sorts::ska_sort::ska_sort;
+#[cfg(all(
+    feature = "voracious_multithread_std",
+    not(feature = "voracious_multithread")
+))]
+use super::super::sorts::std_mt_stable_sort::std_mt_stable_sort;
 use super::super::sorts::voracious_sort::voracious_sort;
+use super::super::types::desc::Desc;
 use super::super::{RadixKey, Radixable};
 
 // This trait is implemented when a struct with a key is the element in the
 // array to sort.
+//
+// Every method has a default: the bits sorts, which choose the sort from the
+// bit width of the key. A key type defined outside of this crate only needs
+// a `RadixKey` implementation and an empty `Dispatcher` implementation.
 pub trait Dispatcher<T: Radixable<K>, K: RadixKey> {
-    fn voracious_sort(&self, arr: &mut [T]);
-    fn voracious_stable_sort(&self, arr: &mut [T]);
+    fn voracious_sort(&self, arr: &mut [T]) { bits_sort(arr); }
+    fn voracious_stable_sort(&self, arr: &mut [T]) { bits_stable_sort(arr); }
+    #[cfg(any(
+        feature = "voracious_multithread",
+        feature = "voracious_multithread_std"
+    ))]
+    fn voracious_mt_sort(&self, arr: &mut [T], thread_n: usize) {
+        bits_mt_sort(arr, thread_n);
+    }
+    fn voracious_sort_desc(&self, arr: &mut [T]) {
+        bits_sort(Desc::from_slice_mut(arr));
+    }
+    fn voracious_stable_sort_desc(&self, arr: &mut [T]) {
+        bits_stable_sort(Desc::from_slice_mut(arr));
+    }
+    #[cfg(any(
+        feature = "voracious_multithread",
+        feature = "voracious_multithread_std"
+    ))]
+    fn voracious_mt_sort_desc(&self, arr: &mut [T], thread_n: usize) {
+        bits_mt_sort(Desc::from_slice_mut(arr), thread_n);
+    }
+    // The parallel stable sorts are generic over the key: a LSD sort, or a
+    // MSD sort for the keys wider than 64 bits which would need too many LSD
+    // passes. Small arrays are sorted with the single thread stable sort.
     #[cfg(feature = "voracious_multithread")]
-    fn voracious_mt_sort(&self, arr: &mut [T], thread_n: usize);
+    fn voracious_mt_stable_sort(&self, arr: &mut [T], thread_n: usize) {
+        if arr.len() <= 100_000 {
+            self.voracious_stable_sort(arr);
+        } else if arr[0].type_size() > 64 {
+            msd_mt_stable_radixsort(arr, 8, thread_n);
+        } else {
+            lsd_mt_radixsort(arr, 8, thread_n);
+        }
+    }
+    // Without rayon, the chunks of the array are sorted with the single
+    // thread stable sort and merged on the std threads.
+    #[cfg(all(
+        feature = "voracious_multithread_std",
+        not(feature = "voracious_multithread")
+    ))]
+    fn voracious_mt_stable_sort(&self, arr: &mut [T], thread_n: usize) {
+        if arr.len() <= 100_000 {
+            self.voracious_stable_sort(arr);
+        } else {
+            std_mt_stable_sort(arr, thread_n);
+        }
+    }
+    #[cfg(any(
+        feature = "voracious_multithread",
+        feature = "voracious_multithread_std"
+    ))]
+    fn voracious_mt_stable_sort_desc(&self, arr: &mut [T], thread_n: usize) {
+        let arr = Desc::from_slice_mut(arr);
+        let dummy = arr[0];
+        dummy.voracious_mt_stable_sort(arr, thread_n);
+    }
+    // Upper bound of the scratch memory (in bytes) of `voracious_sort` on
+    // `arr`. It follows the choices of `voracious_sort`, so the two methods
+    // are overridden together.
+    fn voracious_sort_scratch(&self, arr: &[T]) -> usize {
+        bits_sort_scratch(arr)
+    }
+    // The in place radix sort best suited to the key width: only the
+    // histograms are allocated.
+    fn voracious_sort_in_place(&self, arr: &mut [T]) {
+        bits_in_place_sort(arr);
+    }
+    // `voracious_sort` if its scratch memory fits in `bytes`, otherwise the
+    // in place sort, or the comparison sort (no allocation at all) if even
+    // the histograms of the in place sort do not fit.
+    fn voracious_sort_with_memory_limit(&self, arr: &mut [T], bytes: usize) {
+        if self.voracious_sort_scratch(arr) <= bytes {
+            self.voracious_sort(arr);
+        } else if in_place_scratch(arr, 8) <= bytes {
+            self.voracious_sort_in_place(arr);
+        } else {
+            arr.sort_unstable_by(key_cmp);
+        }
+    }
 }
 
 impl<T: Radixable<bool>> Dispatcher<T, bool> for bool {
     fn voracious_sort(&self, arr: &mut [T]) {
-        arr.sort_unstable_by(|a, b| a.partial_cmp(b).unwrap());
+        arr.sort_unstable_by(key_cmp);
     }
     fn voracious_stable_sort(&self, arr: &mut [T]) {
         if arr.len() <= 128 {
-            arr.sort_by(|a, b| a.partial_cmp(b).unwrap());
+            stable_sort(arr);
         } else if arr.len() < 100_000 {
             lsd_stable_radixsort(arr, 1);
         } else {
@@ -36,27 +141,58 @@ impl<T: Radixable<bool>> Dispatcher<T, bool> for bool {
     }
     #[cfg(feature = "voracious_multithread")]
     fn voracious_mt_sort(&self, arr: &mut [T], thread_n: usize) {
-        if arr.len() < 1_000_000 {
-            arr.par_sort_unstable_by(|a, b| a.partial_cmp(b).unwrap());
+        if arr.len() < 100_000 {
+            arr.par_sort_unstable_by(key_cmp);
+        } else if arr.len() < 1_000_000 {
+            lsd_mt_radixsort(arr, 8, thread_n);
         } else {
             peeka_sort(arr, 1, 75_000, thread_n);
         }
     }
+    fn voracious_sort_desc(&self, arr: &mut [T]) {
+        let arr = Desc::from_slice_mut(arr);
+        Dispatcher::<Desc<T>, bool>::voracious_sort(self, arr);
+    }
+    fn voracious_stable_sort_desc(&self, arr: &mut [T]) {
+        let arr = Desc::from_slice_mut(arr);
+        Dispatcher::<Desc<T>, bool>::voracious_stable_sort(self, arr);
+    }
+    #[cfg(feature = "voracious_multithread")]
+    fn voracious_mt_sort_desc(&self, arr: &mut [T], thread_n: usize) {
+        let arr = Desc::from_slice_mut(arr);
+        Dispatcher::<Desc<T>, bool>::voracious_mt_sort(self, arr, thread_n);
+    }
+    fn voracious_sort_scratch(&self, _arr: &[T]) -> usize { 0 }
+    fn voracious_sort_in_place(&self, arr: &mut [T]) {
+        Dispatcher::<T, bool>::voracious_sort(self, arr);
+    }
 }
 
 impl<T: Radixable<char>> Dispatcher<T, char> for char {
     fn voracious_sort(&self, arr: &mut [T]) {
         if arr.len() <= 400 {
-            arr.sort_unstable_by(|a, b| a.partial_cmp(b).unwrap())
+            arr.sort_unstable_by(key_cmp)
         } else if arr.len() <= 9_000 {
             lsd_radixsort(arr, 7);
-        } else {
+        } else if arr.len() < 50_000_000 {
             lsd_radixsort(arr, 11);
+        } else {
+            lsd_radixsort_wc(arr, 11);
+        }
+    }
+    fn voracious_sort_scratch(&self, arr: &[T]) -> usize {
+        if arr.len() <= 400 {
+            0
+        } else if arr.len() <= 9_000 {
+            lsd_scratch(arr, 7)
+        } else {
+            lsd_scratch(arr, 11)
         }
     }
+    fn voracious_sort_in_place(&self, arr: &mut [T]) { ska_sort(arr, 8); }
     fn voracious_stable_sort(&self, arr: &mut [T]) {
         if arr.len() <= 170 {
-            arr.sort_by(|a, b| a.partial_cmp(b).unwrap())
+            stable_sort(arr)
         } else if arr.len() <= 9_000 {
             lsd_stable_radixsort(arr, 7);
         } else {
@@ -65,8 +201,10 @@ impl<T: Radixable<char>> Dispatcher<T, char> for char {
     }
     #[cfg(feature = "voracious_multithread")]
     fn voracious_mt_sort(&self, arr: &mut [T], thread_n: usize) {
-        if arr.len() <= 900_000 {
-            arr.par_sort_unstable_by(|a, b| a.partial_cmp(b).unwrap());
+        if arr.len() < 100_000 {
+            arr.par_sort_unstable_by(key_cmp);
+        } else if arr.len() <= 900_000 {
+            lsd_mt_radixsort(arr, 11, thread_n);
         } else {
             let chunk_size = if arr.len() < 5_000_000 {
                 100_000
@@ -81,29 +219,54 @@ impl<T: Radixable<char>> Dispatcher<T, char> for char {
             peeka_sort(arr, 7, chunk_size, thread_n);
         }
     }
+    fn voracious_sort_desc(&self, arr: &mut [T]) {
+        let arr = Desc::from_slice_mut(arr);
+        Dispatcher::<Desc<T>, char>::voracious_sort(self, arr);
+    }
+    fn voracious_stable_sort_desc(&self, arr: &mut [T]) {
+        let arr = Desc::from_slice_mut(arr);
+        Dispatcher::<Desc<T>, char>::voracious_stable_sort(self, arr);
+    }
+    #[cfg(feature = "voracious_multithread")]
+    fn voracious_mt_sort_desc(&self, arr: &mut [T], thread_n: usize) {
+        let arr = Desc::from_slice_mut(arr);
+        Dispatcher::<Desc<T>, char>::voracious_mt_sort(self, arr, thread_n);
+    }
 }
 
 impl<T: Radixable<f32>> Dispatcher<T, f32> for f32 {
     fn voracious_sort(&self, arr: &mut [T]) {
         if arr.len() <= 400 {
-            arr.sort_unstable_by(|a, b| a.partial_cmp(b).unwrap());
+            arr.sort_unstable_by(key_cmp);
         } else if arr.len() < 500_000 {
             lsd_radixsort(arr, 8);
         } else {
             rollercoaster_sort(arr, 8);
         }
     }
+    fn voracious_sort_scratch(&self, arr: &[T]) -> usize {
+        if arr.len() <= 400 {
+            0
+        } else if arr.len() < 500_000 {
+            lsd_scratch(arr, 8)
+        } else {
+            rollercoaster_scratch(arr, 8)
+        }
+    }
+    fn voracious_sort_in_place(&self, arr: &mut [T]) { ska_sort(arr, 8); }
     fn voracious_stable_sort(&self, arr: &mut [T]) {
         if arr.len() <= 200 {
-            arr.sort_by(|a, b| a.partial_cmp(b).unwrap());
+            stable_sort(arr);
         } else {
             lsd_stable_radixsort(arr, 8);
         }
     }
     #[cfg(feature = "voracious_multithread")]
     fn voracious_mt_sort(&self, arr: &mut [T], thread_n: usize) {
-        if arr.len() <= 800_000 {
-            arr.par_sort_unstable_by(|a, b| a.partial_cmp(b).unwrap());
+        if arr.len() < 100_000 {
+            arr.par_sort_unstable_by(key_cmp);
+        } else if arr.len() <= 800_000 {
+            lsd_mt_radixsort(arr, 8, thread_n);
         } else {
             let chunk_size = if arr.len() < 1_000_000 {
                 100_000
@@ -122,19 +285,40 @@ impl<T: Radixable<f32>> Dispatcher<T, f32> for f32 {
             peeka_sort(arr, 8, chunk_size, thread_n);
         }
     }
+    fn voracious_sort_desc(&self, arr: &mut [T]) {
+        let arr = Desc::from_slice_mut(arr);
+        Dispatcher::<Desc<T>, f32>::voracious_sort(self, arr);
+    }
+    fn voracious_stable_sort_desc(&self, arr: &mut [T]) {
+        let arr = Desc::from_slice_mut(arr);
+        Dispatcher::<Desc<T>, f32>::voracious_stable_sort(self, arr);
+    }
+    #[cfg(feature = "voracious_multithread")]
+    fn voracious_mt_sort_desc(&self, arr: &mut [T], thread_n: usize) {
+        let arr = Desc::from_slice_mut(arr);
+        Dispatcher::<Desc<T>, f32>::voracious_mt_sort(self, arr, thread_n);
+    }
 }
 
 impl<T: Radixable<f64>> Dispatcher<T, f64> for f64 {
     fn voracious_sort(&self, arr: &mut [T]) {
         if arr.len() <= 800 {
-            arr.sort_unstable_by(|a, b| a.partial_cmp(b).unwrap());
+            arr.sort_unstable_by(key_cmp);
         } else {
             rollercoaster_sort(arr, 8);
         }
     }
+    fn voracious_sort_scratch(&self, arr: &[T]) -> usize {
+        if arr.len() <= 800 {
+            0
+        } else {
+            rollercoaster_scratch(arr, 8)
+        }
+    }
+    fn voracious_sort_in_place(&self, arr: &mut [T]) { ska_sort(arr, 8); }
     fn voracious_stable_sort(&self, arr: &mut [T]) {
         if arr.len() < 350 {
-            arr.sort_by(|a, b| a.partial_cmp(b).unwrap());
+            stable_sort(arr);
         } else if arr.len() < 100_000 {
             msd_stable_radixsort(arr, 8);
         } else if arr.len() < 3_000_000 {
@@ -145,8 +329,10 @@ impl<T: Radixable<f64>> Dispatcher<T, f64> for f64 {
     }
     #[cfg(feature = "voracious_multithread")]
     fn voracious_mt_sort(&self, arr: &mut [T], thread_n: usize) {
-        if arr.len() <= 800_000 {
-            arr.par_sort_unstable_by(|a, b| a.partial_cmp(b).unwrap());
+        if arr.len() < 100_000 {
+            arr.par_sort_unstable_by(key_cmp);
+        } else if arr.len() <= 800_000 {
+            lsd_mt_radixsort(arr, 8, thread_n);
         } else {
             let chunk_size = if arr.len() < 1_000_000 {
                 75_000
@@ -165,16 +351,41 @@ impl<T: Radixable<f64>> Dispatcher<T, f64> for f64 {
             peeka_sort(arr, 8, chunk_size, thread_n);
         }
     }
+    fn voracious_sort_desc(&self, arr: &mut [T]) {
+        let arr = Desc::from_slice_mut(arr);
+        Dispatcher::<Desc<T>, f64>::voracious_sort(self, arr);
+    }
+    fn voracious_stable_sort_desc(&self, arr: &mut [T]) {
+        let arr = Desc::from_slice_mut(arr);
+        Dispatcher::<Desc<T>, f64>::voracious_stable_sort(self, arr);
+    }
+    #[cfg(feature = "voracious_multithread")]
+    fn voracious_mt_sort_desc(&self, arr: &mut [T], thread_n: usize) {
+        let arr = Desc::from_slice_mut(arr);
+        Dispatcher::<Desc<T>, f64>::voracious_mt_sort(self, arr, thread_n);
+    }
 }
 
 impl<T: Radixable<i8>> Dispatcher<T, i8> for i8 {
     fn voracious_sort(&self, arr: &mut [T]) {
         if arr.len() <= 500 {
             msd_radixsort(arr, 8);
-        } else {
+        } else if arr.len() < 50_000_000 {
             lsd_radixsort(arr, 8);
+        } else {
+            lsd_radixsort_wc(arr, 8);
+        }
+    }
+    fn voracious_sort_scratch(&self, arr: &[T]) -> usize {
+        if arr.len() <= 500 {
+            msd_scratch(arr, 8)
+        } else {
+            lsd_scratch(arr, 8)
         }
     }
+    fn voracious_sort_in_place(&self, arr: &mut [T]) {
+        american_flag_sort(arr, 8);
+    }
     fn voracious_stable_sort(&self, arr: &mut [T]) {
         if arr.len() <= 500 {
             msd_stable_radixsort(arr, 8);
@@ -185,7 +396,9 @@ impl<T: Radixable<i8>> Dispatcher<T, i8> for i8 {
     #[cfg(feature = "voracious_multithread")]
     fn voracious_mt_sort(&self, arr: &mut [T], thread_n: usize) {
         if arr.len() <= 256 {
-            arr.sort_unstable_by(|a, b| a.partial_cmp(b).unwrap());
+            arr.sort_unstable_by(key_cmp);
+        } else if (100_000..1_000_000).contains(&arr.len()) {
+            lsd_mt_radixsort(arr, 8, thread_n);
         } else if arr.len() < 5_000_000_000 {
             peeka_sort(arr, 8, 1_150_000, thread_n);
         } else {
@@ -193,6 +406,19 @@ impl<T: Radixable<i8>> Dispatcher<T, i8> for i8 {
             peeka_sort(arr, 8, 5_000, thread_n);
         }
     }
+    fn voracious_sort_desc(&self, arr: &mut [T]) {
+        let arr = Desc::from_slice_mut(arr);
+        Dispatcher::<Desc<T>, i8>::voracious_sort(self, arr);
+    }
+    fn voracious_stable_sort_desc(&self, arr: &mut [T]) {
+        let arr = Desc::from_slice_mut(arr);
+        Dispatcher::<Desc<T>, i8>::voracious_stable_sort(self, arr);
+    }
+    #[cfg(feature = "voracious_multithread")]
+    fn voracious_mt_sort_desc(&self, arr: &mut [T], thread_n: usize) {
+        let arr = Desc::from_slice_mut(arr);
+        Dispatcher::<Desc<T>, i8>::voracious_mt_sort(self, arr, thread_n);
+    }
 }
 
 #[cfg(target_pointer_width = "8")]
@@ -200,10 +426,22 @@ impl<T: Radixable<isize>> Dispatcher<T, isize> for isize {
     fn voracious_sort(&self, arr: &mut [T]) {
         if arr.len() <= 500 {
             msd_radixsort(arr, 8);
-        } else {
+        } else if arr.len() < 50_000_000 {
             lsd_radixsort(arr, 8);
+        } else {
+            lsd_radixsort_wc(arr, 8);
+        }
+    }
+    fn voracious_sort_scratch(&self, arr: &[T]) -> usize {
+        if arr.len() <= 500 {
+            msd_scratch(arr, 8)
+        } else {
+            lsd_scratch(arr, 8)
         }
     }
+    fn voracious_sort_in_place(&self, arr: &mut [T]) {
+        american_flag_sort(arr, 8);
+    }
     fn voracious_stable_sort(&self, arr: &mut [T]) {
         if arr.len() <= 500 {
             msd_stable_radixsort(arr, 8);
@@ -214,7 +452,9 @@ impl<T: Radixable<isize>> Dispatcher<T, isize> for isize {
     #[cfg(feature = "voracious_multithread")]
     fn voracious_mt_sort(&self, arr: &mut [T], thread_n: usize) {
         if arr.len() <= 256 {
-            arr.sort_unstable_by(|a, b| a.partial_cmp(b).unwrap());
+            arr.sort_unstable_by(key_cmp);
+        } else if (100_000..1_000_000).contains(&arr.len()) {
+            lsd_mt_radixsort(arr, 8, thread_n);
         } else if arr.len() < 5_000_000_000 {
             peeka_sort(arr, 8, 1_150_000, thread_n);
         } else {
@@ -222,19 +462,44 @@ impl<T: Radixable<isize>> Dispatcher<T, isize> for isize {
             peeka_sort(arr, 8, 5_000, thread_n);
         }
     }
+    fn voracious_sort_desc(&self, arr: &mut [T]) {
+        let arr = Desc::from_slice_mut(arr);
+        Dispatcher::<Desc<T>, isize>::voracious_sort(self, arr);
+    }
+    fn voracious_stable_sort_desc(&self, arr: &mut [T]) {
+        let arr = Desc::from_slice_mut(arr);
+        Dispatcher::<Desc<T>, isize>::voracious_stable_sort(self, arr);
+    }
+    #[cfg(feature = "voracious_multithread")]
+    fn voracious_mt_sort_desc(&self, arr: &mut [T], thread_n: usize) {
+        let arr = Desc::from_slice_mut(arr);
+        Dispatcher::<Desc<T>, isize>::voracious_mt_sort(self, arr, thread_n);
+    }
 }
 
 impl<T: Radixable<i16>> Dispatcher<T, i16> for i16 {
     fn voracious_sort(&self, arr: &mut [T]) {
         if arr.len() <= 200 {
-            arr.sort_unstable_by(|a, b| a.partial_cmp(b).unwrap());
-        } else {
+            arr.sort_unstable_by(key_cmp);
+        } else if arr.len() < 50_000_000 {
             lsd_radixsort(arr, 8);
+        } else {
+            lsd_radixsort_wc(arr, 8);
         }
     }
+    fn voracious_sort_scratch(&self, arr: &[T]) -> usize {
+        if arr.len() <= 200 {
+            0
+        } else {
+            lsd_scratch(arr, 8)
+        }
+    }
+    fn voracious_sort_in_place(&self, arr: &mut [T]) {
+        american_flag_sort(arr, 8);
+    }
     fn voracious_stable_sort(&self, arr: &mut [T]) {
         if arr.len() <= 200 {
-            arr.sort_by(|a, b| a.partial_cmp(b).unwrap());
+            stable_sort(arr);
         } else {
             lsd_stable_radixsort(arr, 8);
         }
@@ -242,7 +507,9 @@ impl<T: Radixable<i16>> Dispatcher<T, i16> for i16 {
     #[cfg(feature = "voracious_multithread")]
     fn voracious_mt_sort(&self, arr: &mut [T], thread_n: usize) {
         if arr.len() <= 256 {
-            arr.sort_unstable_by(|a, b| a.partial_cmp(b).unwrap());
+            arr.sort_unstable_by(key_cmp);
+        } else if (100_000..1_000_000).contains(&arr.len()) {
+            lsd_mt_radixsort(arr, 8, thread_n);
         } else if arr.len() < 5_000_000_000 {
             peeka_sort(arr, 8, 1_150_000, thread_n);
         } else {
@@ -250,20 +517,45 @@ impl<T: Radixable<i16>> Dispatcher<T, i16> for i16 {
             peeka_sort(arr, 8, 5_000, thread_n);
         }
     }
+    fn voracious_sort_desc(&self, arr: &mut [T]) {
+        let arr = Desc::from_slice_mut(arr);
+        Dispatcher::<Desc<T>, i16>::voracious_sort(self, arr);
+    }
+    fn voracious_stable_sort_desc(&self, arr: &mut [T]) {
+        let arr = Desc::from_slice_mut(arr);
+        Dispatcher::<Desc<T>, i16>::voracious_stable_sort(self, arr);
+    }
+    #[cfg(feature = "voracious_multithread")]
+    fn voracious_mt_sort_desc(&self, arr: &mut [T], thread_n: usize) {
+        let arr = Desc::from_slice_mut(arr);
+        Dispatcher::<Desc<T>, i16>::voracious_mt_sort(self, arr, thread_n);
+    }
 }
 
 #[cfg(target_pointer_width = "16")]
 impl<T: Radixable<isize>> Dispatcher<T, isize> for isize {
     fn voracious_sort(&self, arr: &mut [T]) {
         if arr.len() <= 200 {
-            arr.sort_unstable_by(|a, b| a.partial_cmp(b).unwrap());
-        } else {
+            arr.sort_unstable_by(key_cmp);
+        } else if arr.len() < 50_000_000 {
             lsd_radixsort(arr, 8);
+        } else {
+            lsd_radixsort_wc(arr, 8);
+        }
+    }
+    fn voracious_sort_scratch(&self, arr: &[T]) -> usize {
+        if arr.len() <= 200 {
+            0
+        } else {
+            lsd_scratch(arr, 8)
         }
     }
+    fn voracious_sort_in_place(&self, arr: &mut [T]) {
+        american_flag_sort(arr, 8);
+    }
     fn voracious_stable_sort(&self, arr: &mut [T]) {
         if arr.len() <= 200 {
-            arr.sort_by(|a, b| a.partial_cmp(b).unwrap());
+            stable_sort(arr);
         } else {
             lsd_stable_radixsort(arr, 8);
         }
@@ -271,7 +563,9 @@ impl<T: Radixable<isize>> Dispatcher<T, isize> for isize {
     #[cfg(feature = "voracious_multithread")]
     fn voracious_mt_sort(&self, arr: &mut [T], thread_n: usize) {
         if arr.len() <= 256 {
-            arr.sort_unstable_by(|a, b| a.partial_cmp(b).unwrap());
+            arr.sort_unstable_by(key_cmp);
+        } else if (100_000..1_000_000).contains(&arr.len()) {
+            lsd_mt_radixsort(arr, 8, thread_n);
         } else if arr.len() < 5_000_000_000 {
             peeka_sort(arr, 8, 1_150_000, thread_n);
         } else {
@@ -279,19 +573,42 @@ impl<T: Radixable<isize>> Dispatcher<T, isize> for isize {
             peeka_sort(arr, 8, 5_000, thread_n);
         }
     }
+    fn voracious_sort_desc(&self, arr: &mut [T]) {
+        let arr = Desc::from_slice_mut(arr);
+        Dispatcher::<Desc<T>, isize>::voracious_sort(self, arr);
+    }
+    fn voracious_stable_sort_desc(&self, arr: &mut [T]) {
+        let arr = Desc::from_slice_mut(arr);
+        Dispatcher::<Desc<T>, isize>::voracious_stable_sort(self, arr);
+    }
+    #[cfg(feature = "voracious_multithread")]
+    fn voracious_mt_sort_desc(&self, arr: &mut [T], thread_n: usize) {
+        let arr = Desc::from_slice_mut(arr);
+        Dispatcher::<Desc<T>, isize>::voracious_mt_sort(self, arr, thread_n);
+    }
 }
 
 impl<T: Radixable<i32>> Dispatcher<T, i32> for i32 {
     fn voracious_sort(&self, arr: &mut [T]) {
-        lsd_radixsort(arr, 8);
+        if arr.len() < 50_000_000 {
+            lsd_radixsort(arr, 8);
+        } else {
+            lsd_radixsort_wc(arr, 8);
+        }
+    }
+    fn voracious_sort_scratch(&self, arr: &[T]) -> usize {
+        lsd_scratch(arr, 8)
     }
+    fn voracious_sort_in_place(&self, arr: &mut [T]) { ska_sort(arr, 8); }
     fn voracious_stable_sort(&self, arr: &mut [T]) {
         lsd_stable_radixsort(arr, 8);
     }
     #[cfg(feature = "voracious_multithread")]
     fn voracious_mt_sort(&self, arr: &mut [T], thread_n: usize) {
         if arr.len() <= 256 {
-            arr.sort_unstable_by(|a, b| a.partial_cmp(b).unwrap());
+            arr.sort_unstable_by(key_cmp);
+        } else if (100_000..1_000_000).contains(&arr.len()) {
+            lsd_mt_radixsort(arr, 8, thread_n);
         } else if arr.len() < 5_000_000_000 {
             peeka_sort(arr, 8, 1_150_000, thread_n);
         } else {
@@ -299,19 +616,42 @@ impl<T: Radixable<i32>> Dispatcher<T, i32> for i32 {
             peeka_sort(arr, 8, 5_000, thread_n);
         }
     }
+    fn voracious_sort_desc(&self, arr: &mut [T]) {
+        let arr = Desc::from_slice_mut(arr);
+        Dispatcher::<Desc<T>, i32>::voracious_sort(self, arr);
+    }
+    fn voracious_stable_sort_desc(&self, arr: &mut [T]) {
+        let arr = Desc::from_slice_mut(arr);
+        Dispatcher::<Desc<T>, i32>::voracious_stable_sort(self, arr);
+    }
+    #[cfg(feature = "voracious_multithread")]
+    fn voracious_mt_sort_desc(&self, arr: &mut [T], thread_n: usize) {
+        let arr = Desc::from_slice_mut(arr);
+        Dispatcher::<Desc<T>, i32>::voracious_mt_sort(self, arr, thread_n);
+    }
 }
 
 #[cfg(target_pointer_width = "32")]
 impl<T: Radixable<isize>> Dispatcher<T, isize> for isize {
     fn voracious_sort(&self, arr: &mut [T]) {
-        lsd_radixsort(arr, 8);
+        if arr.len() < 50_000_000 {
+            lsd_radixsort(arr, 8);
+        } else {
+            lsd_radixsort_wc(arr, 8);
+        }
     }
+    fn voracious_sort_scratch(&self, arr: &[T]) -> usize {
+        lsd_scratch(arr, 8)
+    }
+    fn voracious_sort_in_place(&self, arr: &mut [T]) { ska_sort(arr, 8); }
     fn voracious_stable_sort(&self, arr: &mut [T]) {
         lsd_stable_radixsort(arr, 8);
     }
     fn voracious_mt_sort(&self, arr: &mut [T], thread_n: usize) {
         if arr.len() <= 256 {
-            arr.sort_unstable_by(|a, b| a.partial_cmp(b).unwrap());
+            arr.sort_unstable_by(key_cmp);
+        } else if (100_000..1_000_000).contains(&arr.len()) {
+            lsd_mt_radixsort(arr, 8, thread_n);
         } else if arr.len() < 5_000_000_000 {
             peeka_sort(arr, 8, 1_150_000, thread_n);
         } else {
@@ -319,12 +659,25 @@ impl<T: Radixable<isize>> Dispatcher<T, isize> for isize {
             peeka_sort(arr, 8, 5_000, thread_n);
         }
     }
+    fn voracious_sort_desc(&self, arr: &mut [T]) {
+        let arr = Desc::from_slice_mut(arr);
+        Dispatcher::<Desc<T>, isize>::voracious_sort(self, arr);
+    }
+    fn voracious_stable_sort_desc(&self, arr: &mut [T]) {
+        let arr = Desc::from_slice_mut(arr);
+        Dispatcher::<Desc<T>, isize>::voracious_stable_sort(self, arr);
+    }
+    #[cfg(feature = "voracious_multithread")]
+    fn voracious_mt_sort_desc(&self, arr: &mut [T], thread_n: usize) {
+        let arr = Desc::from_slice_mut(arr);
+        Dispatcher::<Desc<T>, isize>::voracious_mt_sort(self, arr, thread_n);
+    }
 }
 
 impl<T: Radixable<i64>> Dispatcher<T, i64> for i64 {
     fn voracious_sort(&self, arr: &mut [T]) {
         if arr.len() <= 200 {
-            arr.sort_unstable_by(|a, b| a.partial_cmp(b).unwrap());
+            arr.sort_unstable_by(key_cmp);
         } else if arr.len() <= 8000 {
             msd_radixsort(arr, 8);
         } else if arr.len() <= 100_000 {
@@ -333,9 +686,21 @@ impl<T: Radixable<i64>> Dispatcher<T, i64> for i64 {
             voracious_sort(arr, 8);
         }
     }
+    fn voracious_sort_scratch(&self, arr: &[T]) -> usize {
+        if arr.len() <= 200 {
+            0
+        } else if arr.len() <= 8000 {
+            msd_scratch(arr, 8)
+        } else if arr.len() <= 100_000 {
+            lsd_scratch(arr, 8)
+        } else {
+            voracious_scratch(arr, 8)
+        }
+    }
+    fn voracious_sort_in_place(&self, arr: &mut [T]) { ska_sort(arr, 8); }
     fn voracious_stable_sort(&self, arr: &mut [T]) {
         if arr.len() <= 200 {
-            arr.sort_by(|a, b| a.partial_cmp(b).unwrap());
+            stable_sort(arr);
         } else if arr.len() <= 8000 {
             msd_stable_radixsort(arr, 8);
         } else if arr.len() <= 100_000 {
@@ -347,7 +712,9 @@ impl<T: Radixable<i64>> Dispatcher<T, i64> for i64 {
     #[cfg(feature = "voracious_multithread")]
     fn voracious_mt_sort(&self, arr: &mut [T], thread_n: usize) {
         if arr.len() <= 256 {
-            arr.sort_unstable_by(|a, b| a.partial_cmp(b).unwrap());
+            arr.sort_unstable_by(key_cmp);
+        } else if (100_000..1_000_000).contains(&arr.len()) {
+            lsd_mt_radixsort(arr, 8, thread_n);
         } else if arr.len() < 5_000_000_000 {
             peeka_sort(arr, 8, 650_000, thread_n);
         } else {
@@ -355,13 +722,26 @@ impl<T: Radixable<i64>> Dispatcher<T, i64> for i64 {
             peeka_sort(arr, 8, 5_000, thread_n);
         }
     }
+    fn voracious_sort_desc(&self, arr: &mut [T]) {
+        let arr = Desc::from_slice_mut(arr);
+        Dispatcher::<Desc<T>, i64>::voracious_sort(self, arr);
+    }
+    fn voracious_stable_sort_desc(&self, arr: &mut [T]) {
+        let arr = Desc::from_slice_mut(arr);
+        Dispatcher::<Desc<T>, i64>::voracious_stable_sort(self, arr);
+    }
+    #[cfg(feature = "voracious_multithread")]
+    fn voracious_mt_sort_desc(&self, arr: &mut [T], thread_n: usize) {
+        let arr = Desc::from_slice_mut(arr);
+        Dispatcher::<Desc<T>, i64>::voracious_mt_sort(self, arr, thread_n);
+    }
 }
 
 #[cfg(target_pointer_width = "64")]
 impl<T: Radixable<isize>> Dispatcher<T, isize> for isize {
     fn voracious_sort(&self, arr: &mut [T]) {
         if arr.len() <= 200 {
-            arr.sort_unstable_by(|a, b| a.partial_cmp(b).unwrap());
+            arr.sort_unstable_by(key_cmp);
         } else if arr.len() <= 8000 {
             msd_radixsort(arr, 8);
         } else if arr.len() <= 100_000 {
@@ -370,9 +750,21 @@ impl<T: Radixable<isize>> Dispatcher<T, isize> for isize {
             voracious_sort(arr, 8);
         }
     }
+    fn voracious_sort_scratch(&self, arr: &[T]) -> usize {
+        if arr.len() <= 200 {
+            0
+        } else if arr.len() <= 8000 {
+            msd_scratch(arr, 8)
+        } else if arr.len() <= 100_000 {
+            lsd_scratch(arr, 8)
+        } else {
+            voracious_scratch(arr, 8)
+        }
+    }
+    fn voracious_sort_in_place(&self, arr: &mut [T]) { ska_sort(arr, 8); }
     fn voracious_stable_sort(&self, arr: &mut [T]) {
         if arr.len() <= 200 {
-            arr.sort_by(|a, b| a.partial_cmp(b).unwrap());
+            stable_sort(arr);
         } else if arr.len() <= 8000 {
             msd_stable_radixsort(arr, 8);
         } else if arr.len() <= 100_000 {
@@ -384,7 +776,9 @@ impl<T: Radixable<isize>> Dispatcher<T, isize> for isize {
     #[cfg(feature = "voracious_multithread")]
     fn voracious_mt_sort(&self, arr: &mut [T], thread_n: usize) {
         if arr.len() <= 256 {
-            arr.sort_unstable_by(|a, b| a.partial_cmp(b).unwrap());
+            arr.sort_unstable_by(key_cmp);
+        } else if (100_000..1_000_000).contains(&arr.len()) {
+            lsd_mt_radixsort(arr, 8, thread_n);
         } else if arr.len() < 5_000_000_000 {
             peeka_sort(arr, 8, 650_000, thread_n);
         } else {
@@ -392,19 +786,38 @@ impl<T: Radixable<isize>> Dispatcher<T, isize> for isize {
             peeka_sort(arr, 8, 5_000, thread_n);
         }
     }
+    fn voracious_sort_desc(&self, arr: &mut [T]) {
+        let arr = Desc::from_slice_mut(arr);
+        Dispatcher::<Desc<T>, isize>::voracious_sort(self, arr);
+    }
+    fn voracious_stable_sort_desc(&self, arr: &mut [T]) {
+        let arr = Desc::from_slice_mut(arr);
+        Dispatcher::<Desc<T>, isize>::voracious_stable_sort(self, arr);
+    }
+    #[cfg(feature = "voracious_multithread")]
+    fn voracious_mt_sort_desc(&self, arr: &mut [T], thread_n: usize) {
+        let arr = Desc::from_slice_mut(arr);
+        Dispatcher::<Desc<T>, isize>::voracious_mt_sort(self, arr, thread_n);
+    }
 }
 
 impl<T: Radixable<i128>> Dispatcher<T, i128> for i128 {
     fn voracious_sort(&self, arr: &mut [T]) {
         voracious_sort(arr, 8);
     }
+    fn voracious_sort_scratch(&self, arr: &[T]) -> usize {
+        voracious_scratch(arr, 8)
+    }
+    fn voracious_sort_in_place(&self, arr: &mut [T]) { ska_sort(arr, 8); }
     fn voracious_stable_sort(&self, arr: &mut [T]) {
         msd_stable_radixsort(arr, 8);
     }
     #[cfg(feature = "voracious_multithread")]
     fn voracious_mt_sort(&self, arr: &mut [T], thread_n: usize) {
         if arr.len() <= 256 {
-            arr.sort_unstable_by(|a, b| a.partial_cmp(b).unwrap());
+            arr.sort_unstable_by(key_cmp);
+        } else if (100_000..1_000_000).contains(&arr.len()) {
+            lsd_mt_radixsort(arr, 8, thread_n);
         } else if arr.len() < 5_000_000_000 {
             peeka_sort(arr, 8, 650_000, thread_n);
         } else {
@@ -412,6 +825,19 @@ impl<T: Radixable<i128>> Dispatcher<T, i128> for i128 {
             peeka_sort(arr, 8, 5_000, thread_n);
         }
     }
+    fn voracious_sort_desc(&self, arr: &mut [T]) {
+        let arr = Desc::from_slice_mut(arr);
+        Dispatcher::<Desc<T>, i128>::voracious_sort(self, arr);
+    }
+    fn voracious_stable_sort_desc(&self, arr: &mut [T]) {
+        let arr = Desc::from_slice_mut(arr);
+        Dispatcher::<Desc<T>, i128>::voracious_stable_sort(self, arr);
+    }
+    #[cfg(feature = "voracious_multithread")]
+    fn voracious_mt_sort_desc(&self, arr: &mut [T], thread_n: usize) {
+        let arr = Desc::from_slice_mut(arr);
+        Dispatcher::<Desc<T>, i128>::voracious_mt_sort(self, arr, thread_n);
+    }
 }
 
 #[cfg(target_pointer_width = "128")]
@@ -419,13 +845,19 @@ impl<T: Radixable<isize>> Dispatcher<T, isize> for isize {
     fn voracious_sort(&self, arr: &mut [T]) {
         voracious_sort(arr, 8);
     }
+    fn voracious_sort_scratch(&self, arr: &[T]) -> usize {
+        voracious_scratch(arr, 8)
+    }
+    fn voracious_sort_in_place(&self, arr: &mut [T]) { ska_sort(arr, 8); }
     fn voracious_stable_sort(&self, arr: &mut [T]) {
         msd_stable_radixsort(arr, 8);
     }
     #[cfg(feature = "voracious_multithread")]
     fn voracious_mt_sort(&self, arr: &mut [T], thread_n: usize) {
         if arr.len() <= 256 {
-            arr.sort_unstable_by(|a, b| a.partial_cmp(b).unwrap());
+            arr.sort_unstable_by(key_cmp);
+        } else if (100_000..1_000_000).contains(&arr.len()) {
+            lsd_mt_radixsort(arr, 8, thread_n);
         } else if arr.len() < 5_000_000_000 {
             peeka_sort(arr, 8, 650_000, thread_n);
         } else {
@@ -433,16 +865,41 @@ impl<T: Radixable<isize>> Dispatcher<T, isize> for isize {
             peeka_sort(arr, 8, 5_000, thread_n);
         }
     }
+    fn voracious_sort_desc(&self, arr: &mut [T]) {
+        let arr = Desc::from_slice_mut(arr);
+        Dispatcher::<Desc<T>, isize>::voracious_sort(self, arr);
+    }
+    fn voracious_stable_sort_desc(&self, arr: &mut [T]) {
+        let arr = Desc::from_slice_mut(arr);
+        Dispatcher::<Desc<T>, isize>::voracious_stable_sort(self, arr);
+    }
+    #[cfg(feature = "voracious_multithread")]
+    fn voracious_mt_sort_desc(&self, arr: &mut [T], thread_n: usize) {
+        let arr = Desc::from_slice_mut(arr);
+        Dispatcher::<Desc<T>, isize>::voracious_mt_sort(self, arr, thread_n);
+    }
 }
 
 impl<T: Radixable<u8>> Dispatcher<T, u8> for u8 {
     fn voracious_sort(&self, arr: &mut [T]) {
         if arr.len() <= 500 {
             msd_radixsort(arr, 8);
-        } else {
+        } else if arr.len() < 50_000_000 {
             lsd_radixsort(arr, 8);
+        } else {
+            lsd_radixsort_wc(arr, 8);
+        }
+    }
+    fn voracious_sort_scratch(&self, arr: &[T]) -> usize {
+        if arr.len() <= 500 {
+            msd_scratch(arr, 8)
+        } else {
+            lsd_scratch(arr, 8)
         }
     }
+    fn voracious_sort_in_place(&self, arr: &mut [T]) {
+        american_flag_sort(arr, 8);
+    }
     fn voracious_stable_sort(&self, arr: &mut [T]) {
         if arr.len() <= 500 {
             msd_stable_radixsort(arr, 8);
@@ -453,7 +910,9 @@ impl<T: Radixable<u8>> Dispatcher<T, u8> for u8 {
     #[cfg(feature = "voracious_multithread")]
     fn voracious_mt_sort(&self, arr: &mut [T], thread_n: usize) {
         if arr.len() <= 256 {
-            arr.sort_unstable_by(|a, b| a.partial_cmp(b).unwrap());
+            arr.sort_unstable_by(key_cmp);
+        } else if (100_000..1_000_000).contains(&arr.len()) {
+            lsd_mt_radixsort(arr, 8, thread_n);
         } else if arr.len() < 5_000_000_000 {
             peeka_sort(arr, 8, 1_150_000, thread_n);
         } else {
@@ -461,6 +920,19 @@ impl<T: Radixable<u8>> Dispatcher<T, u8> for u8 {
             peeka_sort(arr, 8, 5_000, thread_n);
         }
     }
+    fn voracious_sort_desc(&self, arr: &mut [T]) {
+        let arr = Desc::from_slice_mut(arr);
+        Dispatcher::<Desc<T>, u8>::voracious_sort(self, arr);
+    }
+    fn voracious_stable_sort_desc(&self, arr: &mut [T]) {
+        let arr = Desc::from_slice_mut(arr);
+        Dispatcher::<Desc<T>, u8>::voracious_stable_sort(self, arr);
+    }
+    #[cfg(feature = "voracious_multithread")]
+    fn voracious_mt_sort_desc(&self, arr: &mut [T], thread_n: usize) {
+        let arr = Desc::from_slice_mut(arr);
+        Dispatcher::<Desc<T>, u8>::voracious_mt_sort(self, arr, thread_n);
+    }
 }
 
 #[cfg(target_pointer_width = "8")]
@@ -468,10 +940,22 @@ impl<T: Radixable<usize>> Dispatcher<T, usize> for usize {
     fn voracious_sort(&self, arr: &mut [T]) {
         if arr.len() <= 500 {
             msd_radixsort(arr, 8);
-        } else {
+        } else if arr.len() < 50_000_000 {
             lsd_radixsort(arr, 8);
+        } else {
+            lsd_radixsort_wc(arr, 8);
+        }
+    }
+    fn voracious_sort_scratch(&self, arr: &[T]) -> usize {
+        if arr.len() <= 500 {
+            msd_scratch(arr, 8)
+        } else {
+            lsd_scratch(arr, 8)
         }
     }
+    fn voracious_sort_in_place(&self, arr: &mut [T]) {
+        american_flag_sort(arr, 8);
+    }
     fn voracious_stable_sort(&self, arr: &mut [T]) {
         if arr.len() <= 500 {
             msd_stable_radixsort(arr, 8);
@@ -482,7 +966,9 @@ impl<T: Radixable<usize>> Dispatcher<T, usize> for usize {
     #[cfg(feature = "voracious_multithread")]
     fn voracious_mt_sort(&self, arr: &mut [T], thread_n: usize) {
         if arr.len() <= 256 {
-            arr.sort_unstable_by(|a, b| a.partial_cmp(b).unwrap());
+            arr.sort_unstable_by(key_cmp);
+        } else if (100_000..1_000_000).contains(&arr.len()) {
+            lsd_mt_radixsort(arr, 8, thread_n);
         } else if arr.len() < 5_000_000_000 {
             peeka_sort(arr, 8, 1_150_000, thread_n);
         } else {
@@ -490,19 +976,44 @@ impl<T: Radixable<usize>> Dispatcher<T, usize> for usize {
             peeka_sort(arr, 8, 5_000, thread_n);
         }
     }
+    fn voracious_sort_desc(&self, arr: &mut [T]) {
+        let arr = Desc::from_slice_mut(arr);
+        Dispatcher::<Desc<T>, usize>::voracious_sort(self, arr);
+    }
+    fn voracious_stable_sort_desc(&self, arr: &mut [T]) {
+        let arr = Desc::from_slice_mut(arr);
+        Dispatcher::<Desc<T>, usize>::voracious_stable_sort(self, arr);
+    }
+    #[cfg(feature = "voracious_multithread")]
+    fn voracious_mt_sort_desc(&self, arr: &mut [T], thread_n: usize) {
+        let arr = Desc::from_slice_mut(arr);
+        Dispatcher::<Desc<T>, usize>::voracious_mt_sort(self, arr, thread_n);
+    }
 }
 
 impl<T: Radixable<u16>> Dispatcher<T, u16> for u16 {
     fn voracious_sort(&self, arr: &mut [T]) {
         if arr.len() <= 200 {
-            arr.sort_unstable_by(|a, b| a.partial_cmp(b).unwrap());
-        } else {
+            arr.sort_unstable_by(key_cmp);
+        } else if arr.len() < 50_000_000 {
             lsd_radixsort(arr, 8);
+        } else {
+            lsd_radixsort_wc(arr, 8);
+        }
+    }
+    fn voracious_sort_scratch(&self, arr: &[T]) -> usize {
+        if arr.len() <= 200 {
+            0
+        } else {
+            lsd_scratch(arr, 8)
         }
     }
+    fn voracious_sort_in_place(&self, arr: &mut [T]) {
+        american_flag_sort(arr, 8);
+    }
     fn voracious_stable_sort(&self, arr: &mut [T]) {
         if arr.len() <= 200 {
-            arr.sort_by(|a, b| a.partial_cmp(b).unwrap());
+            stable_sort(arr);
         } else {
             lsd_stable_radixsort(arr, 8);
         }
@@ -510,7 +1021,9 @@ impl<T: Radixable<u16>> Dispatcher<T, u16> for u16 {
     #[cfg(feature = "voracious_multithread")]
     fn voracious_mt_sort(&self, arr: &mut [T], thread_n: usize) {
         if arr.len() <= 256 {
-            arr.sort_unstable_by(|a, b| a.partial_cmp(b).unwrap());
+            arr.sort_unstable_by(key_cmp);
+        } else if (100_000..1_000_000).contains(&arr.len()) {
+            lsd_mt_radixsort(arr, 8, thread_n);
         } else if arr.len() < 5_000_000_000 {
             peeka_sort(arr, 8, 1_150_000, thread_n);
         } else {
@@ -518,27 +1031,54 @@ impl<T: Radixable<u16>> Dispatcher<T, u16> for u16 {
             peeka_sort(arr, 8, 5_000, thread_n);
         }
     }
+    fn voracious_sort_desc(&self, arr: &mut [T]) {
+        let arr = Desc::from_slice_mut(arr);
+        Dispatcher::<Desc<T>, u16>::voracious_sort(self, arr);
+    }
+    fn voracious_stable_sort_desc(&self, arr: &mut [T]) {
+        let arr = Desc::from_slice_mut(arr);
+        Dispatcher::<Desc<T>, u16>::voracious_stable_sort(self, arr);
+    }
+    #[cfg(feature = "voracious_multithread")]
+    fn voracious_mt_sort_desc(&self, arr: &mut [T], thread_n: usize) {
+        let arr = Desc::from_slice_mut(arr);
+        Dispatcher::<Desc<T>, u16>::voracious_mt_sort(self, arr, thread_n);
+    }
 }
 
 #[cfg(target_pointer_width = "16")]
 impl<T: Radixable<usize>> Dispatcher<T, usize> for usize {
     fn voracious_sort(&self, arr: &mut [T]) {
         if arr.len() <= 200 {
-            arr.sort_unstable_by(|a, b| a.partial_cmp(b).unwrap());
-        } else {
+            arr.sort_unstable_by(key_cmp);
+        } else if arr.len() < 50_000_000 {
             lsd_radixsort(arr, 8);
+        } else {
+            lsd_radixsort_wc(arr, 8);
         }
     }
+    fn voracious_sort_scratch(&self, arr: &[T]) -> usize {
+        if arr.len() <= 200 {
+            0
+        } else {
+            lsd_scratch(arr, 8)
+        }
+    }
+    fn voracious_sort_in_place(&self, arr: &mut [T]) {
+        american_flag_sort(arr, 8);
+    }
     fn voracious_stable_sort(&self, arr: &mut [T]) {
         if arr.len() <= 200 {
-            arr.sort_by(|a, b| a.partial_cmp(b).unwrap());
+            stable_sort(arr);
         } else {
             lsd_stable_radixsort(arr, 8);
         }
     }
     fn voracious_mt_sort(&self, arr: &mut [T], thread_n: usize) {
         if arr.len() <= 256 {
-            arr.sort_unstable_by(|a, b| a.partial_cmp(b).unwrap());
+            arr.sort_unstable_by(key_cmp);
+        } else if (100_000..1_000_000).contains(&arr.len()) {
+            lsd_mt_radixsort(arr, 8, thread_n);
         } else if arr.len() < 5_000_000_000 {
             peeka_sort(arr, 8, 1_150_000, thread_n);
         } else {
@@ -546,19 +1086,42 @@ impl<T: Radixable<usize>> Dispatcher<T, usize> for usize {
             peeka_sort(arr, 8, 5_000, thread_n);
         }
     }
+    fn voracious_sort_desc(&self, arr: &mut [T]) {
+        let arr = Desc::from_slice_mut(arr);
+        Dispatcher::<Desc<T>, usize>::voracious_sort(self, arr);
+    }
+    fn voracious_stable_sort_desc(&self, arr: &mut [T]) {
+        let arr = Desc::from_slice_mut(arr);
+        Dispatcher::<Desc<T>, usize>::voracious_stable_sort(self, arr);
+    }
+    #[cfg(feature = "voracious_multithread")]
+    fn voracious_mt_sort_desc(&self, arr: &mut [T], thread_n: usize) {
+        let arr = Desc::from_slice_mut(arr);
+        Dispatcher::<Desc<T>, usize>::voracious_mt_sort(self, arr, thread_n);
+    }
 }
 
 impl<T: Radixable<u32>> Dispatcher<T, u32> for u32 {
     fn voracious_sort(&self, arr: &mut [T]) {
-        lsd_radixsort(arr, 8);
+        if arr.len() < 50_000_000 {
+            lsd_radixsort(arr, 8);
+        } else {
+            lsd_radixsort_wc(arr, 8);
+        }
     }
+    fn voracious_sort_scratch(&self, arr: &[T]) -> usize {
+        lsd_scratch(arr, 8)
+    }
+    fn voracious_sort_in_place(&self, arr: &mut [T]) { ska_sort(arr, 8); }
     fn voracious_stable_sort(&self, arr: &mut [T]) {
         lsd_stable_radixsort(arr, 8);
     }
     #[cfg(feature = "voracious_multithread")]
     fn voracious_mt_sort(&self, arr: &mut [T], thread_n: usize) {
         if arr.len() <= 256 {
-            arr.sort_unstable_by(|a, b| a.partial_cmp(b).unwrap());
+            arr.sort_unstable_by(key_cmp);
+        } else if (100_000..1_000_000).contains(&arr.len()) {
+            lsd_mt_radixsort(arr, 8, thread_n);
         } else if arr.len() < 5_000_000_000 {
             peeka_sort(arr, 8, 1_150_000, thread_n);
         } else {
@@ -566,19 +1129,42 @@ impl<T: Radixable<u32>> Dispatcher<T, u32> for u32 {
             peeka_sort(arr, 8, 5_000, thread_n);
         }
     }
+    fn voracious_sort_desc(&self, arr: &mut [T]) {
+        let arr = Desc::from_slice_mut(arr);
+        Dispatcher::<Desc<T>, u32>::voracious_sort(self, arr);
+    }
+    fn voracious_stable_sort_desc(&self, arr: &mut [T]) {
+        let arr = Desc::from_slice_mut(arr);
+        Dispatcher::<Desc<T>, u32>::voracious_stable_sort(self, arr);
+    }
+    #[cfg(feature = "voracious_multithread")]
+    fn voracious_mt_sort_desc(&self, arr: &mut [T], thread_n: usize) {
+        let arr = Desc::from_slice_mut(arr);
+        Dispatcher::<Desc<T>, u32>::voracious_mt_sort(self, arr, thread_n);
+    }
 }
 
 #[cfg(target_pointer_width = "32")]
 impl<T: Radixable<usize>> Dispatcher<T, usize> for usize {
     fn voracious_sort(&self, arr: &mut [T]) {
-        lsd_radixsort(arr, 8);
+        if arr.len() < 50_000_000 {
+            lsd_radixsort(arr, 8);
+        } else {
+            lsd_radixsort_wc(arr, 8);
+        }
     }
+    fn voracious_sort_scratch(&self, arr: &[T]) -> usize {
+        lsd_scratch(arr, 8)
+    }
+    fn voracious_sort_in_place(&self, arr: &mut [T]) { ska_sort(arr, 8); }
     fn voracious_stable_sort(&self, arr: &mut [T]) {
         lsd_stable_radixsort(arr, 8);
     }
     fn voracious_mt_sort(&self, arr: &mut [T], thread_n: usize) {
         if arr.len() <= 256 {
-            arr.sort_unstable_by(|a, b| a.partial_cmp(b).unwrap());
+            arr.sort_unstable_by(key_cmp);
+        } else if (100_000..1_000_000).contains(&arr.len()) {
+            lsd_mt_radixsort(arr, 8, thread_n);
         } else if arr.len() < 5_000_000_000 {
             peeka_sort(arr, 8, 1_150_000, thread_n);
         } else {
@@ -586,19 +1172,42 @@ impl<T: Radixable<usize>> Dispatcher<T, usize> for usize {
             peeka_sort(arr, 8, 5_000, thread_n);
         }
     }
+    fn voracious_sort_desc(&self, arr: &mut [T]) {
+        let arr = Desc::from_slice_mut(arr);
+        Dispatcher::<Desc<T>, usize>::voracious_sort(self, arr);
+    }
+    fn voracious_stable_sort_desc(&self, arr: &mut [T]) {
+        let arr = Desc::from_slice_mut(arr);
+        Dispatcher::<Desc<T>, usize>::voracious_stable_sort(self, arr);
+    }
+    #[cfg(feature = "voracious_multithread")]
+    fn voracious_mt_sort_desc(&self, arr: &mut [T], thread_n: usize) {
+        let arr = Desc::from_slice_mut(arr);
+        Dispatcher::<Desc<T>, usize>::voracious_mt_sort(self, arr, thread_n);
+    }
 }
 
 impl<T: Radixable<u64>> Dispatcher<T, u64> for u64 {
     fn voracious_sort(&self, arr: &mut [T]) {
         if arr.len() <= 300 {
-            arr.sort_unstable_by(|a, b| a.partial_cmp(b).unwrap());
-        } else {
+            arr.sort_unstable_by(key_cmp);
+        } else if arr.len() < 50_000_000 {
             dlsd_radixsort(arr, 8);
+        } else {
+            dlsd_radixsort_wc(arr, 8);
         }
     }
+    fn voracious_sort_scratch(&self, arr: &[T]) -> usize {
+        if arr.len() <= 300 {
+            0
+        } else {
+            dlsd_scratch(arr)
+        }
+    }
+    fn voracious_sort_in_place(&self, arr: &mut [T]) { ska_sort(arr, 8); }
     fn voracious_stable_sort(&self, arr: &mut [T]) {
         if arr.len() <= 200 {
-            arr.sort_by(|a, b| a.partial_cmp(b).unwrap());
+            stable_sort(arr);
         } else if arr.len() <= 8000 {
             msd_stable_radixsort(arr, 8);
         } else if arr.len() <= 100_000 {
@@ -610,7 +1219,9 @@ impl<T: Radixable<u64>> Dispatcher<T, u64> for u64 {
     #[cfg(feature = "voracious_multithread")]
     fn voracious_mt_sort(&self, arr: &mut [T], thread_n: usize) {
         if arr.len() <= 256 {
-            arr.sort_unstable_by(|a, b| a.partial_cmp(b).unwrap());
+            arr.sort_unstable_by(key_cmp);
+        } else if (100_000..1_000_000).contains(&arr.len()) {
+            lsd_mt_radixsort(arr, 8, thread_n);
         } else if arr.len() < 5_000_000_000 {
             peeka_sort(arr, 8, 650_000, thread_n);
         } else {
@@ -618,20 +1229,43 @@ impl<T: Radixable<u64>> Dispatcher<T, u64> for u64 {
             peeka_sort(arr, 8, 5_000, thread_n);
         }
     }
+    fn voracious_sort_desc(&self, arr: &mut [T]) {
+        let arr = Desc::from_slice_mut(arr);
+        Dispatcher::<Desc<T>, u64>::voracious_sort(self, arr);
+    }
+    fn voracious_stable_sort_desc(&self, arr: &mut [T]) {
+        let arr = Desc::from_slice_mut(arr);
+        Dispatcher::<Desc<T>, u64>::voracious_stable_sort(self, arr);
+    }
+    #[cfg(feature = "voracious_multithread")]
+    fn voracious_mt_sort_desc(&self, arr: &mut [T], thread_n: usize) {
+        let arr = Desc::from_slice_mut(arr);
+        Dispatcher::<Desc<T>, u64>::voracious_mt_sort(self, arr, thread_n);
+    }
 }
 
 #[cfg(target_pointer_width = "64")]
 impl<T: Radixable<usize>> Dispatcher<T, usize> for usize {
     fn voracious_sort(&self, arr: &mut [T]) {
         if arr.len() <= 300 {
-            arr.sort_unstable_by(|a, b| a.partial_cmp(b).unwrap());
-        } else {
+            arr.sort_unstable_by(key_cmp);
+        } else if arr.len() < 50_000_000 {
             dlsd_radixsort(arr, 8);
+        } else {
+            dlsd_radixsort_wc(arr, 8);
         }
     }
+    fn voracious_sort_scratch(&self, arr: &[T]) -> usize {
+        if arr.len() <= 300 {
+            0
+        } else {
+            dlsd_scratch(arr)
+        }
+    }
+    fn voracious_sort_in_place(&self, arr: &mut [T]) { ska_sort(arr, 8); }
     fn voracious_stable_sort(&self, arr: &mut [T]) {
         if arr.len() <= 200 {
-            arr.sort_by(|a, b| a.partial_cmp(b).unwrap());
+            stable_sort(arr);
         } else if arr.len() <= 8000 {
             msd_stable_radixsort(arr, 8);
         } else if arr.len() <= 100_000 {
@@ -643,7 +1277,9 @@ impl<T: Radixable<usize>> Dispatcher<T, usize> for usize {
     #[cfg(feature = "voracious_multithread")]
     fn voracious_mt_sort(&self, arr: &mut [T], thread_n: usize) {
         if arr.len() <= 256 {
-            arr.sort_unstable_by(|a, b| a.partial_cmp(b).unwrap());
+            arr.sort_unstable_by(key_cmp);
+        } else if (100_000..1_000_000).contains(&arr.len()) {
+            lsd_mt_radixsort(arr, 8, thread_n);
         } else if arr.len() < 5_000_000_000 {
             peeka_sort(arr, 8, 650_000, thread_n);
         } else {
@@ -651,19 +1287,38 @@ impl<T: Radixable<usize>> Dispatcher<T, usize> for usize {
             peeka_sort(arr, 8, 5_000, thread_n);
         }
     }
+    fn voracious_sort_desc(&self, arr: &mut [T]) {
+        let arr = Desc::from_slice_mut(arr);
+        Dispatcher::<Desc<T>, usize>::voracious_sort(self, arr);
+    }
+    fn voracious_stable_sort_desc(&self, arr: &mut [T]) {
+        let arr = Desc::from_slice_mut(arr);
+        Dispatcher::<Desc<T>, usize>::voracious_stable_sort(self, arr);
+    }
+    #[cfg(feature = "voracious_multithread")]
+    fn voracious_mt_sort_desc(&self, arr: &mut [T], thread_n: usize) {
+        let arr = Desc::from_slice_mut(arr);
+        Dispatcher::<Desc<T>, usize>::voracious_mt_sort(self, arr, thread_n);
+    }
 }
 
 impl<T: Radixable<u128>> Dispatcher<T, u128> for u128 {
     fn voracious_sort(&self, arr: &mut [T]) {
         voracious_sort(arr, 8);
     }
+    fn voracious_sort_scratch(&self, arr: &[T]) -> usize {
+        voracious_scratch(arr, 8)
+    }
+    fn voracious_sort_in_place(&self, arr: &mut [T]) { ska_sort(arr, 8); }
     fn voracious_stable_sort(&self, arr: &mut [T]) {
         msd_stable_radixsort(arr, 8);
     }
     #[cfg(feature = "voracious_multithread")]
     fn voracious_mt_sort(&self, arr: &mut [T], thread_n: usize) {
         if arr.len() <= 256 {
-            arr.sort_unstable_by(|a, b| a.partial_cmp(b).unwrap());
+            arr.sort_unstable_by(key_cmp);
+        } else if (100_000..1_000_000).contains(&arr.len()) {
+            lsd_mt_radixsort(arr, 8, thread_n);
         } else if arr.len() < 5_000_000_000 {
             peeka_sort(arr, 8, 650_000, thread_n);
         } else {
@@ -671,6 +1326,19 @@ impl<T: Radixable<u128>> Dispatcher<T, u128> for u128 {
             peeka_sort(arr, 8, 5_000, thread_n);
         }
     }
+    fn voracious_sort_desc(&self, arr: &mut [T]) {
+        let arr = Desc::from_slice_mut(arr);
+        Dispatcher::<Desc<T>, u128>::voracious_sort(self, arr);
+    }
+    fn voracious_stable_sort_desc(&self, arr: &mut [T]) {
+        let arr = Desc::from_slice_mut(arr);
+        Dispatcher::<Desc<T>, u128>::voracious_stable_sort(self, arr);
+    }
+    #[cfg(feature = "voracious_multithread")]
+    fn voracious_mt_sort_desc(&self, arr: &mut [T], thread_n: usize) {
+        let arr = Desc::from_slice_mut(arr);
+        Dispatcher::<Desc<T>, u128>::voracious_mt_sort(self, arr, thread_n);
+    }
 }
 
 #[cfg(target_pointer_width = "128")]
@@ -678,13 +1346,19 @@ impl<T: Radixable<usize>> Dispatcher<T, usize> for usize {
     fn voracious_sort(&self, arr: &mut [T]) {
         voracious_sort(arr, 8);
     }
+    fn voracious_sort_scratch(&self, arr: &[T]) -> usize {
+        voracious_scratch(arr, 8)
+    }
+    fn voracious_sort_in_place(&self, arr: &mut [T]) { ska_sort(arr, 8); }
     fn voracious_stable_sort(&self, arr: &mut [T]) {
         msd_stable_radixsort(arr, 8);
     }
     #[cfg(feature = "voracious_multithread")]
     fn voracious_mt_sort(&self, arr: &mut [T], thread_n: usize) {
         if arr.len() <= 256 {
-            arr.sort_unstable_by(|a, b| a.partial_cmp(b).unwrap());
+            arr.sort_unstable_by(key_cmp);
+        } else if (100_000..1_000_000).contains(&arr.len()) {
+            lsd_mt_radixsort(arr, 8, thread_n);
         } else if arr.len() < 5_000_000_000 {
             peeka_sort(arr, 8, 650_000, thread_n);
         } else {
@@ -692,4 +1366,277 @@ impl<T: Radixable<usize>> Dispatcher<T, usize> for usize {
             peeka_sort(arr, 8, 5_000, thread_n);
         }
     }
+    fn voracious_sort_desc(&self, arr: &mut [T]) {
+        let arr = Desc::from_slice_mut(arr);
+        Dispatcher::<Desc<T>, usize>::voracious_sort(self, arr);
+    }
+    fn voracious_stable_sort_desc(&self, arr: &mut [T]) {
+        let arr = Desc::from_slice_mut(arr);
+        Dispatcher::<Desc<T>, usize>::voracious_stable_sort(self, arr);
+    }
+    #[cfg(feature = "voracious_multithread")]
+    fn voracious_mt_sort_desc(&self, arr: &mut [T], thread_n: usize) {
+        let arr = Desc::from_slice_mut(arr);
+        Dispatcher::<Desc<T>, usize>::voracious_mt_sort(self, arr, thread_n);
+    }
+}
+
+// Composite keys (tuples and byte arrays) are stored in a u128, but only the
+// concatenated bits of the fields are significant. The sort is chosen
+// according to this number of significant bits.
+
+fn composite_voracious_sort<T, K>(arr: &mut [T], bits: usize)
+where
+    T: Radixable<K>,
+    K: RadixKey,
+{
+    if arr.len() <= 200 {
+        arr.sort_unstable_by(key_cmp);
+    } else if bits <= 32 {
+        lsd_radixsort(arr, 8);
+    } else if bits <= 64 && arr.len() <= 8000 {
+        msd_radixsort(arr, 8);
+    } else {
+        voracious_sort(arr, 8);
+    }
+}
+
+fn composite_voracious_sort_scratch<T, K>(arr: &[T], bits: usize) -> usize
+where
+    T: Radixable<K>,
+    K: RadixKey,
+{
+    if arr.len() <= 200 {
+        0
+    } else if bits <= 32 {
+        lsd_scratch(arr, 8)
+    } else if bits <= 64 && arr.len() <= 8000 {
+        msd_scratch(arr, 8)
+    } else {
+        voracious_scratch(arr, 8)
+    }
+}
+
+fn composite_voracious_sort_in_place<T, K>(arr: &mut [T], bits: usize)
+where
+    T: Radixable<K>,
+    K: RadixKey,
+{
+    if bits <= 16 {
+        american_flag_sort(arr, 8);
+    } else {
+        ska_sort(arr, 8);
+    }
+}
+
+fn composite_voracious_stable_sort<T, K>(arr: &mut [T], bits: usize)
+where
+    T: Radixable<K>,
+    K: RadixKey,
+{
+    if arr.len() <= 200 {
+        stable_sort(arr);
+    } else if bits <= 32
+        || (bits <= 64 && arr.len() > 8000 && arr.len() <= 100_000)
+    {
+        lsd_stable_radixsort(arr, 8);
+    } else {
+        msd_stable_radixsort(arr, 8);
+    }
+}
+
+#[cfg(feature = "voracious_multithread")]
+fn composite_voracious_mt_sort<T, K>(arr: &mut [T], thread_n: usize)
+where
+    T: Radixable<K>,
+    K: RadixKey,
+{
+    if arr.len() <= 256 {
+        arr.sort_unstable_by(key_cmp);
+    } else if arr.len() < 5_000_000_000 {
+        peeka_sort(arr, 8, 650_000, thread_n);
+    } else {
+        // Switch to regions sort algo
+        peeka_sort(arr, 8, 5_000, thread_n);
+    }
+}
+
+impl<T, A, B> Dispatcher<T, (A, B)> for (A, B)
+where
+    T: Radixable<(A, B)>,
+    A: RadixKey,
+    B: RadixKey,
+{
+    fn voracious_sort(&self, arr: &mut [T]) {
+        let bits = self.0.type_size() + self.1.type_size();
+        composite_voracious_sort(arr, bits);
+    }
+    fn voracious_sort_scratch(&self, arr: &[T]) -> usize {
+        let bits = self.0.type_size() + self.1.type_size();
+        composite_voracious_sort_scratch(arr, bits)
+    }
+    fn voracious_sort_in_place(&self, arr: &mut [T]) {
+        let bits = self.0.type_size() + self.1.type_size();
+        composite_voracious_sort_in_place(arr, bits);
+    }
+    fn voracious_stable_sort(&self, arr: &mut [T]) {
+        let bits = self.0.type_size() + self.1.type_size();
+        composite_voracious_stable_sort(arr, bits);
+    }
+    #[cfg(feature = "voracious_multithread")]
+    fn voracious_mt_sort(&self, arr: &mut [T], thread_n: usize) {
+        composite_voracious_mt_sort(arr, thread_n);
+    }
+    fn voracious_sort_desc(&self, arr: &mut [T]) {
+        let arr = Desc::from_slice_mut(arr);
+        Dispatcher::<Desc<T>, (A, B)>::voracious_sort(self, arr);
+    }
+    fn voracious_stable_sort_desc(&self, arr: &mut [T]) {
+        let arr = Desc::from_slice_mut(arr);
+        Dispatcher::<Desc<T>, (A, B)>::voracious_stable_sort(self, arr);
+    }
+    #[cfg(feature = "voracious_multithread")]
+    fn voracious_mt_sort_desc(&self, arr: &mut [T], thread_n: usize) {
+        let arr = Desc::from_slice_mut(arr);
+        Dispatcher::<Desc<T>, (A, B)>::voracious_mt_sort(self, arr, thread_n);
+    }
+}
+
+impl<T, A, B, C> Dispatcher<T, (A, B, C)> for (A, B, C)
+where
+    T: Radixable<(A, B, C)>,
+    A: RadixKey,
+    B: RadixKey,
+    C: RadixKey,
+{
+    fn voracious_sort(&self, arr: &mut [T]) {
+        let bits =
+            self.0.type_size() + self.1.type_size() + self.2.type_size();
+        composite_voracious_sort(arr, bits);
+    }
+    fn voracious_sort_scratch(&self, arr: &[T]) -> usize {
+        let bits =
+            self.0.type_size() + self.1.type_size() + self.2.type_size();
+        composite_voracious_sort_scratch(arr, bits)
+    }
+    fn voracious_sort_in_place(&self, arr: &mut [T]) {
+        let bits =
+            self.0.type_size() + self.1.type_size() + self.2.type_size();
+        composite_voracious_sort_in_place(arr, bits);
+    }
+    fn voracious_stable_sort(&self, arr: &mut [T]) {
+        let bits =
+            self.0.type_size() + self.1.type_size() + self.2.type_size();
+        composite_voracious_stable_sort(arr, bits);
+    }
+    #[cfg(feature = "voracious_multithread")]
+    fn voracious_mt_sort(&self, arr: &mut [T], thread_n: usize) {
+        composite_voracious_mt_sort(arr, thread_n);
+    }
+    fn voracious_sort_desc(&self, arr: &mut [T]) {
+        let arr = Desc::from_slice_mut(arr);
+        Dispatcher::<Desc<T>, (A, B, C)>::voracious_sort(self, arr);
+    }
+    fn voracious_stable_sort_desc(&self, arr: &mut [T]) {
+        let arr = Desc::from_slice_mut(arr);
+        Dispatcher::<Desc<T>, (A, B, C)>::voracious_stable_sort(self, arr);
+    }
+    #[cfg(feature = "voracious_multithread")]
+    fn voracious_mt_sort_desc(&self, arr: &mut [T], thread_n: usize) {
+        let arr = Desc::from_slice_mut(arr);
+        Dispatcher::<Desc<T>, (A, B, C)>::voracious_mt_sort(
+            self, arr, thread_n,
+        );
+    }
+}
+
+impl<T, A, B, C, D> Dispatcher<T, (A, B, C, D)> for (A, B, C, D)
+where
+    T: Radixable<(A, B, C, D)>,
+    A: RadixKey,
+    B: RadixKey,
+    C: RadixKey,
+    D: RadixKey,
+{
+    fn voracious_sort(&self, arr: &mut [T]) {
+        let bits = self.0.type_size()
+            + self.1.type_size()
+            + self.2.type_size()
+            + self.3.type_size();
+        composite_voracious_sort(arr, bits);
+    }
+    fn voracious_sort_scratch(&self, arr: &[T]) -> usize {
+        let bits = self.0.type_size()
+            + self.1.type_size()
+            + self.2.type_size()
+            + self.3.type_size();
+        composite_voracious_sort_scratch(arr, bits)
+    }
+    fn voracious_sort_in_place(&self, arr: &mut [T]) {
+        let bits = self.0.type_size()
+            + self.1.type_size()
+            + self.2.type_size()
+            + self.3.type_size();
+        composite_voracious_sort_in_place(arr, bits);
+    }
+    fn voracious_stable_sort(&self, arr: &mut [T]) {
+        let bits = self.0.type_size()
+            + self.1.type_size()
+            + self.2.type_size()
+            + self.3.type_size();
+        composite_voracious_stable_sort(arr, bits);
+    }
+    #[cfg(feature = "voracious_multithread")]
+    fn voracious_mt_sort(&self, arr: &mut [T], thread_n: usize) {
+        composite_voracious_mt_sort(arr, thread_n);
+    }
+    fn voracious_sort_desc(&self, arr: &mut [T]) {
+        let arr = Desc::from_slice_mut(arr);
+        Dispatcher::<Desc<T>, (A, B, C, D)>::voracious_sort(self, arr);
+    }
+    fn voracious_stable_sort_desc(&self, arr: &mut [T]) {
+        let arr = Desc::from_slice_mut(arr);
+        Dispatcher::<Desc<T>, (A, B, C, D)>::voracious_stable_sort(self, arr);
+    }
+    #[cfg(feature = "voracious_multithread")]
+    fn voracious_mt_sort_desc(&self, arr: &mut [T], thread_n: usize) {
+        let arr = Desc::from_slice_mut(arr);
+        Dispatcher::<Desc<T>, (A, B, C, D)>::voracious_mt_sort(
+            self, arr, thread_n,
+        );
+    }
+}
+
+impl<T: Radixable<[u8; N]>, const N: usize> Dispatcher<T, [u8; N]>
+    for [u8; N]
+{
+    fn voracious_sort(&self, arr: &mut [T]) {
+        composite_voracious_sort(arr, N * 8);
+    }
+    fn voracious_sort_scratch(&self, arr: &[T]) -> usize {
+        composite_voracious_sort_scratch(arr, N * 8)
+    }
+    fn voracious_sort_in_place(&self, arr: &mut [T]) {
+        composite_voracious_sort_in_place(arr, N * 8);
+    }
+    fn voracious_stable_sort(&self, arr: &mut [T]) {
+        composite_voracious_stable_sort(arr, N * 8);
+    }
+    #[cfg(feature = "voracious_multithread")]
+    fn voracious_mt_sort(&self, arr: &mut [T], thread_n: usize) {
+        composite_voracious_mt_sort(arr, thread_n);
+    }
+    fn voracious_sort_desc(&self, arr: &mut [T]) {
+        let arr = Desc::from_slice_mut(arr);
+        Dispatcher::<Desc<T>, [u8; N]>::voracious_sort(self, arr);
+    }
+    fn voracious_stable_sort_desc(&self, arr: &mut [T]) {
+        let arr = Desc::from_slice_mut(arr);
+        Dispatcher::<Desc<T>, [u8; N]>::voracious_stable_sort(self, arr);
+    }
+    #[cfg(feature = "voracious_multithread")]
+    fn voracious_mt_sort_desc(&self, arr: &mut [T], thread_n: usize) {
+        let arr = Desc::from_slice_mut(arr);
+        Dispatcher::<Desc<T>, [u8; N]>::voracious_mt_sort(self, arr, thread_n);
+    }
 }
@@ -1,4 +1,9 @@
 pub mod dispatcher;
 pub mod radix_key;
 pub mod radixable;
+pub mod radixable_owned;
 pub mod radixsort;
+pub mod radixsort_argsort;
+pub mod radixsort_by_key;
+pub mod radixsort_owned;
+pub mod radixsort_string;
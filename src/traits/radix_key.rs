@@ -1,4 +1,4 @@
-use std::ops::{BitAnd, BitOrAssign, Shl, Shr};
+use core::ops::{BitAnd, BitOrAssign, Not, Shl, Shr};
 
 pub trait RadixKey {
     type Key: Copy
@@ -6,12 +6,14 @@ pub trait RadixKey {
         + Shr<Output = Self::Key>
         + BitAnd<Output = Self::Key>
         + BitOrAssign
+        + Not<Output = Self::Key>
+        + Into<u128>
         + PartialEq
         + PartialOrd
         + Ord
         + Send
         + Sync
-        + std::fmt::Display;
+        + core::fmt::Display;
     fn into_keytype(&self) -> Self::Key;
     fn type_size(&self) -> usize;
     fn usize_to_keytype(&self, item: usize) -> Self::Key;
@@ -441,3 +443,147 @@ impl RadixKey for isize {
     #[inline]
     fn one(&self) -> Self::Key { 1 }
 }
+
+// Composite keys: the bits of each field are concatenated, the first field
+// being the most significant one. So the order of the composite key is the
+// lexicographic order of the fields. The concatenation must fit in 128 bits:
+// the byte arrays are checked at compile time, the tuples when their key is
+// computed, otherwise the most significant fields would be shifted out.
+
+#[inline]
+fn check_composite_width(bits: usize) {
+    assert!(
+        bits <= 128,
+        "[RadixKey] A composite key cannot be wider than 128 bits."
+    );
+}
+
+#[inline]
+fn composite_type_size(bits: usize) -> usize {
+    check_composite_width(bits);
+    128
+}
+
+// Evaluated when the key of a `[u8; N]` is used, so a byte array wider than
+// 128 bits does not build.
+struct ByteArrayWidth<const N: usize>;
+
+impl<const N: usize> ByteArrayWidth<N> {
+    const CHECK: () = assert!(
+        N <= 16,
+        "[RadixKey] A composite key cannot be wider than 128 bits."
+    );
+}
+
+impl<A: RadixKey, B: RadixKey> RadixKey for (A, B) {
+    type Key = u128;
+    #[inline]
+    fn into_keytype(&self) -> Self::Key {
+        check_composite_width(self.0.type_size() + self.1.type_size());
+        let a: u128 = self.0.into_keytype().into();
+        let b: u128 = self.1.into_keytype().into();
+        (a << self.1.type_size()) | b
+    }
+    #[inline]
+    fn type_size(&self) -> usize {
+        composite_type_size(self.0.type_size() + self.1.type_size())
+    }
+    #[inline]
+    fn usize_to_keytype(&self, item: usize) -> Self::Key { item as u128 }
+    #[inline]
+    fn keytype_to_usize(&self, item: Self::Key) -> usize { item as usize }
+    #[inline]
+    fn default_key(&self) -> Self::Key { 0 }
+    #[inline]
+    fn one(&self) -> Self::Key { 1 }
+}
+
+impl<A: RadixKey, B: RadixKey, C: RadixKey> RadixKey for (A, B, C) {
+    type Key = u128;
+    #[inline]
+    fn into_keytype(&self) -> Self::Key {
+        check_composite_width(
+            self.0.type_size() + self.1.type_size() + self.2.type_size(),
+        );
+        let a: u128 = self.0.into_keytype().into();
+        let b: u128 = self.1.into_keytype().into();
+        let c: u128 = self.2.into_keytype().into();
+        let a = a << self.1.type_size();
+        ((a | b) << self.2.type_size()) | c
+    }
+    #[inline]
+    fn type_size(&self) -> usize {
+        composite_type_size(
+            self.0.type_size() + self.1.type_size() + self.2.type_size(),
+        )
+    }
+    #[inline]
+    fn usize_to_keytype(&self, item: usize) -> Self::Key { item as u128 }
+    #[inline]
+    fn keytype_to_usize(&self, item: Self::Key) -> usize { item as usize }
+    #[inline]
+    fn default_key(&self) -> Self::Key { 0 }
+    #[inline]
+    fn one(&self) -> Self::Key { 1 }
+}
+
+impl<A: RadixKey, B: RadixKey, C: RadixKey, D: RadixKey> RadixKey
+    for (A, B, C, D)
+{
+    type Key = u128;
+    #[inline]
+    fn into_keytype(&self) -> Self::Key {
+        check_composite_width(
+            self.0.type_size()
+                + self.1.type_size()
+                + self.2.type_size()
+                + self.3.type_size(),
+        );
+        let a: u128 = self.0.into_keytype().into();
+        let b: u128 = self.1.into_keytype().into();
+        let c: u128 = self.2.into_keytype().into();
+        let d: u128 = self.3.into_keytype().into();
+        let a = a << self.1.type_size();
+        let ab = (a | b) << self.2.type_size();
+        ((ab | c) << self.3.type_size()) | d
+    }
+    #[inline]
+    fn type_size(&self) -> usize {
+        composite_type_size(
+            self.0.type_size()
+                + self.1.type_size()
+                + self.2.type_size()
+                + self.3.type_size(),
+        )
+    }
+    #[inline]
+    fn usize_to_keytype(&self, item: usize) -> Self::Key { item as u128 }
+    #[inline]
+    fn keytype_to_usize(&self, item: Self::Key) -> usize { item as usize }
+    #[inline]
+    fn default_key(&self) -> Self::Key { 0 }
+    #[inline]
+    fn one(&self) -> Self::Key { 1 }
+}
+
+impl<const N: usize> RadixKey for [u8; N] {
+    type Key = u128;
+    #[inline]
+    fn into_keytype(&self) -> Self::Key {
+        let () = ByteArrayWidth::<N>::CHECK;
+        self.iter().fold(0, |acc, byte| (acc << 8) | *byte as u128)
+    }
+    #[inline]
+    fn type_size(&self) -> usize {
+        let () = ByteArrayWidth::<N>::CHECK;
+        128
+    }
+    #[inline]
+    fn usize_to_keytype(&self, item: usize) -> Self::Key { item as u128 }
+    #[inline]
+    fn keytype_to_usize(&self, item: Self::Key) -> usize { item as usize }
+    #[inline]
+    fn default_key(&self) -> Self::Key { 0 }
+    #[inline]
+    fn one(&self) -> Self::Key { 1 }
+}
@@ -1,3 +1,5 @@
+use alloc::vec::Vec;
+
 use super::super::sorts::utils::{
     compute_max_level, compute_offset, get_full_histograms, Params,
 };
@@ -5,8 +7,11 @@ use super::super::sorts::utils::{
 use super::super::sorts::utils_mt::compute_offset_mt;
 use super::super::{Dispatcher, RadixKey};
 
-#[cfg(feature = "voracious_multithread")]
-pub trait Radixable<K: RadixKey>: Copy + PartialOrd + Send + Sync {
+#[cfg(any(
+    feature = "voracious_multithread",
+    feature = "voracious_multithread_std"
+))]
+pub trait Radixable<K: RadixKey>: Copy + Send + Sync {
     type Key: RadixKey + Dispatcher<Self, K>;
     fn key(&self) -> Self::Key;
     #[inline]
@@ -176,17 +181,55 @@ pub trait Radixable<K: RadixKey>: Copy + PartialOrd + Send + Sync {
             Dispatcher::voracious_stable_sort(&dummy_key, arr);
         }
     }
-    #[cfg(feature = "voracious_multithread")]
     fn voracious_mt_sort(&self, arr: &mut [Self], thread_n: usize) {
         if arr.len() > 1 {
             let dummy_key = arr[0].key();
             Dispatcher::voracious_mt_sort(&dummy_key, arr, thread_n);
         }
     }
+    fn voracious_mt_stable_sort(&self, arr: &mut [Self], thread_n: usize) {
+        if arr.len() > 1 {
+            let dummy_key = arr[0].key();
+            Dispatcher::voracious_mt_stable_sort(&dummy_key, arr, thread_n);
+        }
+    }
+    fn voracious_sort_desc(&self, arr: &mut [Self]) {
+        if arr.len() > 1 {
+            let dummy_key = arr[0].key();
+            Dispatcher::voracious_sort_desc(&dummy_key, arr);
+        }
+    }
+    fn voracious_stable_sort_desc(&self, arr: &mut [Self]) {
+        if arr.len() > 1 {
+            let dummy_key = arr[0].key();
+            Dispatcher::voracious_stable_sort_desc(&dummy_key, arr);
+        }
+    }
+    fn voracious_mt_sort_desc(&self, arr: &mut [Self], thread_n: usize) {
+        if arr.len() > 1 {
+            let dummy_key = arr[0].key();
+            Dispatcher::voracious_mt_sort_desc(&dummy_key, arr, thread_n);
+        }
+    }
+    fn voracious_mt_stable_sort_desc(
+        &self,
+        arr: &mut [Self],
+        thread_n: usize,
+    ) {
+        if arr.len() > 1 {
+            let dummy_key = arr[0].key();
+            Dispatcher::voracious_mt_stable_sort_desc(
+                &dummy_key, arr, thread_n,
+            );
+        }
+    }
 }
 
-#[cfg(not(feature = "voracious_multithread"))]
-pub trait Radixable<K: RadixKey>: Copy + PartialOrd {
+#[cfg(not(any(
+    feature = "voracious_multithread",
+    feature = "voracious_multithread_std"
+)))]
+pub trait Radixable<K: RadixKey>: Copy {
     type Key: RadixKey + Dispatcher<Self, K>;
     fn key(&self) -> Self::Key;
     #[inline]
@@ -347,4 +390,16 @@ pub trait Radixable<K: RadixKey>: Copy + PartialOrd {
             Dispatcher::voracious_stable_sort(&dummy_key, arr);
         }
     }
+    fn voracious_sort_desc(&self, arr: &mut [Self]) {
+        if arr.len() > 1 {
+            let dummy_key = arr[0].key();
+            Dispatcher::voracious_sort_desc(&dummy_key, arr);
+        }
+    }
+    fn voracious_stable_sort_desc(&self, arr: &mut [Self]) {
+        if arr.len() > 1 {
+            let dummy_key = arr[0].key();
+            Dispatcher::voracious_stable_sort_desc(&dummy_key, arr);
+        }
+    }
 }
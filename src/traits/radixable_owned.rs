@@ -0,0 +1,20 @@
+use super::super::RadixKey;
+
+/// Radixable for elements which are not `Copy`.
+///
+/// Structs holding a `String`, a `Vec` or a `Box` cannot implement
+/// `Radixable`. They can implement `RadixableOwned` instead and be sorted
+/// with the `RadixSortOwned` trait: the keys are extracted once, then the
+/// elements are moved (never cloned) with their key.
+pub trait RadixableOwned<K: RadixKey> {
+    type Key: RadixKey;
+    fn key(&self) -> Self::Key;
+    // Same name as `Radixable::into_key_type`, but `Self` is not `Copy`.
+    #[allow(clippy::wrong_self_convention)]
+    #[inline]
+    fn into_key_type(
+        &self,
+    ) -> <<Self as RadixableOwned<K>>::Key as RadixKey>::Key {
+        self.key().into_keytype()
+    }
+}
@@ -1,10 +1,44 @@
+use alloc::vec::Vec;
+
+#[cfg(feature = "voracious_multithread")]
+use rayon::ThreadPool;
+
+use super::super::sorts::try_sort::{
+    try_voracious_sort, try_voracious_stable_sort, SortError,
+};
 use super::super::{RadixKey, Radixable};
 
 pub trait RadixSort<T: Radixable<K>, K: RadixKey> {
     fn voracious_sort(&mut self);
     fn voracious_stable_sort(&mut self);
-    #[cfg(feature = "voracious_multithread")]
+    fn try_voracious_sort(&mut self) -> Result<(), SortError>;
+    fn try_voracious_stable_sort(&mut self) -> Result<(), SortError>;
+    #[cfg(any(
+        feature = "voracious_multithread",
+        feature = "voracious_multithread_std"
+    ))]
     fn voracious_mt_sort(&mut self, thread_n: usize);
+    #[cfg(any(
+        feature = "voracious_multithread",
+        feature = "voracious_multithread_std"
+    ))]
+    fn voracious_mt_stable_sort(&mut self, thread_n: usize);
+    #[cfg(feature = "voracious_multithread")]
+    fn voracious_mt_sort_in_pool(&mut self, pool: &ThreadPool);
+    #[cfg(feature = "voracious_multithread")]
+    fn voracious_mt_stable_sort_in_pool(&mut self, pool: &ThreadPool);
+    fn voracious_sort_desc(&mut self);
+    fn voracious_stable_sort_desc(&mut self);
+    #[cfg(any(
+        feature = "voracious_multithread",
+        feature = "voracious_multithread_std"
+    ))]
+    fn voracious_mt_sort_desc(&mut self, thread_n: usize);
+    #[cfg(any(
+        feature = "voracious_multithread",
+        feature = "voracious_multithread_std"
+    ))]
+    fn voracious_mt_stable_sort_desc(&mut self, thread_n: usize);
 }
 
 impl<T: Radixable<K>, K: RadixKey> RadixSort<T, K> for [T] {
@@ -20,22 +54,125 @@ impl<T: Radixable<K>, K: RadixKey> RadixSort<T, K> for [T] {
             dummy.voracious_stable_sort(self);
         }
     }
-    #[cfg(feature = "voracious_multithread")]
+    fn try_voracious_sort(&mut self) -> Result<(), SortError> {
+        try_voracious_sort(self)
+    }
+    fn try_voracious_stable_sort(&mut self) -> Result<(), SortError> {
+        try_voracious_stable_sort(self)
+    }
+    #[cfg(any(
+        feature = "voracious_multithread",
+        feature = "voracious_multithread_std"
+    ))]
     fn voracious_mt_sort(&mut self, thread_n: usize) {
         if !self.is_empty() {
             let dummy = self[0];
             dummy.voracious_mt_sort(self, thread_n);
         }
     }
+    #[cfg(any(
+        feature = "voracious_multithread",
+        feature = "voracious_multithread_std"
+    ))]
+    fn voracious_mt_stable_sort(&mut self, thread_n: usize) {
+        if !self.is_empty() {
+            let dummy = self[0];
+            dummy.voracious_mt_stable_sort(self, thread_n);
+        }
+    }
+    #[cfg(feature = "voracious_multithread")]
+    fn voracious_mt_sort_in_pool(&mut self, pool: &ThreadPool) {
+        pool.install(|| self.voracious_mt_sort(0));
+    }
+    #[cfg(feature = "voracious_multithread")]
+    fn voracious_mt_stable_sort_in_pool(&mut self, pool: &ThreadPool) {
+        pool.install(|| self.voracious_mt_stable_sort(0));
+    }
+    fn voracious_sort_desc(&mut self) {
+        if !self.is_empty() {
+            let dummy = self[0];
+            dummy.voracious_sort_desc(self);
+        }
+    }
+    fn voracious_stable_sort_desc(&mut self) {
+        if !self.is_empty() {
+            let dummy = self[0];
+            dummy.voracious_stable_sort_desc(self);
+        }
+    }
+    #[cfg(any(
+        feature = "voracious_multithread",
+        feature = "voracious_multithread_std"
+    ))]
+    fn voracious_mt_sort_desc(&mut self, thread_n: usize) {
+        if !self.is_empty() {
+            let dummy = self[0];
+            dummy.voracious_mt_sort_desc(self, thread_n);
+        }
+    }
+    #[cfg(any(
+        feature = "voracious_multithread",
+        feature = "voracious_multithread_std"
+    ))]
+    fn voracious_mt_stable_sort_desc(&mut self, thread_n: usize) {
+        if !self.is_empty() {
+            let dummy = self[0];
+            dummy.voracious_mt_stable_sort_desc(self, thread_n);
+        }
+    }
 }
 
 impl<T: Radixable<K>, K: RadixKey> RadixSort<T, K> for Vec<T> {
     fn voracious_sort(&mut self) { self.as_mut_slice().voracious_sort(); }
     fn voracious_stable_sort(&mut self) {
-        self.as_mut_slice().voracious_sort();
+        self.as_mut_slice().voracious_stable_sort();
     }
-    #[cfg(feature = "voracious_multithread")]
+    fn try_voracious_sort(&mut self) -> Result<(), SortError> {
+        self.as_mut_slice().try_voracious_sort()
+    }
+    fn try_voracious_stable_sort(&mut self) -> Result<(), SortError> {
+        self.as_mut_slice().try_voracious_stable_sort()
+    }
+    #[cfg(any(
+        feature = "voracious_multithread",
+        feature = "voracious_multithread_std"
+    ))]
     fn voracious_mt_sort(&mut self, thread_n: usize) {
         self.as_mut_slice().voracious_mt_sort(thread_n);
     }
+    #[cfg(any(
+        feature = "voracious_multithread",
+        feature = "voracious_multithread_std"
+    ))]
+    fn voracious_mt_stable_sort(&mut self, thread_n: usize) {
+        self.as_mut_slice().voracious_mt_stable_sort(thread_n);
+    }
+    #[cfg(feature = "voracious_multithread")]
+    fn voracious_mt_sort_in_pool(&mut self, pool: &ThreadPool) {
+        self.as_mut_slice().voracious_mt_sort_in_pool(pool);
+    }
+    #[cfg(feature = "voracious_multithread")]
+    fn voracious_mt_stable_sort_in_pool(&mut self, pool: &ThreadPool) {
+        self.as_mut_slice().voracious_mt_stable_sort_in_pool(pool);
+    }
+    fn voracious_sort_desc(&mut self) {
+        self.as_mut_slice().voracious_sort_desc();
+    }
+    fn voracious_stable_sort_desc(&mut self) {
+        self.as_mut_slice().voracious_stable_sort_desc();
+    }
+    #[cfg(any(
+        feature = "voracious_multithread",
+        feature = "voracious_multithread_std"
+    ))]
+    fn voracious_mt_sort_desc(&mut self, thread_n: usize) {
+        self.as_mut_slice().voracious_mt_sort_desc(thread_n);
+    }
+    #[cfg(any(
+        feature = "voracious_multithread",
+        feature = "voracious_multithread_std"
+    ))]
+    fn voracious_mt_stable_sort_desc(&mut self, thread_n: usize) {
+        self.as_mut_slice().voracious_mt_stable_sort_desc(thread_n);
+    }
 }
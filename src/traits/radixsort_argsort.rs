@@ -0,0 +1,65 @@
+use alloc::vec::Vec;
+
+use super::super::sorts::argsort::{
+    lsd_stable_argsort, lsd_stable_argsort_u32, msd_argsort, msd_argsort_u32,
+};
+#[cfg(feature = "voracious_multithread")]
+use super::super::sorts::argsort::{msd_mt_argsort, msd_mt_argsort_u32};
+use super::super::{RadixKey, Radixable};
+
+// The indices are sorted by the radix key of the elements (the unsigned
+// integer the radix sorts work on), so any `Radixable` type can be argsorted.
+// The `_u32` variants return `u32` indices, and panic if the array has more
+// than 2^32 elements.
+pub trait RadixArgSort<T: Radixable<K>, K: RadixKey> {
+    fn voracious_argsort(&self) -> Vec<usize>;
+    fn voracious_stable_argsort(&self) -> Vec<usize>;
+    #[cfg(feature = "voracious_multithread")]
+    fn voracious_mt_argsort(&self, thread_n: usize) -> Vec<usize>;
+    fn voracious_argsort_u32(&self) -> Vec<u32>;
+    fn voracious_stable_argsort_u32(&self) -> Vec<u32>;
+    #[cfg(feature = "voracious_multithread")]
+    fn voracious_mt_argsort_u32(&self, thread_n: usize) -> Vec<u32>;
+}
+
+impl<T: Radixable<K>, K: RadixKey> RadixArgSort<T, K> for [T] {
+    fn voracious_argsort(&self) -> Vec<usize> { msd_argsort(self, 8) }
+    fn voracious_stable_argsort(&self) -> Vec<usize> {
+        lsd_stable_argsort(self, 8)
+    }
+    #[cfg(feature = "voracious_multithread")]
+    fn voracious_mt_argsort(&self, thread_n: usize) -> Vec<usize> {
+        msd_mt_argsort(self, 8, thread_n)
+    }
+    fn voracious_argsort_u32(&self) -> Vec<u32> { msd_argsort_u32(self, 8) }
+    fn voracious_stable_argsort_u32(&self) -> Vec<u32> {
+        lsd_stable_argsort_u32(self, 8)
+    }
+    #[cfg(feature = "voracious_multithread")]
+    fn voracious_mt_argsort_u32(&self, thread_n: usize) -> Vec<u32> {
+        msd_mt_argsort_u32(self, 8, thread_n)
+    }
+}
+
+impl<T: Radixable<K>, K: RadixKey> RadixArgSort<T, K> for Vec<T> {
+    fn voracious_argsort(&self) -> Vec<usize> {
+        self.as_slice().voracious_argsort()
+    }
+    fn voracious_stable_argsort(&self) -> Vec<usize> {
+        self.as_slice().voracious_stable_argsort()
+    }
+    #[cfg(feature = "voracious_multithread")]
+    fn voracious_mt_argsort(&self, thread_n: usize) -> Vec<usize> {
+        self.as_slice().voracious_mt_argsort(thread_n)
+    }
+    fn voracious_argsort_u32(&self) -> Vec<u32> {
+        self.as_slice().voracious_argsort_u32()
+    }
+    fn voracious_stable_argsort_u32(&self) -> Vec<u32> {
+        self.as_slice().voracious_stable_argsort_u32()
+    }
+    #[cfg(feature = "voracious_multithread")]
+    fn voracious_mt_argsort_u32(&self, thread_n: usize) -> Vec<u32> {
+        self.as_slice().voracious_mt_argsort_u32(thread_n)
+    }
+}
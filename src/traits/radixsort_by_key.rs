@@ -0,0 +1,102 @@
+use alloc::vec::Vec;
+
+use super::super::types::keyed::{apply_permutation, KeyIndex};
+use super::super::{RadixKey, RadixSort, Radixable};
+
+pub trait RadixSortByKey<T> {
+    fn voracious_sort_by_key<K, F>(&mut self, f: F)
+    where
+        K: RadixKey,
+        KeyIndex<K>: Radixable<K>,
+        F: Fn(&T) -> K;
+    fn voracious_stable_sort_by_key<K, F>(&mut self, f: F)
+    where
+        K: RadixKey,
+        KeyIndex<K>: Radixable<K>,
+        F: Fn(&T) -> K;
+    #[cfg(feature = "voracious_multithread")]
+    fn voracious_mt_sort_by_key<K, F>(&mut self, f: F, thread_n: usize)
+    where
+        K: RadixKey,
+        KeyIndex<K>: Radixable<K>,
+        F: Fn(&T) -> K;
+}
+
+#[inline]
+fn extract_keys<T, K, F>(arr: &[T], f: F) -> Vec<KeyIndex<K>>
+where
+    F: Fn(&T) -> K,
+{
+    arr.iter()
+        .enumerate()
+        .map(|(index, item)| KeyIndex { key: f(item), index })
+        .collect()
+}
+
+impl<T> RadixSortByKey<T> for [T] {
+    fn voracious_sort_by_key<K, F>(&mut self, f: F)
+    where
+        K: RadixKey,
+        KeyIndex<K>: Radixable<K>,
+        F: Fn(&T) -> K,
+    {
+        if self.len() > 1 {
+            let mut indices = extract_keys(self, f);
+            indices.as_mut_slice().voracious_sort();
+            apply_permutation(self, &mut indices);
+        }
+    }
+    fn voracious_stable_sort_by_key<K, F>(&mut self, f: F)
+    where
+        K: RadixKey,
+        KeyIndex<K>: Radixable<K>,
+        F: Fn(&T) -> K,
+    {
+        if self.len() > 1 {
+            let mut indices = extract_keys(self, f);
+            indices.as_mut_slice().voracious_stable_sort();
+            apply_permutation(self, &mut indices);
+        }
+    }
+    #[cfg(feature = "voracious_multithread")]
+    fn voracious_mt_sort_by_key<K, F>(&mut self, f: F, thread_n: usize)
+    where
+        K: RadixKey,
+        KeyIndex<K>: Radixable<K>,
+        F: Fn(&T) -> K,
+    {
+        if self.len() > 1 {
+            let mut indices = extract_keys(self, f);
+            indices.as_mut_slice().voracious_mt_sort(thread_n);
+            apply_permutation(self, &mut indices);
+        }
+    }
+}
+
+impl<T> RadixSortByKey<T> for Vec<T> {
+    fn voracious_sort_by_key<K, F>(&mut self, f: F)
+    where
+        K: RadixKey,
+        KeyIndex<K>: Radixable<K>,
+        F: Fn(&T) -> K,
+    {
+        self.as_mut_slice().voracious_sort_by_key(f);
+    }
+    fn voracious_stable_sort_by_key<K, F>(&mut self, f: F)
+    where
+        K: RadixKey,
+        KeyIndex<K>: Radixable<K>,
+        F: Fn(&T) -> K,
+    {
+        self.as_mut_slice().voracious_stable_sort_by_key(f);
+    }
+    #[cfg(feature = "voracious_multithread")]
+    fn voracious_mt_sort_by_key<K, F>(&mut self, f: F, thread_n: usize)
+    where
+        K: RadixKey,
+        KeyIndex<K>: Radixable<K>,
+        F: Fn(&T) -> K,
+    {
+        self.as_mut_slice().voracious_mt_sort_by_key(f, thread_n);
+    }
+}
@@ -0,0 +1,35 @@
+use alloc::vec::Vec;
+
+use super::super::sorts::owned_sort::{
+    lsd_stable_radixsort_owned, msd_radixsort_owned,
+};
+use super::super::{RadixKey, RadixableOwned};
+
+pub trait RadixSortOwned<T: RadixableOwned<K>, K: RadixKey> {
+    fn voracious_sort(&mut self);
+    fn voracious_stable_sort(&mut self);
+}
+
+impl<T: RadixableOwned<K>, K: RadixKey> RadixSortOwned<T, K> for [T] {
+    fn voracious_sort(&mut self) {
+        if self.len() <= 200 {
+            self.sort_unstable_by_key(|e| e.into_key_type());
+        } else {
+            msd_radixsort_owned(self, 8);
+        }
+    }
+    fn voracious_stable_sort(&mut self) {
+        if self.len() <= 200 {
+            self.sort_by_key(|e| e.into_key_type());
+        } else {
+            lsd_stable_radixsort_owned(self, 8);
+        }
+    }
+}
+
+impl<T: RadixableOwned<K>, K: RadixKey> RadixSortOwned<T, K> for Vec<T> {
+    fn voracious_sort(&mut self) { self.as_mut_slice().voracious_sort(); }
+    fn voracious_stable_sort(&mut self) {
+        self.as_mut_slice().voracious_stable_sort();
+    }
+}
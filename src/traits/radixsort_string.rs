@@ -0,0 +1,44 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use super::super::sorts::string_sort::{string_sort, string_stable_sort};
+
+#[cfg(feature = "voracious_multithread")]
+use super::super::sorts::string_sort::string_mt_sort;
+
+/// Variable length byte strings sortable by the String sort.
+///
+/// Strings are sorted in lexicographic order of their bytes. For `String` and
+/// `&str`, it is the same order as `Ord`.
+pub trait ByteString: AsRef<[u8]> + Send {}
+
+impl ByteString for String {}
+impl ByteString for &str {}
+impl ByteString for Vec<u8> {}
+
+pub trait RadixSortString {
+    fn voracious_sort(&mut self);
+    fn voracious_stable_sort(&mut self);
+    #[cfg(feature = "voracious_multithread")]
+    fn voracious_mt_sort(&mut self, thread_n: usize);
+}
+
+impl<T: ByteString> RadixSortString for [T] {
+    fn voracious_sort(&mut self) { string_sort(self); }
+    fn voracious_stable_sort(&mut self) { string_stable_sort(self); }
+    #[cfg(feature = "voracious_multithread")]
+    fn voracious_mt_sort(&mut self, thread_n: usize) {
+        string_mt_sort(self, thread_n);
+    }
+}
+
+impl<T: ByteString> RadixSortString for Vec<T> {
+    fn voracious_sort(&mut self) { string_sort(self.as_mut_slice()); }
+    fn voracious_stable_sort(&mut self) {
+        string_stable_sort(self.as_mut_slice());
+    }
+    #[cfg(feature = "voracious_multithread")]
+    fn voracious_mt_sort(&mut self, thread_n: usize) {
+        string_mt_sort(self.as_mut_slice(), thread_n);
+    }
+}
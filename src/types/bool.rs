@@ -1,4 +1,4 @@
-use super::super::sorts::boolean_sort::boolean_sort;
+use super::super::sorts::boolean_sort::{boolean_sort, boolean_sort_desc};
 use super::super::Radixable;
 
 impl Radixable<bool> for bool {
@@ -14,4 +14,12 @@ impl Radixable<bool> for bool {
     fn voracious_mt_sort(&self, arr: &mut [bool], _thread_n: usize) {
         boolean_sort(arr);
     }
+    fn voracious_sort_desc(&self, arr: &mut [bool]) { boolean_sort_desc(arr); }
+    fn voracious_stable_sort_desc(&self, arr: &mut [bool]) {
+        boolean_sort_desc(arr);
+    }
+    #[cfg(feature = "voracious_multithread")]
+    fn voracious_mt_sort_desc(&self, arr: &mut [bool], _thread_n: usize) {
+        boolean_sort_desc(arr);
+    }
 }
@@ -1,6 +1,12 @@
+use alloc::vec::Vec;
+
 #[cfg(feature = "voracious_multithread")]
 use rayon::slice::ParallelSliceMut;
 
+#[cfg(feature = "voracious_multithread")]
+use super::super::sorts::comparative_sort::key_cmp;
+#[cfg(feature = "voracious_multithread")]
+use super::super::sorts::lsd_mt_sort::lsd_mt_radixsort;
 use super::super::sorts::lsd_sort::lsd_radixsort_heu;
 #[cfg(feature = "voracious_multithread")]
 use super::super::sorts::peeka_sort::peeka_sort;
@@ -18,7 +24,7 @@ impl Radixable<char> for char {
     }
     #[inline] // overrided function
     fn to_generic(&self, v: usize) -> char {
-        std::char::from_u32(v as u32).unwrap()
+        core::char::from_u32(v as u32).unwrap()
     }
     #[inline]
     fn into_key_type(&self) -> u32 { *self as u32 }
@@ -194,8 +200,10 @@ impl Radixable<char> for char {
     }
     #[cfg(feature = "voracious_multithread")]
     fn voracious_mt_sort(&self, arr: &mut [Self], thread_n: usize) {
-        if arr.len() < 1_000_000 {
-            arr.par_sort_unstable_by(|a, b| a.partial_cmp(b).unwrap());
+        if arr.len() < 100_000 {
+            arr.par_sort_unstable_by(key_cmp);
+        } else if arr.len() < 1_000_000 {
+            lsd_mt_radixsort(arr, 11, thread_n);
         } else {
             let chunk_size = if arr.len() < 5_000_000 {
                 100_000
@@ -0,0 +1,43 @@
+use super::super::{RadixKey, Radixable};
+
+// Tuples and byte arrays are sorted by their composite key: the bits of each
+// field are concatenated, so the order is the lexicographic order.
+
+impl<A, B> Radixable<(A, B)> for (A, B)
+where
+    A: RadixKey + Copy + Send + Sync,
+    B: RadixKey + Copy + Send + Sync,
+{
+    type Key = (A, B);
+    #[inline]
+    fn key(&self) -> Self::Key { *self }
+}
+
+impl<A, B, C> Radixable<(A, B, C)> for (A, B, C)
+where
+    A: RadixKey + Copy + Send + Sync,
+    B: RadixKey + Copy + Send + Sync,
+    C: RadixKey + Copy + Send + Sync,
+{
+    type Key = (A, B, C);
+    #[inline]
+    fn key(&self) -> Self::Key { *self }
+}
+
+impl<A, B, C, D> Radixable<(A, B, C, D)> for (A, B, C, D)
+where
+    A: RadixKey + Copy + Send + Sync,
+    B: RadixKey + Copy + Send + Sync,
+    C: RadixKey + Copy + Send + Sync,
+    D: RadixKey + Copy + Send + Sync,
+{
+    type Key = (A, B, C, D);
+    #[inline]
+    fn key(&self) -> Self::Key { *self }
+}
+
+impl<const N: usize> Radixable<[u8; N]> for [u8; N] {
+    type Key = [u8; N];
+    #[inline]
+    fn key(&self) -> Self::Key { *self }
+}
@@ -1,6 +1,12 @@
-use std::cmp::Ordering;
+#[cfg(test)]
+use alloc::string::String;
+#[cfg(test)]
+use alloc::vec::Vec;
+use core::cmp::Ordering;
 
 use super::super::Radixable;
+#[cfg(test)]
+use super::super::RadixableOwned;
 
 // Struct
 
@@ -337,3 +343,53 @@ impl Radixable<usize> for StructUsize {
     #[inline]
     fn key(&self) -> Self::Key { self.value }
 }
+
+// Struct which is not Copy
+
+#[cfg(test)]
+#[derive(Clone, Debug)]
+pub struct StructOwnedU64 {
+    pub value: u64,
+    pub other: String,
+}
+#[cfg(test)]
+impl PartialOrd for StructOwnedU64 {
+    fn partial_cmp(&self, other: &StructOwnedU64) -> Option<Ordering> {
+        (self.value).partial_cmp(&(other.value))
+    }
+}
+#[cfg(test)]
+impl PartialEq for StructOwnedU64 {
+    fn eq(&self, other: &Self) -> bool { self.value == other.value }
+}
+#[cfg(test)]
+impl RadixableOwned<u64> for StructOwnedU64 {
+    type Key = u64;
+    #[inline]
+    fn key(&self) -> Self::Key { self.value }
+}
+
+// Struct which is not Copy
+
+#[cfg(test)]
+#[derive(Clone, Debug)]
+pub struct StructOwnedF32 {
+    pub value: f32,
+    pub other: Vec<u8>,
+}
+#[cfg(test)]
+impl PartialOrd for StructOwnedF32 {
+    fn partial_cmp(&self, other: &StructOwnedF32) -> Option<Ordering> {
+        (self.value).partial_cmp(&(other.value))
+    }
+}
+#[cfg(test)]
+impl PartialEq for StructOwnedF32 {
+    fn eq(&self, other: &Self) -> bool { self.value == other.value }
+}
+#[cfg(test)]
+impl RadixableOwned<f32> for StructOwnedF32 {
+    type Key = f32;
+    #[inline]
+    fn key(&self) -> Self::Key { self.value }
+}
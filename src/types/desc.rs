@@ -0,0 +1,145 @@
+use core::cmp::Ordering;
+
+use super::super::{Dispatcher, RadixKey, Radixable};
+
+// Descending order wrapper.
+//
+// Sorting a slice of `Desc<T>` in ascending order is the same as sorting the
+// underlying slice of `T` in descending order: the radix key is bit-flipped
+// and the comparison is reversed. Since `Desc<T>` has the same memory layout
+// as `T`, a `&mut [T]` can be sorted in descending order by any sort of this
+// crate without extra pass nor extra copy.
+
+#[derive(Copy, Clone, Debug)]
+#[repr(transparent)]
+pub struct Desc<T>(pub T);
+
+impl<T> Desc<T> {
+    #[inline]
+    pub fn from_slice_mut(arr: &mut [T]) -> &mut [Desc<T>] {
+        unsafe { &mut *(arr as *mut [T] as *mut [Desc<T>]) }
+    }
+    #[inline]
+    pub fn into_slice_mut(arr: &mut [Desc<T>]) -> &mut [T] {
+        unsafe { &mut *(arr as *mut [Desc<T>] as *mut [T]) }
+    }
+}
+
+impl<T: PartialOrd> PartialOrd for Desc<T> {
+    fn partial_cmp(&self, other: &Desc<T>) -> Option<Ordering> {
+        other.0.partial_cmp(&self.0)
+    }
+}
+impl<T: PartialEq> PartialEq for Desc<T> {
+    fn eq(&self, other: &Self) -> bool { self.0 == other.0 }
+}
+
+// Descending key: the key is bit-flipped. Only the `type_size` low bits are
+// flipped, so a key narrower than its integer type, like a 48 bits key in a
+// `u64`, keeps its high bits to zero.
+
+#[inline]
+fn flip<K: RadixKey>(key: &K, value: K::Key) -> K::Key {
+    let width = 8 * core::mem::size_of::<K::Key>();
+    let bits = key.type_size();
+    if bits < width {
+        let mask = !key.default_key() >> key.usize_to_keytype(width - bits);
+        !value & mask
+    } else {
+        !value
+    }
+}
+
+#[derive(Copy, Clone, Debug)]
+pub struct DescKey<K>(pub K);
+
+impl<K: RadixKey> RadixKey for DescKey<K> {
+    type Key = K::Key;
+    #[inline]
+    fn into_keytype(&self) -> Self::Key {
+        flip(&self.0, self.0.into_keytype())
+    }
+    #[inline]
+    fn type_size(&self) -> usize { self.0.type_size() }
+    #[inline]
+    fn usize_to_keytype(&self, item: usize) -> Self::Key {
+        self.0.usize_to_keytype(item)
+    }
+    #[inline]
+    fn keytype_to_usize(&self, item: Self::Key) -> usize {
+        self.0.keytype_to_usize(item)
+    }
+    #[inline]
+    fn default_key(&self) -> Self::Key { self.0.default_key() }
+    #[inline]
+    fn one(&self) -> Self::Key { self.0.one() }
+}
+
+impl<T: Radixable<K>, K: RadixKey> Radixable<K> for Desc<T> {
+    type Key = DescKey<T::Key>;
+    #[inline]
+    fn key(&self) -> Self::Key { DescKey(self.0.key()) }
+    #[inline]
+    fn into_key_type(&self) -> <<T as Radixable<K>>::Key as RadixKey>::Key {
+        flip(&self.0.key(), self.0.into_key_type())
+    }
+}
+
+// Sorting a `Desc<T>` slice is dispatched back to the `T` slice, in the
+// opposite order. The `Dispatcher` of the key of `T` then sorts the `Desc<T>`
+// slice with its own radix sort choices.
+impl<T: Radixable<K>, K: RadixKey, KK: RadixKey> Dispatcher<Desc<T>, K>
+    for DescKey<KK>
+{
+    fn voracious_sort(&self, arr: &mut [Desc<T>]) {
+        let arr = Desc::into_slice_mut(arr);
+        let dummy = arr[0];
+        dummy.voracious_sort_desc(arr);
+    }
+    fn voracious_stable_sort(&self, arr: &mut [Desc<T>]) {
+        let arr = Desc::into_slice_mut(arr);
+        let dummy = arr[0];
+        dummy.voracious_stable_sort_desc(arr);
+    }
+    fn voracious_sort_desc(&self, arr: &mut [Desc<T>]) {
+        let arr = Desc::into_slice_mut(arr);
+        let dummy = arr[0];
+        dummy.voracious_sort(arr);
+    }
+    fn voracious_stable_sort_desc(&self, arr: &mut [Desc<T>]) {
+        let arr = Desc::into_slice_mut(arr);
+        let dummy = arr[0];
+        dummy.voracious_stable_sort(arr);
+    }
+    #[cfg(any(
+        feature = "voracious_multithread",
+        feature = "voracious_multithread_std"
+    ))]
+    fn voracious_mt_sort(&self, arr: &mut [Desc<T>], thread_n: usize) {
+        let arr = Desc::into_slice_mut(arr);
+        let dummy = arr[0];
+        dummy.voracious_mt_sort_desc(arr, thread_n);
+    }
+    #[cfg(any(
+        feature = "voracious_multithread",
+        feature = "voracious_multithread_std"
+    ))]
+    fn voracious_mt_sort_desc(&self, arr: &mut [Desc<T>], thread_n: usize) {
+        let arr = Desc::into_slice_mut(arr);
+        let dummy = arr[0];
+        dummy.voracious_mt_sort(arr, thread_n);
+    }
+    #[cfg(any(
+        feature = "voracious_multithread",
+        feature = "voracious_multithread_std"
+    ))]
+    fn voracious_mt_stable_sort_desc(
+        &self,
+        arr: &mut [Desc<T>],
+        thread_n: usize,
+    ) {
+        let arr = Desc::into_slice_mut(arr);
+        let dummy = arr[0];
+        dummy.voracious_mt_stable_sort(arr, thread_n);
+    }
+}
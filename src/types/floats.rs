@@ -1,12 +1,21 @@
+use alloc::vec::Vec;
+
 #[cfg(feature = "voracious_multithread")]
 use rayon::slice::ParallelSliceMut;
 
+use super::super::sorts::comparative_sort::key_cmp;
 use super::super::sorts::dlsd_sort::dlsd_radixsort;
+#[cfg(feature = "voracious_multithread")]
+use super::super::sorts::lsd_mt_sort::lsd_mt_radixsort;
 use super::super::sorts::lsd_sort::lsd_radixsort;
 #[cfg(feature = "voracious_multithread")]
 use super::super::sorts::peeka_sort::peeka_sort;
 use super::super::sorts::rollercoaster_sort::rollercoaster_sort;
 use super::super::sorts::utils::{get_empty_histograms, Params};
+use super::super::sorts::utils_simd::{
+    bits_of_f32, bits_of_f64, fill_histograms_u32, fill_histograms_u64,
+    simd_histograms_available, KeyTransform,
+};
 use super::super::Radixable;
 
 impl Radixable<f32> for f32 {
@@ -36,6 +45,19 @@ impl Radixable<f32> for f32 {
         arr: &mut [f32],
         p: &Params,
     ) -> Vec<Vec<usize>> {
+        if simd_histograms_available(arr.len(), p.radix) {
+            let mut histograms =
+                get_empty_histograms(p.max_level, p.radix_range);
+            fill_histograms_u32(
+                bits_of_f32(arr),
+                p.radix,
+                p.max_level,
+                KeyTransform::FlipFloat,
+                &mut histograms,
+            );
+            return histograms;
+        }
+
         let mut histograms = get_empty_histograms(p.max_level, p.radix_range);
         let default_mask = self.default_mask(p.radix);
         let shift = p.radix as u32;
@@ -190,7 +212,7 @@ impl Radixable<f32> for f32 {
     }
     fn voracious_sort(&self, arr: &mut [f32]) {
         if arr.len() <= 300 {
-            arr.sort_unstable_by(|a, b| a.partial_cmp(b).unwrap());
+            arr.sort_unstable_by(key_cmp);
         } else if arr.len() <= 2_900_000 {
             lsd_radixsort(arr, 8);
         } else {
@@ -203,8 +225,10 @@ impl Radixable<f32> for f32 {
     }
     #[cfg(feature = "voracious_multithread")]
     fn voracious_mt_sort(&self, arr: &mut [Self], thread_n: usize) {
-        if arr.len() <= 800_000 {
-            arr.par_sort_unstable_by(|a, b| a.partial_cmp(b).unwrap());
+        if arr.len() < 100_000 {
+            arr.par_sort_unstable_by(key_cmp);
+        } else if arr.len() <= 800_000 {
+            lsd_mt_radixsort(arr, 8, thread_n);
         } else {
             let chunk_size = if arr.len() < 1_000_000 {
                 100_000
@@ -256,6 +280,19 @@ impl Radixable<f64> for f64 {
         arr: &mut [f64],
         p: &Params,
     ) -> Vec<Vec<usize>> {
+        if simd_histograms_available(arr.len(), p.radix) {
+            let mut histograms =
+                get_empty_histograms(p.max_level, p.radix_range);
+            fill_histograms_u64(
+                bits_of_f64(arr),
+                p.radix,
+                p.max_level,
+                KeyTransform::FlipFloat,
+                &mut histograms,
+            );
+            return histograms;
+        }
+
         let mut histograms = get_empty_histograms(p.max_level, p.radix_range);
         let default_mask = self.default_mask(p.radix);
         let shift = p.radix as u64;
@@ -706,7 +743,7 @@ impl Radixable<f64> for f64 {
     }
     fn voracious_sort(&self, arr: &mut [f64]) {
         if arr.len() <= 300 {
-            arr.sort_unstable_by(|a, b| a.partial_cmp(b).unwrap())
+            arr.sort_unstable_by(key_cmp);
         } else if arr.len() < 800 {
             dlsd_radixsort(arr, 8);
         } else {
@@ -719,8 +756,10 @@ impl Radixable<f64> for f64 {
     }
     #[cfg(feature = "voracious_multithread")]
     fn voracious_mt_sort(&self, arr: &mut [Self], thread_n: usize) {
-        if arr.len() < 800_000 {
-            arr.par_sort_unstable_by(|a, b| a.partial_cmp(b).unwrap());
+        if arr.len() < 100_000 {
+            arr.par_sort_unstable_by(key_cmp);
+        } else if arr.len() < 800_000 {
+            lsd_mt_radixsort(arr, 8, thread_n);
         } else {
             let chunk_size = if arr.len() < 1_000_000 {
                 100_000
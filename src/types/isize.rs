@@ -1,3 +1,5 @@
+use alloc::vec::Vec;
+
 #[cfg(feature = "voracious_multithread")]
 use rayon::slice::ParallelSliceMut;
 
@@ -0,0 +1,151 @@
+use core::cmp::Ordering;
+
+use super::super::{RadixKey, Radixable};
+
+// Key extracted from an element and the position of this element in the
+// array. Sorting a vector of `KeyIndex` gives the permutation that sorts the
+// array by the extracted key.
+
+#[derive(Copy, Clone, Debug)]
+pub struct KeyIndex<K> {
+    pub key: K,
+    pub index: usize,
+}
+impl<K: PartialOrd> PartialOrd for KeyIndex<K> {
+    fn partial_cmp(&self, other: &KeyIndex<K>) -> Option<Ordering> {
+        (self.key).partial_cmp(&(other.key))
+    }
+}
+impl<K: PartialEq> PartialEq for KeyIndex<K> {
+    fn eq(&self, other: &Self) -> bool { self.key == other.key }
+}
+impl Radixable<bool> for KeyIndex<bool> {
+    type Key = bool;
+    #[inline]
+    fn key(&self) -> Self::Key { self.key }
+}
+impl Radixable<char> for KeyIndex<char> {
+    type Key = char;
+    #[inline]
+    fn key(&self) -> Self::Key { self.key }
+}
+impl Radixable<f32> for KeyIndex<f32> {
+    type Key = f32;
+    #[inline]
+    fn key(&self) -> Self::Key { self.key }
+}
+impl Radixable<f64> for KeyIndex<f64> {
+    type Key = f64;
+    #[inline]
+    fn key(&self) -> Self::Key { self.key }
+}
+impl Radixable<u8> for KeyIndex<u8> {
+    type Key = u8;
+    #[inline]
+    fn key(&self) -> Self::Key { self.key }
+}
+impl Radixable<u16> for KeyIndex<u16> {
+    type Key = u16;
+    #[inline]
+    fn key(&self) -> Self::Key { self.key }
+}
+impl Radixable<u32> for KeyIndex<u32> {
+    type Key = u32;
+    #[inline]
+    fn key(&self) -> Self::Key { self.key }
+}
+impl Radixable<u64> for KeyIndex<u64> {
+    type Key = u64;
+    #[inline]
+    fn key(&self) -> Self::Key { self.key }
+}
+impl Radixable<u128> for KeyIndex<u128> {
+    type Key = u128;
+    #[inline]
+    fn key(&self) -> Self::Key { self.key }
+}
+impl Radixable<usize> for KeyIndex<usize> {
+    type Key = usize;
+    #[inline]
+    fn key(&self) -> Self::Key { self.key }
+}
+impl Radixable<i8> for KeyIndex<i8> {
+    type Key = i8;
+    #[inline]
+    fn key(&self) -> Self::Key { self.key }
+}
+impl Radixable<i16> for KeyIndex<i16> {
+    type Key = i16;
+    #[inline]
+    fn key(&self) -> Self::Key { self.key }
+}
+impl Radixable<i32> for KeyIndex<i32> {
+    type Key = i32;
+    #[inline]
+    fn key(&self) -> Self::Key { self.key }
+}
+impl Radixable<i64> for KeyIndex<i64> {
+    type Key = i64;
+    #[inline]
+    fn key(&self) -> Self::Key { self.key }
+}
+impl Radixable<i128> for KeyIndex<i128> {
+    type Key = i128;
+    #[inline]
+    fn key(&self) -> Self::Key { self.key }
+}
+impl Radixable<isize> for KeyIndex<isize> {
+    type Key = isize;
+    #[inline]
+    fn key(&self) -> Self::Key { self.key }
+}
+impl<A, B> Radixable<(A, B)> for KeyIndex<(A, B)>
+where
+    A: RadixKey + Copy + Send + Sync,
+    B: RadixKey + Copy + Send + Sync,
+{
+    type Key = (A, B);
+    #[inline]
+    fn key(&self) -> Self::Key { self.key }
+}
+impl<A, B, C> Radixable<(A, B, C)> for KeyIndex<(A, B, C)>
+where
+    A: RadixKey + Copy + Send + Sync,
+    B: RadixKey + Copy + Send + Sync,
+    C: RadixKey + Copy + Send + Sync,
+{
+    type Key = (A, B, C);
+    #[inline]
+    fn key(&self) -> Self::Key { self.key }
+}
+impl<A, B, C, D> Radixable<(A, B, C, D)> for KeyIndex<(A, B, C, D)>
+where
+    A: RadixKey + Copy + Send + Sync,
+    B: RadixKey + Copy + Send + Sync,
+    C: RadixKey + Copy + Send + Sync,
+    D: RadixKey + Copy + Send + Sync,
+{
+    type Key = (A, B, C, D);
+    #[inline]
+    fn key(&self) -> Self::Key { self.key }
+}
+impl<const N: usize> Radixable<[u8; N]> for KeyIndex<[u8; N]> {
+    type Key = [u8; N];
+    #[inline]
+    fn key(&self) -> Self::Key { self.key }
+}
+
+// Move each element of the array to its position in the sorted permutation.
+// `indices[i].index` is the position, in the array, of the element which must
+// go at position `i`. Follows the cycles of the permutation, so each element
+// is moved at most once and no extra memory is needed.
+pub fn apply_permutation<T, K>(arr: &mut [T], indices: &mut [KeyIndex<K>]) {
+    for i in 0..arr.len() {
+        let mut index = indices[i].index;
+        while index < i {
+            index = indices[index].index;
+        }
+        indices[i].index = index;
+        arr.swap(i, index);
+    }
+}
@@ -1,8 +1,11 @@
 mod bool;
 mod char;
+mod composite;
 pub mod custom;
+pub mod desc;
 mod floats;
 mod isize;
+pub mod keyed;
 mod signed_integer;
 mod unsigned_integer;
 mod usize;
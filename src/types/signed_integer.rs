@@ -1,3 +1,5 @@
+use alloc::vec::Vec;
+
 #[cfg(feature = "voracious_multithread")]
 use rayon::slice::ParallelSliceMut;
 
@@ -11,6 +13,10 @@ use super::super::sorts::rollercoaster_sort::{
     rollercoaster_sort, rollercoaster_sort_heu,
 };
 use super::super::sorts::utils::{get_empty_histograms, Params};
+use super::super::sorts::utils_simd::{
+    bits_of_i32, bits_of_i64, fill_histograms_u32, fill_histograms_u64,
+    simd_histograms_available, KeyTransform,
+};
 use super::super::sorts::voracious_sort::voracious_sort_heu;
 use super::super::Radixable;
 
@@ -211,6 +217,19 @@ impl Radixable<i32> for i32 {
         arr: &mut [i32],
         p: &Params,
     ) -> Vec<Vec<usize>> {
+        if simd_histograms_available(arr.len(), p.radix) {
+            let mut histograms =
+                get_empty_histograms(p.max_level, p.radix_range);
+            fill_histograms_u32(
+                bits_of_i32(arr),
+                p.radix,
+                p.max_level,
+                KeyTransform::FlipSign,
+                &mut histograms,
+            );
+            return histograms;
+        }
+
         let mut histograms = get_empty_histograms(p.max_level, p.radix_range);
         let default_mask = self.default_mask(p.radix);
         let shift = p.radix as u32;
@@ -424,6 +443,19 @@ impl Radixable<i64> for i64 {
         arr: &mut [i64],
         p: &Params,
     ) -> Vec<Vec<usize>> {
+        if simd_histograms_available(arr.len(), p.radix) {
+            let mut histograms =
+                get_empty_histograms(p.max_level, p.radix_range);
+            fill_histograms_u64(
+                bits_of_i64(arr),
+                p.radix,
+                p.max_level,
+                KeyTransform::FlipSign,
+                &mut histograms,
+            );
+            return histograms;
+        }
+
         let mut histograms = get_empty_histograms(p.max_level, p.radix_range);
         let default_mask = self.default_mask(p.radix);
         let shift = p.radix as u64;
@@ -1,13 +1,23 @@
+use alloc::vec::Vec;
+
 #[cfg(feature = "voracious_multithread")]
 use rayon::slice::ParallelSliceMut;
 
+#[cfg(feature = "voracious_multithread")]
+use super::super::sorts::comparative_sort::key_cmp;
 use super::super::sorts::counting_sort::counting_sort;
-use super::super::sorts::dlsd_sort::dlsd_radixsort;
+use super::super::sorts::dlsd_sort::{dlsd_radixsort, dlsd_radixsort_wc};
+#[cfg(feature = "voracious_multithread")]
+use super::super::sorts::lsd_mt_sort::lsd_mt_radixsort;
 use super::super::sorts::lsd_sort::lsd_radixsort_heu;
 use super::super::sorts::msd_sort::msd_radixsort;
 #[cfg(feature = "voracious_multithread")]
 use super::super::sorts::peeka_sort::peeka_sort;
 use super::super::sorts::utils::{get_empty_histograms, Params};
+use super::super::sorts::utils_simd::{
+    fill_histograms_u32, fill_histograms_u64, simd_histograms_available,
+    KeyTransform,
+};
 use super::super::sorts::voracious_sort::voracious_sort_heu;
 use super::super::Radixable;
 
@@ -215,6 +225,19 @@ impl Radixable<u32> for u32 {
         arr: &mut [u32],
         p: &Params,
     ) -> Vec<Vec<usize>> {
+        if simd_histograms_available(arr.len(), p.radix) {
+            let mut histograms =
+                get_empty_histograms(p.max_level, p.radix_range);
+            fill_histograms_u32(
+                arr,
+                p.radix,
+                p.max_level,
+                KeyTransform::Identity,
+                &mut histograms,
+            );
+            return histograms;
+        }
+
         let mut histograms = get_empty_histograms(p.max_level, p.radix_range);
         let default_mask = self.default_mask(p.radix);
         let shift = p.radix as u32;
@@ -382,8 +405,12 @@ impl Radixable<u32> for u32 {
     }
     #[cfg(feature = "voracious_multithread")]
     fn voracious_mt_sort(&self, arr: &mut [Self], thread_n: usize) {
-        if arr.len() < 2_500_000 {
-            arr.par_sort_unstable_by(|a, b| a.partial_cmp(b).unwrap());
+        if arr.len() < 100_000 {
+            arr.par_sort_unstable_by(key_cmp);
+        } else if arr.len() < 1_000_000 {
+            lsd_mt_radixsort(arr, 8, thread_n);
+        } else if arr.len() < 2_500_000 {
+            arr.par_sort_unstable_by(key_cmp);
         } else {
             let chunk_size = if arr.len() < 4_000_000 {
                 400_000
@@ -426,6 +453,19 @@ impl Radixable<u64> for u64 {
         arr: &mut [u64],
         p: &Params,
     ) -> Vec<Vec<usize>> {
+        if simd_histograms_available(arr.len(), p.radix) {
+            let mut histograms =
+                get_empty_histograms(p.max_level, p.radix_range);
+            fill_histograms_u64(
+                arr,
+                p.radix,
+                p.max_level,
+                KeyTransform::Identity,
+                &mut histograms,
+            );
+            return histograms;
+        }
+
         let mut histograms = get_empty_histograms(p.max_level, p.radix_range);
         let default_mask = self.default_mask(p.radix);
         let shift = p.radix as u64;
@@ -881,8 +921,10 @@ impl Radixable<u64> for u64 {
     fn voracious_sort(&self, arr: &mut [u64]) {
         if arr.len() < 350 {
             arr.sort_unstable();
-        } else {
+        } else if arr.len() < 50_000_000 {
             dlsd_radixsort(arr, 8);
+        } else {
+            dlsd_radixsort_wc(arr, 8);
         }
     }
     fn voracious_stable_sort(&self, arr: &mut [u64]) {
@@ -891,8 +933,12 @@ impl Radixable<u64> for u64 {
     }
     #[cfg(feature = "voracious_multithread")]
     fn voracious_mt_sort(&self, arr: &mut [Self], thread_n: usize) {
-        if arr.len() < 2_000_000 {
-            arr.par_sort_unstable_by(|a, b| a.partial_cmp(b).unwrap());
+        if arr.len() < 100_000 {
+            arr.par_sort_unstable_by(key_cmp);
+        } else if arr.len() < 1_000_000 {
+            lsd_mt_radixsort(arr, 8, thread_n);
+        } else if arr.len() < 2_000_000 {
+            arr.par_sort_unstable_by(key_cmp);
         } else {
             let chunk_size = if arr.len() < 70_000_000 {
                 300_000
@@ -939,8 +985,10 @@ impl Radixable<u128> for u128 {
             dlsd_radixsort(arr, 8);
         } else if arr.len() <= 1_000_000 {
             voracious_sort_heu(arr, 8, 200_000);
-        } else {
+        } else if arr.len() < 50_000_000 {
             dlsd_radixsort(arr, 8);
+        } else {
+            dlsd_radixsort_wc(arr, 8);
         }
     }
     fn voracious_stable_sort(&self, arr: &mut [u128]) {
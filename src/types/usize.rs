@@ -1,7 +1,13 @@
+use alloc::vec::Vec;
+
 #[cfg(feature = "voracious_multithread")]
 use rayon::slice::ParallelSliceMut;
 
-use super::super::sorts::dlsd_sort::dlsd_radixsort;
+#[cfg(feature = "voracious_multithread")]
+use super::super::sorts::comparative_sort::key_cmp;
+use super::super::sorts::dlsd_sort::{dlsd_radixsort, dlsd_radixsort_wc};
+#[cfg(feature = "voracious_multithread")]
+use super::super::sorts::lsd_mt_sort::lsd_mt_radixsort;
 #[cfg(feature = "voracious_multithread")]
 use super::super::sorts::peeka_sort::peeka_sort;
 use super::super::sorts::utils::{get_empty_histograms, Params};
@@ -370,8 +376,12 @@ impl Radixable<usize> for usize {
     }
     #[cfg(feature = "voracious_multithread")]
     fn voracious_mt_sort(&self, arr: &mut [Self], thread_n: usize) {
-        if arr.len() < 2_500_000 {
-            arr.par_sort_unstable_by(|a, b| a.partial_cmp(b).unwrap());
+        if arr.len() < 100_000 {
+            arr.par_sort_unstable_by(key_cmp);
+        } else if arr.len() < 1_000_000 {
+            lsd_mt_radixsort(arr, 8, thread_n);
+        } else if arr.len() < 2_500_000 {
+            arr.par_sort_unstable_by(key_cmp);
         } else {
             let chunk_size = if arr.len() < 4_000_000 {
                 400_000
@@ -870,8 +880,10 @@ impl Radixable<usize> for usize {
     fn voracious_sort(&self, arr: &mut [Self]) {
         if arr.len() < 350 {
             arr.sort_unstable();
-        } else {
+        } else if arr.len() < 50_000_000 {
             dlsd_radixsort(arr, 8);
+        } else {
+            dlsd_radixsort_wc(arr, 8);
         }
     }
     fn voracious_stable_sort(&self, arr: &mut [Self]) {
@@ -880,8 +892,12 @@ impl Radixable<usize> for usize {
     }
     #[cfg(feature = "voracious_multithread")]
     fn voracious_mt_sort(&self, arr: &mut [Self], thread_n: usize) {
-        if arr.len() < 2_000_000 {
-            arr.par_sort_unstable_by(|a, b| a.partial_cmp(b).unwrap());
+        if arr.len() < 100_000 {
+            arr.par_sort_unstable_by(key_cmp);
+        } else if arr.len() < 1_000_000 {
+            lsd_mt_radixsort(arr, 8, thread_n);
+        } else if arr.len() < 2_000_000 {
+            arr.par_sort_unstable_by(key_cmp);
         } else {
             let chunk_size = if arr.len() < 70_000_000 {
                 300_000